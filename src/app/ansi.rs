@@ -0,0 +1,193 @@
+//! ANSI escape sequences in container output: SGR colours to styles, or stripped
+
+use std::borrow::Cow;
+
+use ratatui::style::{Color, Modifier, Style};
+
+const ESC: char = '\x1b';
+
+// 跳过一个转义序列（ESC 已被取出），返回 CSI 序列的参数和结束字符
+fn read_escape(chars: &mut std::iter::Peekable<std::str::Chars>) -> Option<(String, char)> {
+    match chars.next()? {
+        '[' => {
+            let mut params = String::new();
+            for c in chars.by_ref() {
+                if ('\x40'..='\x7e').contains(&c) {
+                    return Some((params, c));
+                }
+                params.push(c);
+            }
+            None
+        }
+        // OSC（如终端标题、超链接）以 BEL 或 ESC \ 结束
+        ']' => {
+            while let Some(c) = chars.next() {
+                if c == '\x07' || (c == ESC && chars.next_if_eq(&'\\').is_some()) {
+                    break;
+                }
+            }
+            None
+        }
+        _ => None,
+    }
+}
+
+fn basic_color(index: u16) -> Color {
+    match index {
+        0 => Color::Black,
+        1 => Color::Red,
+        2 => Color::Green,
+        3 => Color::Yellow,
+        4 => Color::Blue,
+        5 => Color::Magenta,
+        6 => Color::Cyan,
+        7 => Color::Gray,
+        8 => Color::DarkGray,
+        9 => Color::LightRed,
+        10 => Color::LightGreen,
+        11 => Color::LightYellow,
+        12 => Color::LightBlue,
+        13 => Color::LightMagenta,
+        14 => Color::LightCyan,
+        _ => Color::White,
+    }
+}
+
+// 38;5;n / 38;2;r;g;b 形式的扩展颜色
+fn extended_color(codes: &mut impl Iterator<Item = u16>) -> Option<Color> {
+    match codes.next()? {
+        5 => Some(Color::Indexed(codes.next()?.min(255) as u8)),
+        2 => {
+            let mut channel = || codes.next().map(|v| v.min(255) as u8);
+            Some(Color::Rgb(channel()?, channel()?, channel()?))
+        }
+        _ => None,
+    }
+}
+
+fn apply_sgr(mut style: Style, params: &str) -> Style {
+    // `ESC[m` 等同于重置
+    if params.is_empty() {
+        return Style::default();
+    }
+    let mut codes = params
+        .split([';', ':'])
+        .map(|code| code.parse::<u16>().unwrap_or(0));
+    while let Some(code) = codes.next() {
+        style = match code {
+            0 => Style::default(),
+            1 => style.add_modifier(Modifier::BOLD),
+            2 => style.add_modifier(Modifier::DIM),
+            3 => style.add_modifier(Modifier::ITALIC),
+            4 => style.add_modifier(Modifier::UNDERLINED),
+            5 | 6 => style.add_modifier(Modifier::SLOW_BLINK),
+            7 => style.add_modifier(Modifier::REVERSED),
+            9 => style.add_modifier(Modifier::CROSSED_OUT),
+            22 => style.remove_modifier(Modifier::BOLD | Modifier::DIM),
+            23 => style.remove_modifier(Modifier::ITALIC),
+            24 => style.remove_modifier(Modifier::UNDERLINED),
+            25 => style.remove_modifier(Modifier::SLOW_BLINK),
+            27 => style.remove_modifier(Modifier::REVERSED),
+            29 => style.remove_modifier(Modifier::CROSSED_OUT),
+            30..=37 => style.fg(basic_color(code - 30)),
+            38 => match extended_color(&mut codes) {
+                Some(color) => style.fg(color),
+                None => style,
+            },
+            39 => style.fg(Color::Reset),
+            40..=47 => style.bg(basic_color(code - 40)),
+            48 => match extended_color(&mut codes) {
+                Some(color) => style.bg(color),
+                None => style,
+            },
+            49 => style.bg(Color::Reset),
+            90..=97 => style.fg(basic_color(code - 90 + 8)),
+            100..=107 => style.bg(basic_color(code - 100 + 8)),
+            _ => style,
+        };
+    }
+    style
+}
+
+/// 按 SGR 序列把一行拆成带样式的片段，其它控制序列直接丢弃
+pub fn parse_ansi(text: &str) -> Vec<(String, Style)> {
+    let mut segments = Vec::new();
+    let mut style = Style::default();
+    let mut current = String::new();
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != ESC {
+            current.push(c);
+            continue;
+        }
+        if let Some((params, 'm')) = read_escape(&mut chars) {
+            let next = apply_sgr(style, &params);
+            if next != style && !current.is_empty() {
+                segments.push((std::mem::take(&mut current), style));
+            }
+            style = next;
+        }
+    }
+    if !current.is_empty() || segments.is_empty() {
+        segments.push((current, style));
+    }
+    segments
+}
+
+/// 去掉所有转义序列，用于搜索匹配、级别识别和结构化解析
+pub fn strip_ansi(text: &str) -> Cow<'_, str> {
+    if !text.contains(ESC) {
+        return Cow::Borrowed(text);
+    }
+    let mut plain = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == ESC {
+            read_escape(&mut chars);
+        } else {
+            plain.push(c);
+        }
+    }
+    Cow::Owned(plain)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_sgr_colours() {
+        let segments =
+            parse_ansi("\x1b[1;31mERROR\x1b[0m done \x1b[38;5;208mx\x1b[39;48;2;1;2;3my");
+        assert_eq!(
+            segments,
+            vec![
+                (
+                    "ERROR".to_string(),
+                    Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)
+                ),
+                (" done ".to_string(), Style::default()),
+                ("x".to_string(), Style::default().fg(Color::Indexed(208))),
+                (
+                    "y".to_string(),
+                    Style::default().fg(Color::Reset).bg(Color::Rgb(1, 2, 3))
+                ),
+            ]
+        );
+        assert_eq!(
+            parse_ansi("plain"),
+            vec![("plain".to_string(), Style::default())]
+        );
+        assert_eq!(
+            parse_ansi("\x1b[92mok")[0].1,
+            Style::default().fg(Color::LightGreen)
+        );
+    }
+
+    #[test]
+    fn test_strip_ansi() {
+        assert_eq!(strip_ansi("\x1b[32mINFO\x1b[m ready"), "INFO ready");
+        assert_eq!(strip_ansi("\x1b]0;title\x07a\x1b[2Kb"), "ab");
+        assert!(matches!(strip_ansi("no escapes"), Cow::Borrowed(_)));
+    }
+}
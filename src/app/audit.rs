@@ -0,0 +1,92 @@
+//! Audit trail of sensitive actions (secret reveals, copies)
+
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+
+use chrono::{DateTime, Local};
+
+/// 默认审计日志 `~/.local/share/kube-tui/audit.log`
+pub fn audit_path() -> PathBuf {
+    let home = std::env::var("HOME").unwrap_or_else(|_| "/".to_string());
+    PathBuf::from(home).join(".local/share/kube-tui/audit.log")
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AuditEntry {
+    pub time: DateTime<Local>,
+    /// 如 `secret.reveal`、`secret.copy`
+    pub action: String,
+    /// 如 `namespace/secret:key`，从不包含值本身
+    pub target: String,
+}
+
+impl AuditEntry {
+    fn line(&self) -> String {
+        format!(
+            "{}\t{}\t{}\n",
+            self.time.to_rfc3339(),
+            self.action,
+            self.target
+        )
+    }
+}
+
+/// 本次会话的审计记录；设置了文件时同时追加写入
+#[derive(Debug, Clone, Default)]
+pub struct AuditLog {
+    path: Option<PathBuf>,
+    pub entries: Vec<AuditEntry>,
+}
+
+impl AuditLog {
+    pub fn new(path: PathBuf) -> Self {
+        Self {
+            path: Some(path),
+            entries: Vec::new(),
+        }
+    }
+
+    pub fn record(&mut self, action: &str, target: &str) -> std::io::Result<()> {
+        let entry = AuditEntry {
+            time: Local::now(),
+            action: action.to_string(),
+            target: target.to_string(),
+        };
+        let line = entry.line();
+        self.entries.push(entry);
+
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)?
+            .write_all(line.as_bytes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_appends_to_file() {
+        let dir = std::env::temp_dir().join(format!("kube-tui-audit-{}", std::process::id()));
+        let path = dir.join("audit.log");
+        let mut log = AuditLog::new(path.clone());
+        log.record("secret.reveal", "prod/db:password").unwrap();
+        log.record("secret.copy", "prod/db:password").unwrap();
+
+        let content = fs::read_to_string(&path).unwrap();
+        let lines: Vec<&str> = content.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].ends_with("\tsecret.reveal\tprod/db:password"));
+        assert_eq!(log.entries[1].action, "secret.copy");
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
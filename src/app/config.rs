@@ -3,6 +3,7 @@
 
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fs;
 use std::path::PathBuf;
 
@@ -13,6 +14,21 @@ pub struct UserConfig {
     pub favorite_namespaces: Vec<String>,
     /// Command history (max 100 entries)
     pub command_history: Vec<String>,
+    /// Extra shell tried after bash and sh when exec'ing into a container
+    #[serde(default)]
+    pub exec_fallback_shell: Option<String>,
+    /// Last exec choice per workload, keyed by `namespace/Kind/name`
+    #[serde(default)]
+    pub exec_preferences: HashMap<String, ExecPreference>,
+}
+
+/// Remembered exec choice for a workload
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ExecPreference {
+    /// Container picked last time
+    pub container: Option<String>,
+    /// Free-form command, `None` means the shell fallback chain
+    pub command: Option<String>,
 }
 
 impl Default for UserConfig {
@@ -20,6 +36,8 @@ impl Default for UserConfig {
         Self {
             favorite_namespaces: Vec::new(),
             command_history: Vec::new(),
+            exec_fallback_shell: None,
+            exec_preferences: HashMap::new(),
         }
     }
 }
@@ -94,6 +112,23 @@ impl UserConfig {
         self.favorite_namespaces.contains(&namespace.to_string())
     }
 
+    /// Shells tried in order when exec'ing without a command
+    pub fn exec_shells(&self) -> Vec<String> {
+        let mut shells = vec!["bash".to_string(), "sh".to_string()];
+        if let Some(shell) = &self.exec_fallback_shell
+            && !shells.contains(shell)
+        {
+            shells.push(shell.clone());
+        }
+        shells
+    }
+
+    /// Remember the exec choice for a workload
+    pub fn remember_exec(&mut self, workload: &str, preference: ExecPreference) {
+        self.exec_preferences
+            .insert(workload.to_string(), preference);
+    }
+
     /// Limit command history to 100 entries
     fn limit_command_history(mut config: Self) -> Self {
         if config.command_history.len() > 100 {
@@ -173,6 +208,23 @@ mod tests {
         assert_eq!(config.command_history[99], "command 104");
     }
 
+    #[test]
+    fn test_exec_shells_with_fallback() {
+        let mut config = UserConfig::default();
+        assert_eq!(config.exec_shells(), vec!["bash", "sh"]);
+
+        config.exec_fallback_shell = Some("/busybox/sh".to_string());
+        assert_eq!(config.exec_shells(), vec!["bash", "sh", "/busybox/sh"]);
+    }
+
+    #[test]
+    fn test_load_config_without_exec_fields() {
+        let config: UserConfig =
+            serde_json::from_str(r#"{"favorite_namespaces":[],"command_history":[]}"#).unwrap();
+        assert!(config.exec_fallback_shell.is_none());
+        assert!(config.exec_preferences.is_empty());
+    }
+
     #[test]
     fn test_config_path() {
         let path = UserConfig::config_path();
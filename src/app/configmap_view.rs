@@ -0,0 +1,234 @@
+//! ConfigMap detail view: keys with sizes, highlighted values, per-key editing
+
+use super::detail_lifecycle::{DetailLifecycle, EditableView};
+use super::diff::{DiffLine, line_diff};
+use crate::kubectl::types::{ConfigMapDetail, ConfigMapEntry};
+
+/// 按键名扩展名猜测的值语法
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Syntax {
+    Yaml,
+    Json,
+    Properties,
+    Ini,
+    Plain,
+}
+
+impl Syntax {
+    pub fn from_key(key: &str) -> Self {
+        let extension = key
+            .rsplit_once('.')
+            .map(|(_, ext)| ext.to_ascii_lowercase())
+            .unwrap_or_default();
+        match extension.as_str() {
+            "yaml" | "yml" => Syntax::Yaml,
+            "json" => Syntax::Json,
+            "properties" | "env" => Syntax::Properties,
+            "ini" | "conf" | "cfg" | "toml" => Syntax::Ini,
+            _ => Syntax::Plain,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Syntax::Yaml => "yaml",
+            Syntax::Json => "json",
+            Syntax::Properties => "properties",
+            Syntax::Ini => "ini",
+            Syntax::Plain => "text",
+        }
+    }
+}
+
+/// 编辑器返回后等待确认的修改
+#[derive(Debug, Clone)]
+pub struct KeyEdit {
+    pub key: String,
+    pub value: String,
+    pub diff: Vec<DiffLine>,
+}
+
+/// ConfigMap 详情视图：逐键在编辑器中修改
+#[derive(Debug, Clone)]
+pub struct ConfigMapView {
+    pub namespace: String,
+    pub name: String,
+    /// 编辑目标为键名
+    pub lifecycle: DetailLifecycle<ConfigMapDetail, String, KeyEdit>,
+    pub selected: usize,
+    /// 值面板的滚动位置
+    pub scroll: usize,
+    /// 上次编辑/修改的结果
+    pub message: Option<Result<String, String>>,
+}
+
+impl ConfigMapView {
+    pub fn new(namespace: &str, name: &str) -> Self {
+        Self {
+            namespace: namespace.to_string(),
+            name: name.to_string(),
+            lifecycle: DetailLifecycle::default(),
+            selected: 0,
+            scroll: 0,
+            message: None,
+        }
+    }
+
+    pub fn entries(&self) -> &[ConfigMapEntry] {
+        self.lifecycle
+            .detail
+            .as_ref()
+            .map_or(&[], |d| d.entries.as_slice())
+    }
+
+    pub fn selected_entry(&self) -> Option<&ConfigMapEntry> {
+        self.entries().get(self.selected)
+    }
+
+    pub fn select_next(&mut self) {
+        if self.selected + 1 < self.entries().len() {
+            self.selected += 1;
+            self.scroll = 0;
+        }
+    }
+
+    pub fn select_previous(&mut self) {
+        if self.selected > 0 {
+            self.selected -= 1;
+            self.scroll = 0;
+        }
+    }
+
+    pub fn scroll_down(&mut self) {
+        let lines = self.selected_entry().map_or(0, |e| e.value.lines().count());
+        if self.scroll + 1 < lines {
+            self.scroll += 1;
+        }
+    }
+
+    pub fn scroll_up(&mut self) {
+        self.scroll = self.scroll.saturating_sub(1);
+    }
+
+    /// 请求编辑选中的键；binaryData 不可编辑
+    pub fn request_edit(&mut self) -> Result<(), String> {
+        let entry = self
+            .selected_entry()
+            .ok_or_else(|| "No key selected".to_string())?;
+        if entry.binary {
+            return Err(format!("{} is binaryData and cannot be edited", entry.key));
+        }
+        self.lifecycle.pending_edit = Some(entry.key.clone());
+        Ok(())
+    }
+
+    /// 编辑器返回后计算 diff；内容未变化时不进入确认
+    pub fn set_edited(&mut self, key: &str, edited: anyhow::Result<String>) {
+        let original = self
+            .entries()
+            .iter()
+            .find(|e| e.key == key)
+            .map(|e| e.value.clone())
+            .unwrap_or_default();
+        match edited {
+            Ok(value) if value == original => {
+                self.message = Some(Ok(format!("{}: no changes", key)));
+            }
+            Ok(value) => {
+                self.lifecycle.set_edit(KeyEdit {
+                    key: key.to_string(),
+                    diff: line_diff(&original, &value),
+                    value,
+                });
+                self.message = None;
+            }
+            Err(e) => self.message = Some(Err(e.to_string())),
+        }
+    }
+
+    pub fn discard_edit(&mut self) {
+        if let Some(edit) = self.lifecycle.discard_edit() {
+            self.message = Some(Ok(format!("{}: edit discarded", edit.key)));
+        }
+    }
+}
+
+impl EditableView for ConfigMapView {
+    type Detail = ConfigMapDetail;
+
+    fn load_pending(&self) -> bool {
+        self.lifecycle.pending
+    }
+
+    fn set_result(&mut self, result: anyhow::Result<ConfigMapDetail>) {
+        if let Some(detail) = self.lifecycle.set_result(result) {
+            self.selected = self.selected.min(detail.entries.len().saturating_sub(1));
+        }
+    }
+
+    fn set_patch_result(&mut self, result: anyhow::Result<String>) {
+        self.message = Some(self.lifecycle.finish_patch(result).map(|edit| {
+            let key = edit.map(|e| e.key).unwrap_or_default();
+            format!("{}: patched", key)
+        }));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn detail() -> ConfigMapDetail {
+        let entry = |key: &str, value: &str, binary: bool| ConfigMapEntry {
+            key: key.to_string(),
+            value: value.to_string(),
+            binary,
+            size: value.len(),
+        };
+        ConfigMapDetail {
+            name: "app".to_string(),
+            namespace: "prod".to_string(),
+            entries: vec![
+                entry("app.yaml", "port: 80\nhost: a\n", false),
+                entry("logo.png", "iVBORw==", true),
+            ],
+        }
+    }
+
+    #[test]
+    fn test_syntax_from_key() {
+        assert_eq!(Syntax::from_key("config.YAML"), Syntax::Yaml);
+        assert_eq!(Syntax::from_key("settings.json"), Syntax::Json);
+        assert_eq!(Syntax::from_key("app.properties"), Syntax::Properties);
+        assert_eq!(Syntax::from_key("my.cnf.ini"), Syntax::Ini);
+        assert_eq!(Syntax::from_key("LOG_LEVEL"), Syntax::Plain);
+    }
+
+    #[test]
+    fn test_edit_flow() {
+        let mut view = ConfigMapView::new("prod", "app");
+        view.set_result(Ok(detail()));
+
+        view.request_edit().unwrap();
+        assert_eq!(view.lifecycle.pending_edit.as_deref(), Some("app.yaml"));
+        view.lifecycle.pending_edit = None;
+        view.set_edited("app.yaml", Ok("port: 80\nhost: a\n".to_string()));
+        assert!(view.lifecycle.edit.is_none());
+
+        view.set_edited("app.yaml", Ok("port: 8080\nhost: a\n".to_string()));
+        let edit = view.lifecycle.edit.as_ref().unwrap();
+        assert_eq!(edit.diff[0], DiffLine::Removed("port: 80".to_string()));
+        assert_eq!(edit.diff[1], DiffLine::Added("port: 8080".to_string()));
+
+        view.lifecycle.confirm_edit();
+        view.set_patch_result(Err(anyhow::anyhow!("forbidden")));
+        assert!(view.lifecycle.edit.is_some());
+        view.set_patch_result(Ok("patched".to_string()));
+        assert!(view.lifecycle.edit.is_none());
+        assert!(view.lifecycle.pending);
+
+        view.select_next();
+        assert!(view.request_edit().is_err());
+        assert!(view.lifecycle.pending_edit.is_none());
+    }
+}
@@ -0,0 +1,121 @@
+//! Incremental search inside the YAML and describe views
+
+use regex::Regex;
+
+use super::log_search::build_pattern;
+
+/// YAML / Describe 视图的搜索状态；按字面量、忽略大小写匹配
+#[derive(Debug, Clone, Default)]
+pub struct ContentSearch {
+    pub query: String,
+    /// 正在输入查询
+    pub input: bool,
+    pub pattern: Option<Regex>,
+    /// 匹配的源行号，升序
+    pub matches: Vec<usize>,
+    pub current: usize,
+}
+
+impl ContentSearch {
+    pub fn is_active(&self) -> bool {
+        !self.query.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    /// 查询变化后重新编译模式；字面量模式不会编译失败
+    pub fn set_query(&mut self, query: String, lines: &[String]) {
+        self.pattern = build_pattern(&query, false, false).ok().flatten();
+        self.query = query;
+        self.refresh(lines);
+    }
+
+    /// 内容变化后重新计算匹配行
+    pub fn refresh(&mut self, lines: &[String]) {
+        self.matches = match &self.pattern {
+            Some(pattern) => lines
+                .iter()
+                .enumerate()
+                .filter(|(_, line)| pattern.is_match(line))
+                .map(|(i, _)| i)
+                .collect(),
+            None => Vec::new(),
+        };
+        self.current = self.current.min(self.matches.len().saturating_sub(1));
+    }
+
+    pub fn current_line(&self) -> Option<usize> {
+        self.matches.get(self.current).copied()
+    }
+
+    /// 选中 line 及之后的第一个匹配，没有时回到第一个（增量搜索）
+    pub fn select_from(&mut self, line: usize) -> Option<usize> {
+        self.current = self.matches.iter().position(|&m| m >= line).unwrap_or(0);
+        self.current_line()
+    }
+
+    /// 循环跳到下一个或上一个匹配
+    pub fn step(&mut self, forward: bool) -> Option<usize> {
+        let len = self.matches.len();
+        if len == 0 {
+            return None;
+        }
+        self.current = if forward {
+            (self.current + 1) % len
+        } else {
+            (self.current + len - 1) % len
+        };
+        self.current_line()
+    }
+
+    /// 标题中显示的状态：`[/查询 当前/总数]`
+    pub fn status(&self) -> String {
+        if !self.is_active() {
+            return String::new();
+        }
+        let position = match self.matches.len() {
+            0 => "0/0".to_string(),
+            total => format!("{}/{}", self.current + 1, total),
+        };
+        format!(" [/{} {}]", self.query, position)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lines(text: &str) -> Vec<String> {
+        text.lines().map(str::to_string).collect()
+    }
+
+    #[test]
+    fn test_incremental_search() {
+        let lines = lines("name: web\nimage: nginx\n  name: sidecar\nImage: busybox\n");
+        let mut search = ContentSearch::default();
+        search.set_query("image".to_string(), &lines);
+        assert_eq!(search.matches, vec![1, 3]);
+        assert_eq!(search.select_from(2), Some(3));
+        assert_eq!(search.status(), " [/image 2/2]");
+        assert_eq!(search.step(true), Some(1));
+        assert_eq!(search.step(false), Some(3));
+
+        // 字面量匹配，`.` 不是通配符
+        search.set_query("a.e".to_string(), &lines);
+        assert!(search.matches.is_empty());
+        assert_eq!(search.select_from(0), None);
+        assert_eq!(search.step(true), None);
+        assert_eq!(search.status(), " [/a.e 0/0]");
+
+        // 内容刷新后当前位置不越界
+        search.set_query("name".to_string(), &lines);
+        search.select_from(2);
+        search.refresh(&lines[..1]);
+        assert_eq!(search.current_line(), Some(0));
+
+        search.clear();
+        assert_eq!(search.status(), "");
+    }
+}
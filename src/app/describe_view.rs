@@ -0,0 +1,235 @@
+//! Split `kubectl describe` output into foldable top-level sections
+
+use std::collections::HashSet;
+
+/// 始终固定显示在底部的段落
+pub const EVENTS_SECTION: &str = "Events";
+
+/// 顶层段落：标题行及其后缩进的内容行，`[start, end)`
+#[derive(Debug, Clone, PartialEq)]
+pub struct DescribeSection {
+    pub title: String,
+    pub start: usize,
+    pub end: usize,
+}
+
+impl DescribeSection {
+    /// 标题行之后的行数
+    pub fn body_len(&self) -> usize {
+        self.end - self.start - 1
+    }
+
+    pub fn is_events(&self) -> bool {
+        self.title == EVENTS_SECTION
+    }
+}
+
+/// 顶格的 `Key:` 行开始一个新段落，如 `Labels:`、`Init Containers:`
+fn section_title(line: &str) -> Option<&str> {
+    let (title, _) = line.split_once(':')?;
+    let first = title.chars().next()?;
+    (first.is_ascii_alphabetic()
+        && title
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, ' ' | '-' | '_' | '.' | '/')))
+    .then_some(title)
+}
+
+/// 按顶层段落切分；第一个标题之前的行归入第一个段落，末尾空行不计入内容
+pub fn parse_sections(lines: &[String]) -> Vec<DescribeSection> {
+    let mut sections: Vec<DescribeSection> = Vec::new();
+    for (index, line) in lines.iter().enumerate() {
+        match (section_title(line), sections.last_mut()) {
+            (Some(title), _) => sections.push(DescribeSection {
+                title: title.to_string(),
+                start: index,
+                end: index + 1,
+            }),
+            (None, Some(section)) => section.end = index + 1,
+            (None, None) => {}
+        }
+    }
+    for section in &mut sections {
+        while section.body_len() > 0 && lines[section.end - 1].trim().is_empty() {
+            section.end -= 1;
+        }
+    }
+    sections
+}
+
+/// Describe 视图的段落结构、折叠状态和段落列表弹窗
+#[derive(Debug, Clone, Default)]
+pub struct DescribeOutline {
+    pub sections: Vec<DescribeSection>,
+    /// 折叠的段落标题；自动刷新后保持
+    pub folded: HashSet<String>,
+    /// 段落列表弹窗中选中的段落，None 表示未打开
+    pub picker: Option<usize>,
+}
+
+impl DescribeOutline {
+    pub fn set_lines(&mut self, lines: &[String]) {
+        self.sections = parse_sections(lines);
+        if let Some(selected) = self.picker.as_mut() {
+            *selected = (*selected).min(self.sections.len().saturating_sub(1));
+        }
+    }
+
+    pub fn events(&self) -> Option<&DescribeSection> {
+        self.sections.iter().find(|s| s.is_events())
+    }
+
+    pub fn is_folded(&self, section: &DescribeSection) -> bool {
+        section.body_len() > 0 && self.folded.contains(&section.title)
+    }
+
+    /// 主面板中可见的行号；Events 固定在底部，不在其中
+    pub fn rows(&self) -> Vec<usize> {
+        let mut rows = Vec::new();
+        for section in self.sections.iter().filter(|s| !s.is_events()) {
+            if self.is_folded(section) {
+                rows.push(section.start);
+            } else {
+                rows.extend(section.start..section.end);
+            }
+        }
+        rows
+    }
+
+    /// 可见行中第 row 行所属的段落
+    pub fn section_at_row(&self, row: usize) -> Option<usize> {
+        let line = *self.rows().get(row)?;
+        self.sections
+            .iter()
+            .position(|s| s.start <= line && line < s.end)
+    }
+
+    /// 段落标题在可见行中的位置
+    pub fn header_row(&self, index: usize) -> Option<usize> {
+        let start = self.sections.get(index)?.start;
+        self.rows().iter().position(|&line| line == start)
+    }
+
+    pub fn toggle(&mut self, index: usize) {
+        let Some(section) = self.sections.get(index).filter(|s| s.body_len() > 0) else {
+            return;
+        };
+        if !self.folded.remove(&section.title) {
+            self.folded.insert(section.title.clone());
+        }
+    }
+
+    /// 展开包含 line 的段落
+    pub fn reveal(&mut self, line: usize) {
+        if let Some(section) = self
+            .sections
+            .iter()
+            .find(|s| s.start < line && line < s.end)
+        {
+            self.folded.remove(&section.title);
+        }
+    }
+
+    /// 有展开的段落时全部折叠，否则全部展开
+    pub fn toggle_all(&mut self) {
+        let foldable: Vec<&DescribeSection> = self
+            .sections
+            .iter()
+            .filter(|s| s.body_len() > 0 && !s.is_events())
+            .collect();
+        if foldable.iter().any(|s| !self.folded.contains(&s.title)) {
+            self.folded = foldable.iter().map(|s| s.title.clone()).collect();
+        } else {
+            self.folded.clear();
+        }
+    }
+
+    /// 从 from 段落起向前或向后查找下一个有内容的段落
+    pub fn adjacent_section(&self, from: usize, forward: bool) -> Option<usize> {
+        let foldable = |i: &usize| {
+            let section = &self.sections[*i];
+            section.body_len() > 0 && !section.is_events()
+        };
+        if forward {
+            (from + 1..self.sections.len()).find(foldable)
+        } else {
+            (0..from).rev().find(foldable)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const POD: &str = "Name:         web-abc
+Namespace:    prod
+Labels:       app=web
+              tier=frontend
+Containers:
+  web:
+    Image:  nginx
+Conditions:
+  Type    Status
+  Ready   True
+
+Events:
+  Type    Reason   Age  From     Message
+  Normal  Pulled   1m   kubelet  Pulled image
+";
+
+    fn outline() -> DescribeOutline {
+        let lines: Vec<String> = POD.lines().map(str::to_string).collect();
+        let mut outline = DescribeOutline::default();
+        outline.set_lines(&lines);
+        outline
+    }
+
+    #[test]
+    fn test_parse_sections() {
+        let outline = outline();
+        let titles: Vec<&str> = outline.sections.iter().map(|s| s.title.as_str()).collect();
+        assert_eq!(
+            titles,
+            [
+                "Name",
+                "Namespace",
+                "Labels",
+                "Containers",
+                "Conditions",
+                "Events"
+            ]
+        );
+        assert_eq!(outline.sections[2].body_len(), 1);
+        // 段落末尾的空行不计入
+        assert_eq!(outline.sections[4].body_len(), 2);
+        assert_eq!(outline.events().unwrap().body_len(), 2);
+    }
+
+    #[test]
+    fn test_fold_rows() {
+        let mut outline = outline();
+        // Events 不在主面板中
+        assert_eq!(outline.rows().len(), 10);
+
+        outline.toggle(3);
+        assert_eq!(outline.rows().len(), 8);
+        assert_eq!(outline.header_row(4), Some(5));
+        assert_eq!(outline.section_at_row(4), Some(3));
+        // 没有内容的段落不能折叠
+        outline.toggle(0);
+        assert!(!outline.folded.contains("Name"));
+
+        outline.toggle_all();
+        assert_eq!(outline.rows(), vec![0, 1, 2, 4, 7]);
+        outline.reveal(6);
+        assert_eq!(outline.rows(), vec![0, 1, 2, 4, 5, 6, 7]);
+        outline.toggle(3);
+        outline.toggle_all();
+        assert!(outline.folded.is_empty());
+
+        assert_eq!(outline.adjacent_section(0, true), Some(2));
+        assert_eq!(outline.adjacent_section(4, true), None);
+        assert_eq!(outline.adjacent_section(4, false), Some(3));
+    }
+}
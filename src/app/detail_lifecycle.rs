@@ -0,0 +1,136 @@
+//! Load / edit / confirm / patch lifecycle shared by the detail views
+
+/// 详情视图的公共状态：打开、按 R 或修改成功后由主循环执行 kubectl 加载；
+/// 可编辑的视图在编辑器返回后等待确认，确认后由主循环执行 kubectl patch
+#[derive(Debug, Clone)]
+pub struct DetailLifecycle<D, T = (), E = ()> {
+    pub detail: Option<D>,
+    /// 等待主循环执行 kubectl 加载
+    pub pending: bool,
+    /// 上次加载失败的原因；保留已加载的内容
+    pub error: Option<String>,
+    /// 等待主循环让出终端、在编辑器中打开的目标
+    pub pending_edit: Option<T>,
+    /// 编辑器返回后等待确认的修改
+    pub edit: Option<E>,
+    /// 已确认修改，等待主循环执行 kubectl patch
+    pub pending_patch: bool,
+}
+
+impl<D, T, E> Default for DetailLifecycle<D, T, E> {
+    fn default() -> Self {
+        Self {
+            detail: None,
+            pending: true,
+            error: None,
+            pending_edit: None,
+            edit: None,
+            pending_patch: false,
+        }
+    }
+}
+
+impl<D, T, E> DetailLifecycle<D, T, E> {
+    pub fn reload(&mut self) {
+        self.pending = true;
+    }
+
+    /// 加载完成；成功时返回新内容，供视图调整选中项等
+    pub fn set_result(&mut self, result: anyhow::Result<D>) -> Option<&D> {
+        self.pending = false;
+        match result {
+            Ok(detail) => {
+                self.error = None;
+                Some(self.detail.insert(detail))
+            }
+            Err(e) => {
+                self.error = Some(e.to_string());
+                None
+            }
+        }
+    }
+
+    /// 编辑器返回后的修改，等待确认
+    pub fn set_edit(&mut self, edit: E) {
+        self.edit = Some(edit);
+    }
+
+    pub fn confirm_edit(&mut self) {
+        self.pending_patch = self.edit.is_some();
+    }
+
+    pub fn discard_edit(&mut self) -> Option<E> {
+        self.edit.take()
+    }
+
+    /// 已确认、等待 patch 的修改
+    pub fn confirmed_edit(&self) -> Option<&E> {
+        self.edit.as_ref().filter(|_| self.pending_patch)
+    }
+
+    /// patch 完成：成功时取出修改并重新加载；失败时保留修改以便重试
+    pub fn finish_patch(&mut self, result: anyhow::Result<String>) -> Result<Option<E>, String> {
+        self.pending_patch = false;
+        match result {
+            Ok(_) => {
+                self.pending = true;
+                Ok(self.edit.take())
+            }
+            Err(e) => Err(e.to_string()),
+        }
+    }
+}
+
+/// 可编辑的详情视图，主循环按同一流程 patch 并重新加载
+pub trait EditableView {
+    type Detail;
+
+    fn load_pending(&self) -> bool;
+
+    fn set_result(&mut self, result: anyhow::Result<Self::Detail>);
+
+    fn set_patch_result(&mut self, result: anyhow::Result<String>);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lifecycle() {
+        let mut lifecycle: DetailLifecycle<u32, String, &str> = DetailLifecycle::default();
+        assert!(lifecycle.pending);
+        assert_eq!(lifecycle.set_result(Ok(1)), Some(&1));
+        assert!(!lifecycle.pending);
+
+        // 加载失败保留上次内容
+        lifecycle.reload();
+        assert_eq!(lifecycle.set_result(Err(anyhow::anyhow!("timeout"))), None);
+        assert_eq!(lifecycle.detail, Some(1));
+        assert_eq!(lifecycle.error.as_deref(), Some("timeout"));
+
+        // 没有修改时确认不会触发 patch
+        lifecycle.confirm_edit();
+        assert!(lifecycle.confirmed_edit().is_none());
+
+        lifecycle.set_edit("port: 8080");
+        assert!(lifecycle.confirmed_edit().is_none());
+        lifecycle.confirm_edit();
+        assert_eq!(lifecycle.confirmed_edit(), Some(&"port: 8080"));
+
+        assert_eq!(
+            lifecycle.finish_patch(Err(anyhow::anyhow!("forbidden"))),
+            Err("forbidden".to_string())
+        );
+        assert!(!lifecycle.pending_patch);
+        assert!(lifecycle.edit.is_some());
+
+        lifecycle.confirm_edit();
+        assert_eq!(
+            lifecycle.finish_patch(Ok("patched".to_string())),
+            Ok(Some("port: 8080"))
+        );
+        assert!(lifecycle.edit.is_none());
+        assert!(lifecycle.pending);
+    }
+}
@@ -0,0 +1,129 @@
+//! Line diff shown before patching an edited value back to the cluster
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum DiffLine {
+    Same(String),
+    Added(String),
+    Removed(String),
+}
+
+/// 参与最长公共子序列计算的最大行数乘积；超过时把中间部分整体视为替换
+const LCS_MAX_CELLS: usize = 1_000_000;
+
+/// 逐行对比：先去掉相同的开头和结尾，中间部分用最长公共子序列对比
+pub fn line_diff(old: &str, new: &str) -> Vec<DiffLine> {
+    let old: Vec<&str> = old.lines().collect();
+    let new: Vec<&str> = new.lines().collect();
+
+    let prefix = old.iter().zip(&new).take_while(|(a, b)| a == b).count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    let mut diff: Vec<DiffLine> = old[..prefix]
+        .iter()
+        .map(|line| DiffLine::Same(line.to_string()))
+        .collect();
+    diff.extend(middle_diff(
+        &old[prefix..old.len() - suffix],
+        &new[prefix..new.len() - suffix],
+    ));
+    diff.extend(
+        old[old.len() - suffix..]
+            .iter()
+            .map(|line| DiffLine::Same(line.to_string())),
+    );
+    diff
+}
+
+fn middle_diff(old: &[&str], new: &[&str]) -> Vec<DiffLine> {
+    // 太大时不计算对齐，避免在界面线程上分配巨大的表
+    if old.len().saturating_mul(new.len()) > LCS_MAX_CELLS {
+        let removed = old.iter().map(|line| DiffLine::Removed(line.to_string()));
+        let added = new.iter().map(|line| DiffLine::Added(line.to_string()));
+        return removed.chain(added).collect();
+    }
+
+    // lcs[i][j]：old[i..] 与 new[j..] 的最长公共子序列长度
+    let mut lcs = vec![vec![0u32; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let (mut i, mut j) = (0, 0);
+    let mut diff = Vec::new();
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            diff.push(DiffLine::Same(old[i].to_string()));
+            i += 1;
+            j += 1;
+        } else if i < old.len() && (j == new.len() || lcs[i + 1][j] >= lcs[i][j + 1]) {
+            // 删除行排在对应的新增行之前
+            diff.push(DiffLine::Removed(old[i].to_string()));
+            i += 1;
+        } else {
+            diff.push(DiffLine::Added(new[j].to_string()));
+            j += 1;
+        }
+    }
+    diff
+}
+
+/// 新增与删除的行数
+pub fn diff_stats(diff: &[DiffLine]) -> (usize, usize) {
+    diff.iter()
+        .fold((0, 0), |(added, removed), line| match line {
+            DiffLine::Added(_) => (added + 1, removed),
+            DiffLine::Removed(_) => (added, removed + 1),
+            DiffLine::Same(_) => (added, removed),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_line_diff() {
+        let diff = line_diff("a\nb\nc\n", "a\nB\nc\nd\n");
+        assert_eq!(
+            diff,
+            vec![
+                DiffLine::Same("a".to_string()),
+                DiffLine::Removed("b".to_string()),
+                DiffLine::Added("B".to_string()),
+                DiffLine::Same("c".to_string()),
+                DiffLine::Added("d".to_string()),
+            ]
+        );
+        assert_eq!(diff_stats(&diff), (2, 1));
+        assert_eq!(diff_stats(&line_diff("x\ny", "x\ny")), (0, 0));
+        assert_eq!(
+            line_diff("", "new"),
+            vec![DiffLine::Added("new".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_large_diff_is_bounded() {
+        // 相同的开头和结尾不参与计算
+        let old: String = (0..20_000).map(|i| format!("line {}\n", i)).collect();
+        let new = old.replacen("line 10000\n", "changed\n", 1);
+        let diff = line_diff(&old, &new);
+        assert_eq!(diff.len(), 20_001);
+        assert_eq!(diff_stats(&diff), (1, 1));
+
+        // 中间部分过大时整体替换，行数统计仍然正确
+        let new: String = (0..20_000).map(|i| format!("new {}\n", i)).collect();
+        assert_eq!(diff_stats(&line_diff(&old, &new)), (20_000, 20_000));
+    }
+}
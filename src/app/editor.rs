@@ -0,0 +1,42 @@
+//! Edit a value in `$VISUAL` / `$EDITOR` through a temporary file
+
+use std::process::Command;
+
+use anyhow::{Result, anyhow};
+
+use crate::kubectl::temp_file::PrivateTempFile;
+
+/// `$VISUAL`、`$EDITOR`，都未设置时为 vi；允许带参数，如 `code --wait`
+pub fn editor_command() -> Vec<String> {
+    ["VISUAL", "EDITOR"]
+        .iter()
+        .filter_map(|var| std::env::var(var).ok())
+        .map(|command| {
+            command
+                .split_whitespace()
+                .map(str::to_string)
+                .collect::<Vec<_>>()
+        })
+        .find(|args| !args.is_empty())
+        .unwrap_or_else(|| vec!["vi".to_string()])
+}
+
+/// 写入临时文件并阻塞等待编辑器退出，返回编辑后的内容；调用方需先让出终端。
+/// 临时文件中可能是解码后的 Secret，只有当前用户可读写
+pub fn edit(text: &str, file_name: &str) -> Result<String> {
+    let file = PrivateTempFile::new(file_name, text)?;
+
+    let command = editor_command();
+    let status = Command::new(&command[0])
+        .args(&command[1..])
+        .arg(file.path())
+        .status();
+    let edited = file.read();
+    drop(file);
+
+    let status = status.map_err(|e| anyhow!("Failed to run {}: {}", command[0], e))?;
+    if !status.success() {
+        return Err(anyhow!("{} exited with {}", command[0], status));
+    }
+    Ok(edited?)
+}
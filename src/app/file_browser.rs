@@ -0,0 +1,271 @@
+//! Remote filesystem browser and `kubectl cp` transfers
+
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
+use crate::kubectl::types::RemoteFile;
+
+/// 树中的一个可见节点
+#[derive(Debug, Clone, PartialEq)]
+pub struct FileNode {
+    pub file: RemoteFile,
+    pub path: String,
+    pub depth: usize,
+    pub expanded: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum FileBrowserPrompt {
+    Download,
+    Upload,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TransferStatus {
+    Running,
+    Done,
+    Failed(String),
+}
+
+/// 等待主循环启动的 kubectl cp
+#[derive(Debug, Clone, PartialEq)]
+pub struct TransferRequest {
+    pub upload: bool,
+    pub remote_path: String,
+    pub local_path: String,
+}
+
+/// 正在进行或已结束的传输，status 由后台线程更新
+#[derive(Debug, Clone)]
+pub struct FileTransfer {
+    pub request: TransferRequest,
+    pub started: Instant,
+    pub finished: Option<Instant>,
+    /// 下载时为远端大小（目录未知），上传时为本地大小
+    pub total_bytes: Option<u64>,
+    /// 下载时定期统计本地已写入的字节数
+    pub transferred_bytes: u64,
+    pub status: Arc<Mutex<TransferStatus>>,
+}
+
+impl FileTransfer {
+    pub fn status(&self) -> TransferStatus {
+        self.status
+            .lock()
+            .map(|s| s.clone())
+            .unwrap_or(TransferStatus::Running)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct FileBrowser {
+    pub pod_name: String,
+    pub containers: Vec<String>,
+    pub container: Option<String>,
+    pub root: String,
+    pub nodes: Vec<FileNode>,
+    pub selected: usize,
+    /// 等待主循环加载的目录
+    pub pending_list: Option<String>,
+    pub loading: bool,
+    pub prompt: Option<FileBrowserPrompt>,
+    pub input: String,
+    pub pending_transfer: Option<TransferRequest>,
+    pub transfer: Option<FileTransfer>,
+    pub error: Option<String>,
+}
+
+impl FileBrowser {
+    pub fn new(pod_name: &str, containers: Vec<String>) -> Self {
+        let container = containers.first().cloned();
+        Self {
+            pod_name: pod_name.to_string(),
+            containers,
+            container,
+            root: "/".to_string(),
+            nodes: Vec::new(),
+            selected: 0,
+            pending_list: Some("/".to_string()),
+            loading: true,
+            prompt: None,
+            input: String::new(),
+            pending_transfer: None,
+            transfer: None,
+            error: None,
+        }
+    }
+
+    pub fn selected_node(&self) -> Option<&FileNode> {
+        self.nodes.get(self.selected)
+    }
+
+    pub fn request_list(&mut self, path: &str) {
+        self.pending_list = Some(path.to_string());
+        self.loading = true;
+    }
+
+    // 节点后面紧跟的子孙节点范围
+    fn descendants_end(&self, index: usize) -> usize {
+        let depth = self.nodes[index].depth;
+        self.nodes[index + 1..]
+            .iter()
+            .position(|n| n.depth <= depth)
+            .map(|offset| index + 1 + offset)
+            .unwrap_or(self.nodes.len())
+    }
+
+    /// 目录加载完成：根目录替换整棵树，其它目录展开到其节点下方
+    pub fn set_children(&mut self, dir: &str, files: Vec<RemoteFile>) {
+        self.loading = false;
+        self.error = None;
+        let (insert_at, depth) = if dir == self.root {
+            self.nodes.clear();
+            self.selected = 0;
+            (0, 0)
+        } else {
+            let Some(index) = self.nodes.iter().position(|n| n.path == dir) else {
+                return;
+            };
+            let end = self.descendants_end(index);
+            self.nodes.drain(index + 1..end);
+            self.nodes[index].expanded = true;
+            (index + 1, self.nodes[index].depth + 1)
+        };
+        let children = files.into_iter().map(|file| FileNode {
+            path: join_remote(dir, &file.name),
+            file,
+            depth,
+            expanded: false,
+        });
+        self.nodes.splice(insert_at..insert_at, children);
+        self.selected = self.selected.min(self.nodes.len().saturating_sub(1));
+    }
+
+    pub fn collapse(&mut self, index: usize) {
+        if index >= self.nodes.len() {
+            return;
+        }
+        let end = self.descendants_end(index);
+        self.nodes.drain(index + 1..end);
+        self.nodes[index].expanded = false;
+        self.selected = self.selected.min(self.nodes.len().saturating_sub(1));
+    }
+
+    /// 选中项的父目录节点
+    pub fn parent_index(&self, index: usize) -> Option<usize> {
+        let depth = self.nodes.get(index)?.depth;
+        self.nodes[..index]
+            .iter()
+            .rposition(|n| n.depth + 1 == depth)
+    }
+
+    /// 上传目标目录：选中目录本身，或选中文件所在目录
+    pub fn upload_dir(&self) -> String {
+        match self.selected_node() {
+            Some(node) if node.file.is_dir => node.path.clone(),
+            Some(node) => parent_remote(&node.path),
+            None => self.root.clone(),
+        }
+    }
+}
+
+pub fn join_remote(dir: &str, name: &str) -> String {
+    format!("{}/{}", dir.trim_end_matches('/'), name)
+}
+
+pub fn parent_remote(path: &str) -> String {
+    match path.trim_end_matches('/').rsplit_once('/') {
+        Some(("", _)) | None => "/".to_string(),
+        Some((parent, _)) => parent.to_string(),
+    }
+}
+
+/// 本地文件或目录的总字节数
+pub fn local_size(path: &Path) -> u64 {
+    let Ok(metadata) = std::fs::symlink_metadata(path) else {
+        return 0;
+    };
+    if !metadata.is_dir() {
+        return metadata.len();
+    }
+    std::fs::read_dir(path)
+        .map(|entries| {
+            entries
+                .filter_map(|e| e.ok())
+                .map(|e| local_size(&e.path()))
+                .sum()
+        })
+        .unwrap_or(0)
+}
+
+pub fn format_size(bytes: u64) -> String {
+    const UNITS: [&str; 5] = ["B", "KiB", "MiB", "GiB", "TiB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{} B", bytes)
+    } else {
+        format!("{:.1} {}", value, UNITS[unit])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn file(name: &str, is_dir: bool) -> RemoteFile {
+        RemoteFile {
+            name: name.to_string(),
+            permissions: if is_dir { "drwxr-xr-x" } else { "-rw-r--r--" }.to_string(),
+            size: 10,
+            modified: "Jan 1 00:00".to_string(),
+            is_dir,
+            link_target: None,
+        }
+    }
+
+    #[test]
+    fn test_expand_and_collapse() {
+        let mut browser = FileBrowser::new("web", vec!["app".to_string()]);
+        browser.set_children("/", vec![file("etc", true), file("tmp", true)]);
+        assert_eq!(browser.nodes.len(), 2);
+
+        browser.set_children("/etc", vec![file("hosts", false), file("ssl", true)]);
+        browser.set_children("/etc/ssl", vec![file("cert.pem", false)]);
+        let paths: Vec<_> = browser.nodes.iter().map(|n| n.path.as_str()).collect();
+        assert_eq!(
+            paths,
+            vec![
+                "/etc",
+                "/etc/hosts",
+                "/etc/ssl",
+                "/etc/ssl/cert.pem",
+                "/tmp"
+            ]
+        );
+        assert_eq!(browser.nodes[3].depth, 2);
+        assert_eq!(browser.parent_index(3), Some(2));
+
+        browser.selected = 1;
+        assert_eq!(browser.upload_dir(), "/etc");
+
+        browser.collapse(0);
+        assert_eq!(browser.nodes.len(), 2);
+        assert!(!browser.nodes[0].expanded);
+    }
+
+    #[test]
+    fn test_remote_paths() {
+        assert_eq!(join_remote("/", "etc"), "/etc");
+        assert_eq!(join_remote("/etc/", "hosts"), "/etc/hosts");
+        assert_eq!(parent_remote("/etc/hosts"), "/etc");
+        assert_eq!(parent_remote("/etc"), "/");
+        assert_eq!(format_size(512), "512 B");
+        assert_eq!(format_size(1536), "1.5 KiB");
+    }
+}
@@ -1,14 +1,85 @@
-use super::state::{ActivePane, AppMode, AppState, ConfirmAction};
+use super::clipboard;
+use super::config::ExecPreference;
+use super::configmap_view::ConfigMapView;
+use super::file_browser::{FileBrowser, FileBrowserPrompt, TransferRequest, TransferStatus};
+use super::multi_log::MultiLogView;
+use super::node_detail::NodeDetailView;
+use super::node_drain::{DRAIN_OPTION_ROWS, DrainDialog, NodeAction};
+use super::secret_view::{SecretView, display_value};
+use super::state::{
+    ActivePane, AppMode, AppState, ConfirmAction, ExecPicker, ExecPickerStep, ExecRequest,
+    LOG_OPTION_ROWS, LogOptionsDialog,
+};
+use super::terminal::{TerminalSession, is_focus_toggle_key};
 use anyhow::Result;
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
 impl AppState {
     pub fn handle_key_event(&mut self, key_event: KeyEvent) -> Result<()> {
+        // 终端会话获得焦点时，除 Ctrl+] 外的按键全部交给 shell
+        if self.mode == AppMode::Terminal && self.terminal_focus {
+            return self.handle_terminal_focus_key_event(key_event);
+        }
+
+        // 文件浏览器独占按键（含路径输入）
+        if self.mode == AppMode::FileBrowser {
+            return self.handle_file_browser_key_event(key_event);
+        }
+
         // 处理搜索模式
         if self.search_mode {
             return self.handle_search_key_event(key_event);
         }
 
+        // 处理日志搜索输入
+        if self.log_search_mode {
+            return self.handle_log_search_key_event(key_event);
+        }
+
+        // 处理 YAML / Describe 视图的搜索输入
+        if self.content_search.input {
+            return self.handle_content_search_key_event(key_event);
+        }
+
+        // 处理展开的日志行
+        if self.log_expanded.is_some() && self.mode == AppMode::Logs {
+            match key_event.code {
+                KeyCode::Esc | KeyCode::Enter | KeyCode::Char('e') => self.log_expanded = None,
+                KeyCode::Char('j') | KeyCode::Down => self.log_expanded_scroll += 1,
+                KeyCode::Char('k') | KeyCode::Up => {
+                    self.log_expanded_scroll = self.log_expanded_scroll.saturating_sub(1)
+                }
+                KeyCode::Char('q') => self.should_quit = true,
+                _ => {}
+            }
+            return Ok(());
+        }
+
+        // 处理 exec 弹窗
+        if self.exec_picker.is_some() {
+            return self.handle_exec_picker_key_event(key_event);
+        }
+
+        // 处理聚合日志的选择器输入
+        if self.multi_log_selector_input.is_some() {
+            return self.handle_multi_log_selector_key_event(key_event);
+        }
+
+        // 处理日志选项弹窗
+        if self.log_options_dialog.is_some() {
+            return self.handle_log_options_key_event(key_event);
+        }
+
+        // 处理排空选项弹窗
+        if self.drain_dialog.is_some() {
+            return self.handle_drain_dialog_key_event(key_event);
+        }
+
+        // 处理保存弹窗
+        if self.save_dialog.is_some() {
+            return self.handle_save_dialog_key_event(key_event);
+        }
+
         // 处理确认对话框
         if self.confirm_action.is_some() {
             return self.handle_confirm_key_event(key_event);
@@ -19,8 +90,47 @@ impl AppState {
             return self.handle_split_pod_selection_key_event(key_event);
         }
 
+        if self.mode == AppMode::Terminal && self.handle_terminal_view_key_event(key_event) {
+            return Ok(());
+        }
+
+        if self.mode == AppMode::MultiLogs && self.handle_multi_log_key_event(key_event) {
+            return Ok(());
+        }
+
+        if self.mode == AppMode::LogCaptures && self.handle_log_capture_key_event(key_event) {
+            return Ok(());
+        }
+
+        if self.mode == AppMode::NodeDrain && self.handle_node_drain_key_event(key_event) {
+            return Ok(());
+        }
+
+        if self.mode == AppMode::NodeDetail && self.handle_node_detail_key_event(key_event) {
+            return Ok(());
+        }
+
+        if self.mode == AppMode::YamlView && self.handle_yaml_tree_key_event(key_event) {
+            return Ok(());
+        }
+
+        if self.mode == AppMode::Describe && self.handle_describe_outline_key_event(key_event) {
+            return Ok(());
+        }
+
+        if self.mode == AppMode::SecretDetail && self.handle_secret_view_key_event(key_event) {
+            return Ok(());
+        }
+
+        if self.mode == AppMode::ConfigMapDetail && self.handle_configmap_view_key_event(key_event)
+        {
+            return Ok(());
+        }
+
+        let content_view = matches!(self.mode, AppMode::YamlView | AppMode::Describe);
         match key_event.code {
             KeyCode::Char('q') => self.should_quit = true,
+            KeyCode::Char('`') => self.open_terminal_view(),
             KeyCode::Char('?') | KeyCode::F(1) => self.mode = AppMode::Help,
             KeyCode::Esc => {
                 if self.batch_mode {
@@ -31,6 +141,15 @@ impl AppState {
                     self.split_log_mode = false;
                     return Ok(());
                 }
+                // 先清除日志搜索，再返回
+                if self.mode == AppMode::Logs && !self.log_search_query.is_empty() {
+                    self.clear_log_search();
+                    return Ok(());
+                }
+                if content_view && self.content_search.is_active() {
+                    self.content_search.clear();
+                    return Ok(());
+                }
                 match self.mode {
                     AppMode::Help
                     | AppMode::Logs
@@ -121,10 +240,57 @@ impl AppState {
                 }
             }
             KeyCode::Char('L') => self.handle_logs(), // L 查看日志
+            KeyCode::Char('S') if self.mode == AppMode::PodList => {
+                self.multi_log_selector_input = Some(String::new());
+            }
+            // 后台日志捕获
+            KeyCode::Char('C') if self.mode == AppMode::PodList => self.toggle_log_capture(),
+            KeyCode::Char('B') => self.open_log_captures(),
+            KeyCode::Char('f') if self.mode == AppMode::Logs => self.toggle_log_streaming(),
+            KeyCode::Char('p') if self.mode == AppMode::Logs => self.toggle_logs_pause(),
+            KeyCode::Char('o') if self.mode == AppMode::Logs => self.open_log_options(),
+            // 保存到文件：w 当前缓冲区，W 不带 --tail 的完整日志或精简的 YAML 清单
+            KeyCode::Char('w')
+                if matches!(
+                    self.mode,
+                    AppMode::Logs | AppMode::Describe | AppMode::YamlView
+                ) =>
+            {
+                self.open_save_dialog(false)
+            }
+            KeyCode::Char('W') if matches!(self.mode, AppMode::Logs | AppMode::YamlView) => {
+                self.open_save_dialog(true)
+            }
+            // 节点维护
+            KeyCode::Char('C') if self.mode == AppMode::NodeList => self.toggle_node_cordon(),
+            KeyCode::Char('D') if self.mode == AppMode::NodeList => self.open_drain(),
             KeyCode::Char('D') => self.handle_delete(), // D 删除（需确认）
-            KeyCode::Char('E') => self.handle_exec(), // E 进入容器
+            KeyCode::Char('E') => self.handle_exec(),   // E 进入容器
+            KeyCode::Char('F') => self.handle_file_browser(), // F 浏览容器文件
             KeyCode::Char('Y') => self.handle_yaml_view(), // Y 查看YAML配置
             KeyCode::Char('T') => self.handle_top_view(), // T 查看资源使用
+            // 日志搜索与 grep 过滤
+            KeyCode::Char('/') if self.mode == AppMode::Logs => self.log_search_mode = true,
+            KeyCode::Char('n') if self.mode == AppMode::Logs => self.log_search_next(),
+            KeyCode::Char('N') if self.mode == AppMode::Logs => self.log_search_previous(),
+            KeyCode::Char('g') if self.mode == AppMode::Logs => self.toggle_log_grep_mode(),
+            KeyCode::Char('+') if self.mode == AppMode::Logs => self.adjust_log_grep_context(true),
+            KeyCode::Char('-') if self.mode == AppMode::Logs => self.adjust_log_grep_context(false),
+            // 结构化日志
+            KeyCode::Char('s') if self.mode == AppMode::TopView && self.top_nodes => {
+                self.cycle_node_metrics_sort()
+            }
+            KeyCode::Char('s') if self.mode == AppMode::Logs => {
+                self.log_structured = !self.log_structured
+            }
+            KeyCode::Char('v') if self.mode == AppMode::Logs => self.cycle_log_level_filter(),
+            KeyCode::Char('e') if self.mode == AppMode::Logs => self.toggle_log_expanded(),
+            KeyCode::Char('c') if self.mode == AppMode::Logs => self.log_ansi = !self.log_ansi,
+            KeyCode::Char('c') if self.mode == AppMode::YamlView => self.toggle_yaml_neat(),
+            // YAML / Describe 内容搜索
+            KeyCode::Char('/') if content_view => self.content_search.input = true,
+            KeyCode::Char('n') if content_view => self.content_search_step(true),
+            KeyCode::Char('N') if content_view => self.content_search_step(false),
             // 搜索
             KeyCode::Char('/') => self.start_search(),
             KeyCode::Char('n') => self.search_next(),
@@ -267,6 +433,56 @@ impl AppState {
         }
     }
 
+    // 日志搜索输入：实时匹配，Ctrl+R 切换正则，Ctrl+T 切换大小写敏感
+    fn handle_log_search_key_event(&mut self, key_event: KeyEvent) -> Result<()> {
+        let ctrl = key_event.modifiers.contains(KeyModifiers::CONTROL);
+        match key_event.code {
+            KeyCode::Esc => self.clear_log_search(),
+            KeyCode::Enter => self.log_search_mode = false,
+            KeyCode::Char('r') if ctrl => {
+                self.log_search_regex = !self.log_search_regex;
+                self.update_log_search_pattern();
+                self.select_nearest_log_match();
+            }
+            KeyCode::Char('t') if ctrl => {
+                self.log_search_case_sensitive = !self.log_search_case_sensitive;
+                self.update_log_search_pattern();
+                self.select_nearest_log_match();
+            }
+            KeyCode::Backspace => {
+                self.log_search_query.pop();
+                self.update_log_search_pattern();
+                self.select_nearest_log_match();
+            }
+            KeyCode::Char(c) if !ctrl => {
+                self.log_search_query.push(c);
+                self.update_log_search_pattern();
+                self.select_nearest_log_match();
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    // YAML / Describe 搜索输入：实时匹配并跳转到匹配行
+    fn handle_content_search_key_event(&mut self, key_event: KeyEvent) -> Result<()> {
+        match key_event.code {
+            KeyCode::Esc => self.content_search.clear(),
+            KeyCode::Enter => self.content_search.input = false,
+            KeyCode::Backspace => {
+                let mut query = self.content_search.query.clone();
+                query.pop();
+                self.update_content_search(query);
+            }
+            KeyCode::Char(c) if !key_event.modifiers.contains(KeyModifiers::CONTROL) => {
+                let query = format!("{}{}", self.content_search.query, c);
+                self.update_content_search(query);
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
     // 搜索事件处理
     fn handle_search_key_event(&mut self, key_event: KeyEvent) -> Result<()> {
         match key_event.code {
@@ -629,49 +845,321 @@ impl AppState {
         match self.mode {
             AppMode::NamespaceList => {
                 if let Some(namespace) = self.namespaces.get(self.selected_namespace_index) {
-                    self.current_namespace = namespace.clone();
-                    self.mode = AppMode::PodList;
-                    self.selected_pod_index = 0;
-                    // 清理所有缓存数据，强制刷新
-                    self.pods.clear();
-                    self.services.clear();
-                    self.deployments.clear();
-                    self.jobs.clear();
-                    self.daemonsets.clear();
-                    self.pvcs.clear();
-                    self.configmaps.clear();
-                    self.secrets.clear();
-                    self.logs.clear();
-                    self.describe_content.clear();
-                    // 重置选中索引
-                    self.selected_service_index = 0;
-                    self.selected_deployment_index = 0;
-                    self.selected_job_index = 0;
-                    self.selected_daemonset_index = 0;
-                    self.selected_configmap_index = 0;
-                    self.selected_secret_index = 0;
-                    self.selected_pvc_index = 0;
-                    self.selected_pv_index = 0;
-                    self.selected_node_index = 0;
+                    self.switch_namespace(namespace.clone());
                 }
             }
+            // 节点列表中 Enter 打开容量/分配视图，Space 仍为 describe
+            AppMode::NodeList => self.open_node_detail(),
+            // Secret 列表中 Enter 查看解码后的值
+            AppMode::SecretList => self.open_secret_view(),
+            AppMode::ConfigMapList => self.open_configmap_view(),
             // 在资源列表模式下，Enter键也可以进入Describe模式
             AppMode::PodList
             | AppMode::ServiceList
-            | AppMode::NodeList
             | AppMode::DeploymentList
             | AppMode::JobList
             | AppMode::DaemonSetList
             | AppMode::PVCList
-            | AppMode::PVList
-            | AppMode::ConfigMapList
-            | AppMode::SecretList => {
+            | AppMode::PVList => {
                 self.handle_describe();
             }
             _ => {}
         }
     }
 
+    /// 切换到命名空间的 Pod 列表
+    pub fn switch_namespace(&mut self, namespace: String) {
+        self.current_namespace = namespace;
+        self.mode = AppMode::PodList;
+        self.selected_pod_index = 0;
+        // 清理所有缓存数据，强制刷新
+        self.pods.clear();
+        self.services.clear();
+        self.deployments.clear();
+        self.jobs.clear();
+        self.daemonsets.clear();
+        self.pvcs.clear();
+        self.configmaps.clear();
+        self.secrets.clear();
+        self.clear_logs();
+        self.describe_content.clear();
+        // 重置选中索引
+        self.selected_service_index = 0;
+        self.selected_deployment_index = 0;
+        self.selected_job_index = 0;
+        self.selected_daemonset_index = 0;
+        self.selected_configmap_index = 0;
+        self.selected_secret_index = 0;
+        self.selected_pvc_index = 0;
+        self.selected_pv_index = 0;
+        self.selected_node_index = 0;
+    }
+
+    pub fn open_node_detail(&mut self) {
+        if let Some(node) = self.get_selected_node() {
+            self.node_detail = Some(NodeDetailView::new(&node.name));
+            self.previous_mode = AppMode::NodeList;
+            self.mode = AppMode::NodeDetail;
+        }
+    }
+
+    fn handle_node_detail_key_event(&mut self, key_event: KeyEvent) -> bool {
+        let Some(view) = self.node_detail.as_mut() else {
+            return false;
+        };
+        match key_event.code {
+            KeyCode::Char('j') | KeyCode::Down => view.select_next(),
+            KeyCode::Char('k') | KeyCode::Up => view.select_previous(),
+            KeyCode::Char('R') => view.lifecycle.reload(),
+            // 跳转到 Pod 所在命名空间的列表并选中它
+            KeyCode::Enter => {
+                if let Some(pod) = view.selected_pod() {
+                    let (namespace, name) = (pod.namespace.clone(), pod.name.clone());
+                    let node_index = self.selected_node_index;
+                    self.switch_namespace(namespace);
+                    self.selected_node_index = node_index;
+                    self.pending_pod_focus = Some(name);
+                    self.node_detail = None;
+                }
+            }
+            KeyCode::Esc => {
+                self.node_detail = None;
+                self.mode = AppMode::NodeList;
+            }
+            _ => return false,
+        }
+        true
+    }
+
+    pub fn open_secret_view(&mut self) {
+        if let Some(secret) = self.get_selected_secret() {
+            self.secret_view = Some(SecretView::new(&secret.namespace, &secret.name));
+            self.previous_mode = AppMode::SecretList;
+            self.mode = AppMode::SecretDetail;
+        }
+    }
+
+    fn handle_secret_view_key_event(&mut self, key_event: KeyEvent) -> bool {
+        let Some(view) = self.secret_view.as_mut() else {
+            return false;
+        };
+        // 编辑器返回后先确认修改；应用前逐键写审计记录
+        if let Some(changes) = &view.lifecycle.edit {
+            match key_event.code {
+                KeyCode::Char('y') | KeyCode::Enter => {
+                    let result = changes.iter().try_for_each(|change| {
+                        self.audit_log
+                            .record("secret.patch", &view.audit_target(&change.key))
+                    });
+                    match result {
+                        Ok(()) => view.lifecycle.confirm_edit(),
+                        Err(e) => view.message = Some(Err(format!("audit log: {}", e))),
+                    }
+                }
+                KeyCode::Char('n') | KeyCode::Esc => view.discard_edit(),
+                _ => {}
+            }
+            return true;
+        }
+        match key_event.code {
+            KeyCode::Char('j') | KeyCode::Down => view.select_next(),
+            KeyCode::Char('k') | KeyCode::Up => view.select_previous(),
+            KeyCode::Char('R') => view.lifecycle.reload(),
+            // 编辑器中是所有文本键的明文，打开前写审计记录
+            KeyCode::Char('e') => {
+                let target = format!("{}/{}", view.namespace, view.name);
+                let result = view.request_edit().and_then(|()| {
+                    self.audit_log
+                        .record("secret.edit", &target)
+                        .map_err(|e| format!("audit log: {}", e))
+                });
+                if let Err(e) = result {
+                    view.lifecycle.pending_edit = None;
+                    view.message = Some(Err(e));
+                }
+            }
+            // 显示明文前先写审计记录，写入失败则不显示
+            KeyCode::Char('r') => {
+                if let Some(key) = view.selected_entry().map(|e| e.key.clone()) {
+                    if view.is_revealed(&key) {
+                        view.toggle_reveal();
+                    } else {
+                        match self
+                            .audit_log
+                            .record("secret.reveal", &view.audit_target(&key))
+                        {
+                            Ok(()) => {
+                                view.toggle_reveal();
+                                view.message = None;
+                            }
+                            Err(e) => view.message = Some(Err(format!("audit log: {}", e))),
+                        }
+                    }
+                }
+            }
+            KeyCode::Char('c') => {
+                if let Some(entry) = view.selected_entry() {
+                    let (key, value) = (entry.key.clone(), display_value(entry));
+                    let result = self
+                        .audit_log
+                        .record("secret.copy", &view.audit_target(&key))
+                        .and_then(|()| clipboard::copy(&value));
+                    view.message = Some(match result {
+                        Ok(method) if self.language_chinese => {
+                            Ok(format!("已复制 {} ({})", key, method))
+                        }
+                        Ok(method) => Ok(format!("Copied {} ({})", key, method)),
+                        Err(e) => Err(e.to_string()),
+                    });
+                }
+            }
+            KeyCode::Esc => {
+                self.secret_view = None;
+                self.mode = AppMode::SecretList;
+            }
+            _ => return false,
+        }
+        true
+    }
+
+    pub fn open_configmap_view(&mut self) {
+        if let Some(configmap) = self.get_selected_configmap() {
+            self.configmap_view = Some(ConfigMapView::new(&configmap.namespace, &configmap.name));
+            self.previous_mode = AppMode::ConfigMapList;
+            self.mode = AppMode::ConfigMapDetail;
+        }
+    }
+
+    fn handle_configmap_view_key_event(&mut self, key_event: KeyEvent) -> bool {
+        let Some(view) = self.configmap_view.as_mut() else {
+            return false;
+        };
+        // 编辑器返回后先确认 diff
+        if view.lifecycle.edit.is_some() {
+            match key_event.code {
+                KeyCode::Char('y') | KeyCode::Enter => view.lifecycle.confirm_edit(),
+                KeyCode::Char('n') | KeyCode::Esc => view.discard_edit(),
+                _ => {}
+            }
+            return true;
+        }
+        match key_event.code {
+            KeyCode::Char('j') | KeyCode::Down => view.select_next(),
+            KeyCode::Char('k') | KeyCode::Up => view.select_previous(),
+            KeyCode::Char('J') | KeyCode::PageDown => view.scroll_down(),
+            KeyCode::Char('K') | KeyCode::PageUp => view.scroll_up(),
+            KeyCode::Char('R') => view.lifecycle.reload(),
+            KeyCode::Char('e') => {
+                if let Err(e) = view.request_edit() {
+                    view.message = Some(Err(e));
+                }
+            }
+            KeyCode::Esc => {
+                self.configmap_view = None;
+                self.mode = AppMode::ConfigMapList;
+            }
+            _ => return false,
+        }
+        true
+    }
+
+    /// 当前滚动位置顶部所在的段落
+    fn describe_current_section(&self) -> usize {
+        self.describe_outline
+            .section_at_row(self.describe_scroll)
+            .unwrap_or(0)
+    }
+
+    /// 滚动到段落标题，折叠的段落先展开
+    fn jump_to_describe_section(&mut self, index: usize) {
+        let outline = &mut self.describe_outline;
+        if outline
+            .sections
+            .get(index)
+            .is_some_and(|s| outline.is_folded(s))
+        {
+            outline.toggle(index);
+        }
+        if let Some(row) = outline.header_row(index) {
+            self.describe_scroll = row;
+        }
+    }
+
+    fn handle_describe_outline_key_event(&mut self, key_event: KeyEvent) -> bool {
+        // 段落列表弹窗
+        if let Some(selected) = self.describe_outline.picker {
+            let last = self.describe_outline.sections.len().saturating_sub(1);
+            match key_event.code {
+                KeyCode::Char('j') | KeyCode::Down => {
+                    self.describe_outline.picker = Some((selected + 1).min(last))
+                }
+                KeyCode::Char('k') | KeyCode::Up => {
+                    self.describe_outline.picker = Some(selected.saturating_sub(1))
+                }
+                KeyCode::Char(' ') | KeyCode::Char('z') => self.describe_outline.toggle(selected),
+                KeyCode::Enter => {
+                    self.describe_outline.picker = None;
+                    self.jump_to_describe_section(selected);
+                }
+                KeyCode::Esc | KeyCode::Char('s') => self.describe_outline.picker = None,
+                KeyCode::Char('q') => self.should_quit = true,
+                _ => {}
+            }
+            return true;
+        }
+
+        let current = self.describe_current_section();
+        match key_event.code {
+            KeyCode::Char('s') => self.describe_outline.picker = Some(current),
+            // 折叠/展开顶部所在的段落，并让它的标题留在顶部
+            KeyCode::Char('z') => {
+                self.describe_outline.toggle(current);
+                if let Some(row) = self.describe_outline.header_row(current) {
+                    self.describe_scroll = row;
+                }
+            }
+            KeyCode::Char('Z') => {
+                self.describe_outline.toggle_all();
+                self.describe_scroll = self.describe_outline.header_row(current).unwrap_or(0);
+            }
+            KeyCode::Char(']') => {
+                if let Some(next) = self.describe_outline.adjacent_section(current, true) {
+                    self.jump_to_describe_section(next);
+                }
+            }
+            KeyCode::Char('[') => {
+                // 不在段落标题上时先回到当前段落的标题
+                let at_header =
+                    self.describe_outline.header_row(current) == Some(self.describe_scroll);
+                let target = if at_header {
+                    self.describe_outline.adjacent_section(current, false)
+                } else {
+                    Some(current)
+                };
+                if let Some(target) = target {
+                    self.jump_to_describe_section(target);
+                }
+            }
+            _ => return false,
+        }
+        true
+    }
+
+    fn handle_yaml_tree_key_event(&mut self, key_event: KeyEvent) -> bool {
+        let Some(line) = self.yaml_cursor_line() else {
+            return false;
+        };
+        match key_event.code {
+            KeyCode::Char('z') => self.yaml_tree.toggle(line),
+            KeyCode::Char('Z') => self.yaml_tree.toggle_all(),
+            KeyCode::Char('S') => self.yaml_tree.focus_spec(),
+            _ => return false,
+        }
+        // 光标所在行被折叠时停在包含它的节点上
+        self.yaml_scroll = self.yaml_tree.visible_row(line);
+        true
+    }
+
     pub fn handle_describe(&mut self) {
         match self.mode {
             AppMode::PodList
@@ -688,6 +1176,8 @@ impl AppState {
                 self.reset_scroll();
                 // 清理之前的describe内容
                 self.describe_content.clear();
+                self.describe_outline.picker = None;
+                self.content_search.clear();
                 self.mode = AppMode::Describe;
                 // 默认为鼠标滚动模式，方便快速浏览内容
                 self.text_selection_mode = false;
@@ -699,12 +1189,237 @@ impl AppState {
     pub fn handle_logs(&mut self) {
         match self.mode {
             AppMode::PodList => {
+                // 选项在会话内保留，但指定的容器可能不属于这个 Pod
+                if let Some(container) = &self.log_options.container
+                    && self
+                        .get_selected_pod()
+                        .is_some_and(|pod| !pod.containers.contains(container))
+                {
+                    self.log_options.container = None;
+                }
                 self.previous_mode = self.mode.clone();
                 self.reset_scroll();
                 self.mode = AppMode::Logs;
             }
+            // 工作负载按其选择器聚合所有 Pod 的日志
+            AppMode::DeploymentList => {
+                if let Some(d) = self.get_selected_deployment() {
+                    let (selector, label) = (d.selector.clone(), format!("Deployment/{}", d.name));
+                    self.open_multi_log(&selector, &label);
+                }
+            }
+            AppMode::JobList => {
+                if let Some(job) = self.get_selected_job() {
+                    let (selector, label) = (job.selector.clone(), format!("Job/{}", job.name));
+                    self.open_multi_log(&selector, &label);
+                }
+            }
+            AppMode::DaemonSetList => {
+                if let Some(ds) = self.get_selected_daemonset() {
+                    let (selector, label) = (ds.selector.clone(), format!("DaemonSet/{}", ds.name));
+                    self.open_multi_log(&selector, &label);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    pub fn open_multi_log(&mut self, selector: &str, label: &str) {
+        // 空选择器会匹配命名空间内所有 Pod
+        if selector.is_empty() {
+            return;
+        }
+        self.multi_log = Some(MultiLogView::new(&self.current_namespace, selector, label));
+        self.previous_mode = self.mode.clone();
+        self.mode = AppMode::MultiLogs;
+    }
+
+    fn handle_multi_log_selector_key_event(&mut self, key_event: KeyEvent) -> Result<()> {
+        let Some(input) = self.multi_log_selector_input.as_mut() else {
+            return Ok(());
+        };
+        match key_event.code {
+            KeyCode::Enter => {
+                let selector = input.trim().to_string();
+                self.multi_log_selector_input = None;
+                self.open_multi_log(&selector, &format!("-l {}", selector));
+            }
+            KeyCode::Esc => self.multi_log_selector_input = None,
+            KeyCode::Backspace => {
+                input.pop();
+            }
+            KeyCode::Char(c) => input.push(c),
+            _ => {}
+        }
+        Ok(())
+    }
+
+    // 聚合日志视图的按键，返回 false 时交给通用处理（q、` 等）
+    fn handle_multi_log_key_event(&mut self, key_event: KeyEvent) -> bool {
+        let Some(view) = self.multi_log.as_mut() else {
+            return false;
+        };
+        let last = view.lines.len().saturating_sub(1);
+        match key_event.code {
+            KeyCode::Char('j') | KeyCode::Down => view.scroll = (view.scroll + 1).min(last),
+            KeyCode::Char('k') | KeyCode::Up => view.scroll = view.scroll.saturating_sub(1),
+            KeyCode::PageDown => view.scroll = (view.scroll + 20).min(last),
+            KeyCode::PageUp => view.scroll = view.scroll.saturating_sub(20),
+            KeyCode::Char('g') | KeyCode::Home => view.scroll = 0,
+            KeyCode::Char('G') | KeyCode::End => view.scroll = last,
+            KeyCode::Char('A') => view.auto_scroll = !view.auto_scroll,
+            KeyCode::Esc => {
+                // 跟随进程由主循环在离开视图后停止
+                self.mode = self.previous_mode.clone();
+                return true;
+            }
+            _ => return false,
+        }
+        // 手动滚动离开底部时暂停自动滚动，回到底部时恢复
+        if !matches!(key_event.code, KeyCode::Char('A')) {
+            view.auto_scroll = view.scroll == last;
+        }
+        true
+    }
+
+    /// 开始/停止捕获选中的 Pod，Pod 被替换后跟随同一 owner 的新 Pod
+    pub fn toggle_log_capture(&mut self) {
+        let Some(pod) = self.get_selected_pod() else {
+            return;
+        };
+        let (name, owner) = (pod.name.clone(), pod.owner.clone());
+        let namespace = self.current_namespace.clone();
+        self.log_captures.toggle(&namespace, &name, owner);
+    }
+
+    /// 从资源列表打开捕获列表
+    pub fn open_log_captures(&mut self) {
+        let from_list = matches!(
+            self.mode,
+            AppMode::NamespaceList
+                | AppMode::PodList
+                | AppMode::ServiceList
+                | AppMode::DeploymentList
+                | AppMode::JobList
+                | AppMode::DaemonSetList
+                | AppMode::PVCList
+                | AppMode::PVList
+                | AppMode::ConfigMapList
+                | AppMode::SecretList
+                | AppMode::NodeList
+        );
+        if from_list {
+            self.previous_mode = self.mode.clone();
+            self.mode = AppMode::LogCaptures;
+        }
+    }
+
+    fn handle_log_capture_key_event(&mut self, key_event: KeyEvent) -> bool {
+        let manager = &mut self.log_captures;
+        let last = manager.captures.len().saturating_sub(1);
+        match key_event.code {
+            KeyCode::Char('j') | KeyCode::Down => {
+                manager.selected = (manager.selected + 1).min(last)
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                manager.selected = manager.selected.saturating_sub(1)
+            }
+            KeyCode::Char('x') => manager.stop(manager.selected),
+            KeyCode::Char('X') => manager.stop_all(),
+            // 离开列表后捕获继续
+            KeyCode::Esc => self.mode = self.previous_mode.clone(),
+            _ => return false,
+        }
+        true
+    }
+
+    /// 已 cordon 的节点 uncordon，否则 cordon
+    pub fn toggle_node_cordon(&mut self) {
+        let Some(node) = self.get_selected_node() else {
+            return;
+        };
+        let action = if node.unschedulable {
+            NodeAction::Uncordon(node.name.clone())
+        } else {
+            NodeAction::Cordon(node.name.clone())
+        };
+        self.pending_node_action = Some(action);
+    }
+
+    /// 排空进行中时回到进度视图，否则打开选项弹窗
+    pub fn open_drain(&mut self) {
+        if self
+            .node_drain
+            .as_ref()
+            .is_some_and(|drain| drain.is_running())
+        {
+            self.previous_mode = self.mode.clone();
+            self.mode = AppMode::NodeDrain;
+            return;
+        }
+        if let Some(node) = self.get_selected_node() {
+            self.drain_dialog = Some(DrainDialog::new(&node.name));
+        }
+    }
+
+    fn handle_drain_dialog_key_event(&mut self, key_event: KeyEvent) -> Result<()> {
+        let Some(dialog) = self.drain_dialog.as_mut() else {
+            return Ok(());
+        };
+        match key_event.code {
+            KeyCode::Esc => self.drain_dialog = None,
+            KeyCode::Enter => match dialog.validated() {
+                Ok(options) => {
+                    let node = dialog.node.clone();
+                    self.drain_dialog = None;
+                    self.pending_node_action = Some(NodeAction::Drain(node, options));
+                }
+                Err(error) => dialog.error = Some(error),
+            },
+            KeyCode::Down | KeyCode::Tab | KeyCode::Char('j') => {
+                dialog.selected = (dialog.selected + 1) % DRAIN_OPTION_ROWS;
+            }
+            KeyCode::Up | KeyCode::BackTab | KeyCode::Char('k') => {
+                dialog.selected = (dialog.selected + DRAIN_OPTION_ROWS - 1) % DRAIN_OPTION_ROWS;
+            }
+            KeyCode::Left | KeyCode::Right | KeyCode::Char(' ') => match dialog.selected {
+                0 => dialog.options.ignore_daemonsets = !dialog.options.ignore_daemonsets,
+                1 => dialog.options.delete_emptydir_data = !dialog.options.delete_emptydir_data,
+                _ => {}
+            },
+            KeyCode::Backspace => match dialog.selected {
+                2 => {
+                    dialog.grace_input.pop();
+                }
+                3 => {
+                    dialog.options.timeout.pop();
+                }
+                _ => {}
+            },
+            KeyCode::Char(c) => match dialog.selected {
+                2 if c.is_ascii_digit() || c == '-' => dialog.grace_input.push(c),
+                3 => dialog.options.timeout.push(c),
+                _ => {}
+            },
             _ => {}
         }
+        Ok(())
+    }
+
+    fn handle_node_drain_key_event(&mut self, key_event: KeyEvent) -> bool {
+        let Some(drain) = self.node_drain.as_mut() else {
+            return false;
+        };
+        let last = drain.progress().pods.len().saturating_sub(1);
+        match key_event.code {
+            KeyCode::Char('j') | KeyCode::Down => drain.selected = (drain.selected + 1).min(last),
+            KeyCode::Char('k') | KeyCode::Up => drain.selected = drain.selected.saturating_sub(1),
+            KeyCode::Char('x') => drain.cancel(),
+            // 排空在后台继续，节点列表中按 D 可回到进度视图
+            KeyCode::Esc => self.mode = AppMode::NodeList,
+            _ => return false,
+        }
+        true
     }
 
     pub fn handle_delete(&mut self) {
@@ -726,35 +1441,493 @@ impl AppState {
         match self.mode {
             AppMode::PodList => {
                 if let Some(pod) = self.get_selected_pod() {
-                    let cmd = format!(
-                        "kubectl exec -it -n {} {} -- /bin/sh",
-                        self.current_namespace, pod.name
-                    );
-                    self.set_current_command(&cmd);
-                    self.pending_exec = Some(cmd);
+                    let workload = pod.workload_key();
+                    let preference = self
+                        .user_config
+                        .exec_preferences
+                        .get(&workload)
+                        .cloned()
+                        .unwrap_or_default();
+                    let mut picker = ExecPicker {
+                        pod_name: pod.name.clone(),
+                        workload,
+                        containers: pod.containers.clone(),
+                        container: None,
+                        step: ExecPickerStep::Container,
+                        selected: 0,
+                        command_input: preference.command.clone().unwrap_or_default(),
+                    };
+                    // 多容器时先选容器，默认选中上次使用的容器
+                    if picker.containers.len() > 1 {
+                        picker.selected = preference
+                            .container
+                            .and_then(|c| picker.containers.iter().position(|n| *n == c))
+                            .unwrap_or(0);
+                    } else {
+                        picker.container = picker.containers.first().cloned();
+                        picker.step = ExecPickerStep::Command;
+                        picker.selected = if preference.command.is_some() { 1 } else { 0 };
+                    }
+                    self.exec_picker = Some(picker);
                 }
             }
             _ => {}
         }
     }
 
-    pub fn handle_yaml_view(&mut self) {
-        match self.mode {
-            AppMode::PodList
-            | AppMode::ServiceList
-            | AppMode::DeploymentList
-            | AppMode::JobList
-            | AppMode::DaemonSetList
-            | AppMode::NodeList
-            | AppMode::ConfigMapList
-            | AppMode::SecretList
-            | AppMode::PVCList
-            | AppMode::PVList => {
-                self.previous_mode = self.mode.clone();
-                self.mode = AppMode::YamlView;
-                self.yaml_scroll = 0;
-                // 默认为鼠标滚动模式，方便快速浏览YAML内容
-                self.text_selection_mode = false;
+    fn open_log_options(&mut self) {
+        let containers = self
+            .get_selected_pod()
+            .map(|pod| pod.containers.clone())
+            .unwrap_or_default();
+        self.log_options_dialog = Some(LogOptionsDialog::new(containers, &self.log_options));
+    }
+
+    // 日志选项弹窗：0 容器 1 previous 2 since 3 timestamps 4 tail
+    fn handle_log_options_key_event(&mut self, key_event: KeyEvent) -> Result<()> {
+        let Some(dialog) = self.log_options_dialog.as_mut() else {
+            return Ok(());
+        };
+        match key_event.code {
+            KeyCode::Esc => self.log_options_dialog = None,
+            KeyCode::Enter => match dialog.validated() {
+                Ok(options) => {
+                    self.log_options_dialog = None;
+                    self.apply_log_options(options);
+                }
+                Err(error) => dialog.error = Some(error),
+            },
+            KeyCode::Down | KeyCode::Tab | KeyCode::Char('j') => {
+                dialog.selected = (dialog.selected + 1) % LOG_OPTION_ROWS;
+            }
+            KeyCode::Up | KeyCode::BackTab | KeyCode::Char('k') => {
+                dialog.selected = (dialog.selected + LOG_OPTION_ROWS - 1) % LOG_OPTION_ROWS;
+            }
+            KeyCode::Left | KeyCode::Right | KeyCode::Char(' ') => match dialog.selected {
+                0 => dialog.cycle_container(key_event.code != KeyCode::Left),
+                1 => dialog.options.previous = !dialog.options.previous,
+                3 => dialog.options.timestamps = !dialog.options.timestamps,
+                _ => {}
+            },
+            KeyCode::Backspace => match dialog.selected {
+                2 => {
+                    dialog.options.since.pop();
+                }
+                4 => {
+                    dialog.tail_input.pop();
+                }
+                _ => {}
+            },
+            KeyCode::Char(c) => match dialog.selected {
+                2 => dialog.options.since.push(c),
+                4 if c.is_ascii_digit() => dialog.tail_input.push(c),
+                _ => {}
+            },
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn handle_save_dialog_key_event(&mut self, key_event: KeyEvent) -> Result<()> {
+        let Some(dialog) = self.save_dialog.as_mut() else {
+            return Ok(());
+        };
+        // 显示结果后任意键关闭；写入中忽略按键
+        if dialog.result.is_some() {
+            self.save_dialog = None;
+            return Ok(());
+        }
+        if dialog.pending {
+            return Ok(());
+        }
+        match key_event.code {
+            KeyCode::Esc => self.save_dialog = None,
+            KeyCode::Enter => dialog.submit(),
+            KeyCode::Char('u') if key_event.modifiers.contains(KeyModifiers::CONTROL) => {
+                dialog.input.clear();
+                dialog.edit();
+            }
+            KeyCode::Backspace => {
+                dialog.input.pop();
+                dialog.edit();
+            }
+            KeyCode::Char(c) => {
+                dialog.input.push(c);
+                dialog.edit();
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    // exec 弹窗事件处理
+    fn handle_exec_picker_key_event(&mut self, key_event: KeyEvent) -> Result<()> {
+        let Some(picker) = self.exec_picker.as_mut() else {
+            return Ok(());
+        };
+        match picker.step {
+            ExecPickerStep::Container => match key_event.code {
+                KeyCode::Down | KeyCode::Char('j') => {
+                    picker.selected =
+                        (picker.selected + 1).min(picker.containers.len().saturating_sub(1));
+                }
+                KeyCode::Up | KeyCode::Char('k') => {
+                    picker.selected = picker.selected.saturating_sub(1);
+                }
+                KeyCode::Enter => {
+                    picker.container = picker.containers.get(picker.selected).cloned();
+                    picker.step = ExecPickerStep::Command;
+                    picker.selected = if picker.command_input.is_empty() {
+                        0
+                    } else {
+                        1
+                    };
+                }
+                KeyCode::Esc => self.exec_picker = None,
+                _ => {}
+            },
+            ExecPickerStep::Command => match key_event.code {
+                KeyCode::Down | KeyCode::Char('j') => {
+                    picker.selected = (picker.selected + 1).min(2);
+                }
+                KeyCode::Up | KeyCode::Char('k') => {
+                    picker.selected = picker.selected.saturating_sub(1);
+                }
+                KeyCode::Enter => match picker.selected {
+                    0 => self.run_exec(None),
+                    1 => picker.step = ExecPickerStep::CustomCommand,
+                    _ => self.run_attach(),
+                },
+                KeyCode::Esc => {
+                    if picker.containers.len() > 1 {
+                        picker.selected = picker
+                            .container
+                            .as_ref()
+                            .and_then(|c| picker.containers.iter().position(|n| n == c))
+                            .unwrap_or(0);
+                        picker.step = ExecPickerStep::Container;
+                    } else {
+                        self.exec_picker = None;
+                    }
+                }
+                _ => {}
+            },
+            ExecPickerStep::CustomCommand => match key_event.code {
+                KeyCode::Enter => {
+                    let command = picker.command_input.trim().to_string();
+                    if !command.is_empty() {
+                        self.run_exec(Some(command));
+                    }
+                }
+                KeyCode::Backspace => {
+                    picker.command_input.pop();
+                }
+                KeyCode::Char(c) => picker.command_input.push(c),
+                KeyCode::Esc => picker.step = ExecPickerStep::Command,
+                _ => {}
+            },
+        }
+        Ok(())
+    }
+
+    // 生成 exec 命令并记住本次选择（配置在主循环执行完 exec 后保存）
+    fn run_exec(&mut self, command: Option<String>) {
+        let Some(picker) = self.exec_picker.take() else {
+            return;
+        };
+        let shells = self.user_config.exec_shells();
+        let cmd = crate::kubectl::commands::build_exec_command(
+            &self.current_namespace,
+            &picker.pod_name,
+            picker.container.as_deref(),
+            command.as_deref(),
+            &shells,
+        );
+        let title = exec_title(&picker);
+        let display = command.clone().unwrap_or_else(|| shells.join("|"));
+        let container_arg = picker
+            .container
+            .as_ref()
+            .map(|c| format!(" -c {}", c))
+            .unwrap_or_default();
+        self.set_current_command(&format!(
+            "kubectl exec -it -n {} {}{} -- {}",
+            self.current_namespace, picker.pod_name, container_arg, display
+        ));
+        self.user_config.remember_exec(
+            &picker.workload,
+            ExecPreference {
+                container: picker.container,
+                command,
+            },
+        );
+        self.pending_exec = Some(ExecRequest {
+            title,
+            command: cmd,
+        });
+    }
+
+    fn run_attach(&mut self) {
+        let Some(picker) = self.exec_picker.take() else {
+            return;
+        };
+        let cmd = crate::kubectl::commands::build_attach_command(
+            &self.current_namespace,
+            &picker.pod_name,
+            picker.container.as_deref(),
+        );
+        self.set_current_command(&cmd);
+        self.pending_exec = Some(ExecRequest {
+            title: format!("attach {}", exec_title(&picker)),
+            command: cmd,
+        });
+    }
+
+    /// 主循环启动新会话后调用：切到终端视图并把焦点交给 shell
+    pub fn add_terminal_session(&mut self, session: TerminalSession) {
+        if self.mode != AppMode::Terminal {
+            self.terminal_return_mode = self.mode.clone();
+        }
+        self.terminal_sessions.push(session);
+        self.active_terminal = self.terminal_sessions.len() - 1;
+        self.terminal_focus = true;
+        self.mode = AppMode::Terminal;
+    }
+
+    pub fn active_terminal_session(&self) -> Option<&TerminalSession> {
+        self.terminal_sessions.get(self.active_terminal)
+    }
+
+    pub fn open_terminal_view(&mut self) {
+        if self.terminal_sessions.is_empty() || self.mode == AppMode::Terminal {
+            return;
+        }
+        self.terminal_return_mode = match self.mode {
+            AppMode::Help | AppMode::Search | AppMode::Confirm | AppMode::CommandHistory => {
+                self.terminal_return_mode.clone()
+            }
+            _ => self.mode.clone(),
+        };
+        self.mode = AppMode::Terminal;
+        self.terminal_focus = false;
+    }
+
+    fn handle_terminal_focus_key_event(&mut self, key_event: KeyEvent) -> Result<()> {
+        if is_focus_toggle_key(&key_event) {
+            self.terminal_focus = false;
+            return Ok(());
+        }
+        match self.active_terminal_session() {
+            // 会话已结束时任意键交还焦点
+            Some(session) if !session.is_exited() => session.send_key(key_event),
+            _ => self.terminal_focus = false,
+        }
+        Ok(())
+    }
+
+    // 终端视图（TUI 焦点）下的快捷键，返回 true 表示已处理
+    fn handle_terminal_view_key_event(&mut self, key_event: KeyEvent) -> bool {
+        let count = self.terminal_sessions.len();
+        match key_event.code {
+            KeyCode::Enter | KeyCode::Char('i') => {
+                if self
+                    .active_terminal_session()
+                    .is_some_and(|s| !s.is_exited())
+                {
+                    self.terminal_focus = true;
+                }
+            }
+            KeyCode::Tab | KeyCode::Char(']') | KeyCode::Char('l') | KeyCode::Right => {
+                if count > 0 {
+                    self.active_terminal = (self.active_terminal + 1) % count;
+                }
+            }
+            KeyCode::BackTab | KeyCode::Char('[') | KeyCode::Char('h') | KeyCode::Left => {
+                if count > 0 {
+                    self.active_terminal = (self.active_terminal + count - 1) % count;
+                }
+            }
+            KeyCode::Char('x') => self.close_active_terminal(),
+            KeyCode::Esc | KeyCode::Char('`') => self.mode = self.terminal_return_mode.clone(),
+            _ => return false,
+        }
+        true
+    }
+
+    pub fn close_active_terminal(&mut self) {
+        if self.active_terminal >= self.terminal_sessions.len() {
+            return;
+        }
+        let session = self.terminal_sessions.remove(self.active_terminal);
+        session.kill();
+        if self.terminal_sessions.is_empty() {
+            self.active_terminal = 0;
+            self.terminal_focus = false;
+            self.mode = self.terminal_return_mode.clone();
+        } else {
+            self.active_terminal = self.active_terminal.min(self.terminal_sessions.len() - 1);
+        }
+    }
+
+    pub fn handle_file_browser(&mut self) {
+        if self.mode != AppMode::PodList {
+            return;
+        }
+        if let Some(pod) = self.get_selected_pod() {
+            self.file_browser = Some(FileBrowser::new(&pod.name, pod.containers.clone()));
+            self.mode = AppMode::FileBrowser;
+        }
+    }
+
+    // 文件浏览器事件处理
+    fn handle_file_browser_key_event(&mut self, key_event: KeyEvent) -> Result<()> {
+        let Some(browser) = self.file_browser.as_mut() else {
+            self.mode = AppMode::PodList;
+            return Ok(());
+        };
+
+        // 输入本地路径
+        if let Some(prompt) = browser.prompt.clone() {
+            match key_event.code {
+                KeyCode::Enter => {
+                    let local = expand_home(browser.input.trim());
+                    browser.prompt = None;
+                    if local.is_empty() {
+                        return Ok(());
+                    }
+                    let request = match prompt {
+                        FileBrowserPrompt::Download => {
+                            let Some(node) = browser.selected_node() else {
+                                return Ok(());
+                            };
+                            TransferRequest {
+                                upload: false,
+                                remote_path: node.path.clone(),
+                                local_path: local,
+                            }
+                        }
+                        FileBrowserPrompt::Upload => {
+                            let name = std::path::Path::new(&local)
+                                .file_name()
+                                .map(|n| n.to_string_lossy().to_string());
+                            let Some(name) = name.filter(|_| std::path::Path::new(&local).exists())
+                            else {
+                                browser.error =
+                                    Some(format!("{}: No such file or directory", local));
+                                return Ok(());
+                            };
+                            TransferRequest {
+                                upload: true,
+                                remote_path: super::file_browser::join_remote(
+                                    &browser.upload_dir(),
+                                    &name,
+                                ),
+                                local_path: local,
+                            }
+                        }
+                    };
+                    browser.pending_transfer = Some(request);
+                }
+                KeyCode::Esc => browser.prompt = None,
+                KeyCode::Backspace => {
+                    browser.input.pop();
+                }
+                KeyCode::Char(c) => browser.input.push(c),
+                _ => {}
+            }
+            return Ok(());
+        }
+
+        match key_event.code {
+            KeyCode::Char('q') => self.should_quit = true,
+            KeyCode::Esc => {
+                self.file_browser = None;
+                self.mode = AppMode::PodList;
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                browser.selected =
+                    (browser.selected + 1).min(browser.nodes.len().saturating_sub(1));
+            }
+            KeyCode::Up | KeyCode::Char('k') => {
+                browser.selected = browser.selected.saturating_sub(1);
+            }
+            KeyCode::Enter | KeyCode::Char('l') | KeyCode::Right => {
+                if let Some(node) = browser.selected_node().filter(|n| n.file.is_dir) {
+                    if node.expanded {
+                        browser.collapse(browser.selected);
+                    } else {
+                        let path = node.path.clone();
+                        browser.request_list(&path);
+                    }
+                }
+            }
+            KeyCode::Char('h') | KeyCode::Left => {
+                if browser.selected_node().is_some_and(|n| n.expanded) {
+                    browser.collapse(browser.selected);
+                } else if let Some(parent) = browser.parent_index(browser.selected) {
+                    browser.selected = parent;
+                }
+            }
+            KeyCode::Char('d') => {
+                let running = browser
+                    .transfer
+                    .as_ref()
+                    .is_some_and(|t| t.status() == TransferStatus::Running);
+                if !running && let Some(node) = browser.selected_node() {
+                    browser.input = format!("./{}", node.file.name);
+                    browser.prompt = Some(FileBrowserPrompt::Download);
+                }
+            }
+            KeyCode::Char('u') => {
+                let running = browser
+                    .transfer
+                    .as_ref()
+                    .is_some_and(|t| t.status() == TransferStatus::Running);
+                if !running {
+                    browser.input.clear();
+                    browser.prompt = Some(FileBrowserPrompt::Upload);
+                }
+            }
+            // 切换容器后重新从根目录浏览
+            KeyCode::Char('c') if browser.containers.len() > 1 => {
+                let index = browser
+                    .container
+                    .as_ref()
+                    .and_then(|c| browser.containers.iter().position(|n| n == c))
+                    .map(|i| (i + 1) % browser.containers.len())
+                    .unwrap_or(0);
+                browser.container = browser.containers.get(index).cloned();
+                browser.nodes.clear();
+                let root = browser.root.clone();
+                browser.request_list(&root);
+            }
+            KeyCode::Char('r') => {
+                let root = browser.root.clone();
+                browser.request_list(&root);
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    pub fn handle_yaml_view(&mut self) {
+        match self.mode {
+            AppMode::PodList
+            | AppMode::ServiceList
+            | AppMode::DeploymentList
+            | AppMode::JobList
+            | AppMode::DaemonSetList
+            | AppMode::NodeList
+            | AppMode::ConfigMapList
+            | AppMode::SecretList
+            | AppMode::PVCList
+            | AppMode::PVList => {
+                self.previous_mode = self.mode.clone();
+                self.mode = AppMode::YamlView;
+                self.yaml_scroll = 0;
+                self.content_search.clear();
+                // 默认为鼠标滚动模式，方便快速浏览YAML内容
+                self.text_selection_mode = false;
                 // 在主循环中会加载相应的YAML内容
             }
             _ => {}
@@ -767,8 +1940,18 @@ impl AppState {
                 self.previous_mode = self.mode.clone();
                 self.mode = AppMode::TopView;
                 self.metrics_scroll = 0;
+                self.top_nodes = false;
+                self.metrics_pending = true;
                 // 在主循环中会加载Pod的资源使用情况
             }
+            AppMode::NodeList => {
+                self.previous_mode = self.mode.clone();
+                self.mode = AppMode::TopView;
+                self.metrics_scroll = 0;
+                self.top_nodes = true;
+                self.metrics_pending = true;
+                // 主循环中执行 kubectl top nodes
+            }
             _ => {}
         }
     }
@@ -817,15 +2000,92 @@ impl AppState {
     }
 }
 
+// 本地路径支持 ~/ 前缀
+fn expand_home(path: &str) -> String {
+    match path.strip_prefix("~/") {
+        Some(rest) => match std::env::var("HOME") {
+            Ok(home) => format!("{}/{}", home, rest),
+            Err(_) => path.to_string(),
+        },
+        None => path.to_string(),
+    }
+}
+
+// 会话标签名：pod 或 pod/container
+fn exec_title(picker: &ExecPicker) -> String {
+    match &picker.container {
+        Some(container) => format!("{}/{}", picker.pod_name, container),
+        None => picker.pod_name.clone(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::app::detail_lifecycle::EditableView;
+    use crate::app::log_search::LogViewLine;
+    use crate::app::save::SaveKind;
+    use crate::app::structured_log::LogLevel;
+    use crate::kubectl::types::{ConfigMapDetail, ConfigMapEntry, Node, SecretDetail, SecretEntry};
     use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
     fn create_test_state() -> AppState {
         AppState::default()
     }
 
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    fn create_test_node(name: &str) -> Node {
+        Node {
+            name: name.to_string(),
+            status: "Ready".to_string(),
+            roles: vec!["worker".to_string()],
+            age: "10d".to_string(),
+            version: "v1.30.0".to_string(),
+            internal_ip: None,
+            external_ip: None,
+            os_image: None,
+            kernel_version: None,
+            container_runtime: None,
+            unschedulable: false,
+        }
+    }
+
+    /// prod/db，Opaque 类型
+    fn create_test_secret_detail(entries: &[(&str, &[u8])]) -> SecretDetail {
+        SecretDetail {
+            name: "db".to_string(),
+            namespace: "prod".to_string(),
+            type_: "Opaque".to_string(),
+            entries: entries
+                .iter()
+                .map(|(key, value)| SecretEntry {
+                    key: key.to_string(),
+                    value: value.to_vec(),
+                })
+                .collect(),
+        }
+    }
+
+    /// prod/app，只有文本键
+    fn create_test_configmap_detail(entries: &[(&str, &str)]) -> ConfigMapDetail {
+        ConfigMapDetail {
+            name: "app".to_string(),
+            namespace: "prod".to_string(),
+            entries: entries
+                .iter()
+                .map(|(key, value)| ConfigMapEntry {
+                    key: key.to_string(),
+                    value: value.to_string(),
+                    binary: false,
+                    size: value.len(),
+                })
+                .collect(),
+        }
+    }
+
     #[test]
     fn test_quit_key() {
         let mut state = create_test_state();
@@ -870,6 +2130,8 @@ mod tests {
                 age: "1d".to_string(),
                 node: None,
                 ip: None,
+                containers: Vec::new(),
+                owner: None,
             },
             Pod {
                 name: "pod2".to_string(),
@@ -884,6 +2146,8 @@ mod tests {
                 age: "1d".to_string(),
                 node: None,
                 ip: None,
+                containers: Vec::new(),
+                owner: None,
             },
         ];
         state.mode = AppMode::PodList;
@@ -964,6 +2228,8 @@ mod tests {
             age: "1d".into(),
             node: None,
             ip: None,
+            containers: Vec::new(),
+            owner: None,
         });
         state.pods.push(crate::kubectl::types::Pod {
             name: "pod2".into(),
@@ -978,6 +2244,8 @@ mod tests {
             age: "1d".into(),
             node: None,
             ip: None,
+            containers: Vec::new(),
+            owner: None,
         });
         let v_key = KeyEvent::new(KeyCode::Char('V'), KeyModifiers::NONE);
         state.handle_key_event(v_key).unwrap();
@@ -995,4 +2263,848 @@ mod tests {
         assert!(!state.split_pod_selection_mode);
         assert_eq!(state.split_log_pod_name, "pod2");
     }
+
+    fn create_exec_test_pod(containers: &[&str]) -> crate::kubectl::types::Pod {
+        crate::kubectl::types::Pod {
+            name: "web-abc".into(),
+            namespace: "default".into(),
+            status: crate::kubectl::types::PodStatus {
+                phase: "Running".into(),
+                conditions: None,
+                container_statuses: None,
+            },
+            ready: "1/1".into(),
+            restarts: 0,
+            age: "1d".into(),
+            node: None,
+            ip: None,
+            containers: containers.iter().map(|c| c.to_string()).collect(),
+            owner: Some("Deployment/web".into()),
+        }
+    }
+
+    #[test]
+    fn test_exec_container_picker() {
+        let mut state = create_test_state();
+        state.mode = AppMode::PodList;
+        state.pods.push(create_exec_test_pod(&["app", "sidecar"]));
+
+        state.handle_key_event(key(KeyCode::Char('E'))).unwrap();
+        assert_eq!(
+            state.exec_picker.as_ref().unwrap().step,
+            ExecPickerStep::Container
+        );
+        state.handle_key_event(key(KeyCode::Char('j'))).unwrap();
+        state.handle_key_event(key(KeyCode::Enter)).unwrap();
+        assert_eq!(
+            state.exec_picker.as_ref().unwrap().container.as_deref(),
+            Some("sidecar")
+        );
+        state.handle_key_event(key(KeyCode::Enter)).unwrap();
+
+        assert!(state.exec_picker.is_none());
+        let request = state.pending_exec.clone().unwrap();
+        assert_eq!(request.title, "web-abc/sidecar");
+        let cmd = request.command;
+        assert!(cmd.contains("kubectl exec -it -n default web-abc -c sidecar"));
+        assert!(cmd.contains("for s in 'bash' 'sh'"));
+        let preference = &state.user_config.exec_preferences["default/Deployment/web"];
+        assert_eq!(preference.container.as_deref(), Some("sidecar"));
+        assert!(preference.command.is_none());
+
+        // 再次打开时默认选中上次的容器
+        state.handle_key_event(key(KeyCode::Char('E'))).unwrap();
+        assert_eq!(state.exec_picker.as_ref().unwrap().selected, 1);
+    }
+
+    #[test]
+    fn test_exec_custom_command() {
+        let mut state = create_test_state();
+        state.mode = AppMode::PodList;
+        state.pods.push(create_exec_test_pod(&["app"]));
+
+        state.handle_key_event(key(KeyCode::Char('E'))).unwrap();
+        assert_eq!(
+            state.exec_picker.as_ref().unwrap().step,
+            ExecPickerStep::Command
+        );
+        state.handle_key_event(key(KeyCode::Down)).unwrap();
+        state.handle_key_event(key(KeyCode::Enter)).unwrap();
+        for c in "ls /tmp".chars() {
+            state.handle_key_event(key(KeyCode::Char(c))).unwrap();
+        }
+        state.handle_key_event(key(KeyCode::Enter)).unwrap();
+
+        assert_eq!(
+            state.pending_exec.map(|r| r.command).as_deref(),
+            Some("kubectl exec -it -n default web-abc -c app -- ls /tmp")
+        );
+        assert_eq!(
+            state.user_config.exec_preferences["default/Deployment/web"]
+                .command
+                .as_deref(),
+            Some("ls /tmp")
+        );
+    }
+
+    #[test]
+    fn test_exec_attach() {
+        let mut state = create_test_state();
+        state.mode = AppMode::PodList;
+        state.pods.push(create_exec_test_pod(&["app"]));
+
+        state.handle_key_event(key(KeyCode::Char('E'))).unwrap();
+        state.handle_key_event(key(KeyCode::Down)).unwrap();
+        state.handle_key_event(key(KeyCode::Down)).unwrap();
+        state.handle_key_event(key(KeyCode::Enter)).unwrap();
+
+        let request = state.pending_exec.unwrap();
+        assert_eq!(request.title, "attach web-abc/app");
+        assert_eq!(
+            request.command,
+            "kubectl attach -it -n default web-abc -c app"
+        );
+    }
+
+    #[test]
+    fn test_terminal_focus_and_tabs() {
+        let mut state = create_test_state();
+        state.mode = AppMode::PodList;
+        state.add_terminal_session(TerminalSession::spawn("one", "sleep 5", 24, 80).unwrap());
+        state.add_terminal_session(TerminalSession::spawn("two", "sleep 5", 24, 80).unwrap());
+        assert_eq!(state.mode, AppMode::Terminal);
+        assert!(state.terminal_focus);
+        assert_eq!(state.active_terminal, 1);
+
+        // 聚焦时普通按键不触发 TUI 快捷键
+        state.handle_key_event(key(KeyCode::Char('q'))).unwrap();
+        assert!(!state.should_quit);
+
+        state
+            .handle_key_event(KeyEvent::new(KeyCode::Char(']'), KeyModifiers::CONTROL))
+            .unwrap();
+        assert!(!state.terminal_focus);
+
+        state.handle_key_event(key(KeyCode::Tab)).unwrap();
+        assert_eq!(state.active_terminal, 0);
+
+        state.handle_key_event(key(KeyCode::Char('x'))).unwrap();
+        assert_eq!(state.terminal_sessions.len(), 1);
+        assert_eq!(state.terminal_sessions[0].title, "two");
+
+        // Esc 返回原视图，会话保持运行
+        state.handle_key_event(key(KeyCode::Esc)).unwrap();
+        assert_eq!(state.mode, AppMode::PodList);
+        assert_eq!(state.terminal_sessions.len(), 1);
+
+        state.handle_key_event(key(KeyCode::Char('`'))).unwrap();
+        assert_eq!(state.mode, AppMode::Terminal);
+        state.close_active_terminal();
+        assert_eq!(state.mode, AppMode::PodList);
+    }
+
+    #[test]
+    fn test_file_browser_download() {
+        use crate::kubectl::types::RemoteFile;
+        let mut state = create_test_state();
+        state.mode = AppMode::PodList;
+        state.pods.push(create_exec_test_pod(&["app", "sidecar"]));
+
+        state.handle_key_event(key(KeyCode::Char('F'))).unwrap();
+        assert_eq!(state.mode, AppMode::FileBrowser);
+        let browser = state.file_browser.as_mut().unwrap();
+        assert_eq!(browser.pending_list.take().as_deref(), Some("/"));
+        let dir = |name: &str| RemoteFile {
+            name: name.to_string(),
+            permissions: "drwxr-xr-x".to_string(),
+            size: 4096,
+            modified: "Jan 1 00:00".to_string(),
+            is_dir: true,
+            link_target: None,
+        };
+        browser.set_children("/", vec![dir("etc"), dir("var")]);
+
+        // 展开目录只登记加载请求，由主循环执行
+        state.handle_key_event(key(KeyCode::Enter)).unwrap();
+        let browser = state.file_browser.as_mut().unwrap();
+        assert_eq!(browser.pending_list.take().as_deref(), Some("/etc"));
+
+        state.handle_key_event(key(KeyCode::Char('j'))).unwrap();
+        state.handle_key_event(key(KeyCode::Char('d'))).unwrap();
+        assert_eq!(state.file_browser.as_ref().unwrap().input, "./var");
+        state.handle_key_event(key(KeyCode::Backspace)).unwrap();
+        state.handle_key_event(key(KeyCode::Char('x'))).unwrap();
+        state.handle_key_event(key(KeyCode::Enter)).unwrap();
+        let browser = state.file_browser.as_ref().unwrap();
+        let request = browser.pending_transfer.as_ref().unwrap();
+        assert!(!request.upload);
+        assert_eq!(request.remote_path, "/var");
+        assert_eq!(request.local_path, "./vax");
+
+        // c 切换容器并重新加载根目录
+        state.handle_key_event(key(KeyCode::Char('c'))).unwrap();
+        let browser = state.file_browser.as_ref().unwrap();
+        assert_eq!(browser.container.as_deref(), Some("sidecar"));
+        assert!(browser.nodes.is_empty());
+
+        state.handle_key_event(key(KeyCode::Esc)).unwrap();
+        assert_eq!(state.mode, AppMode::PodList);
+        assert!(state.file_browser.is_none());
+    }
+
+    #[test]
+    fn test_log_options_dialog() {
+        let mut state = create_test_state();
+        state.mode = AppMode::PodList;
+        state.pods.push(create_exec_test_pod(&["app", "sidecar"]));
+
+        state.handle_key_event(key(KeyCode::Char('L'))).unwrap();
+        state.set_logs(vec!["old".to_string()]);
+        state.handle_key_event(key(KeyCode::Char('o'))).unwrap();
+        // 默认容器 → app → sidecar → 全部容器
+        for _ in 0..3 {
+            state.handle_key_event(key(KeyCode::Char(' '))).unwrap();
+        }
+        state.handle_key_event(key(KeyCode::Char('j'))).unwrap();
+        state.handle_key_event(key(KeyCode::Char(' '))).unwrap();
+        state.handle_key_event(key(KeyCode::Char('j'))).unwrap();
+        for c in "5x".chars() {
+            state.handle_key_event(key(KeyCode::Char(c))).unwrap();
+        }
+        // 非法的 since 不会关闭弹窗
+        state.handle_key_event(key(KeyCode::Enter)).unwrap();
+        assert!(state.log_options_dialog.as_ref().unwrap().error.is_some());
+        state.handle_key_event(key(KeyCode::Backspace)).unwrap();
+        state.handle_key_event(key(KeyCode::Char('m'))).unwrap();
+        state.handle_key_event(key(KeyCode::Down)).unwrap();
+        state.handle_key_event(key(KeyCode::Down)).unwrap();
+        state.handle_key_event(key(KeyCode::Backspace)).unwrap();
+        state.handle_key_event(key(KeyCode::Enter)).unwrap();
+
+        assert!(state.log_options_dialog.is_none());
+        assert!(state.logs.is_empty());
+        let options = &state.log_options;
+        assert!(options.all_containers);
+        assert!(options.previous);
+        assert_eq!(options.since, "5m");
+        assert_eq!(options.tail, 10);
+        assert_eq!(
+            crate::kubectl::commands::log_args("default", "web-abc", options, false),
+            vec![
+                "logs",
+                "-n",
+                "default",
+                "web-abc",
+                "--all-containers",
+                "--prefix",
+                "--previous",
+                "--since=5m",
+                "--tail",
+                "10"
+            ]
+        );
+
+        // 选项在会话内保留，Esc 取消不改变
+        state.handle_key_event(key(KeyCode::Char('o'))).unwrap();
+        state.handle_key_event(key(KeyCode::Char(' '))).unwrap();
+        state.handle_key_event(key(KeyCode::Esc)).unwrap();
+        assert!(state.log_options.all_containers);
+    }
+
+    #[test]
+    fn test_multi_log_from_deployment_and_selector() {
+        let mut state = create_test_state();
+        state.mode = AppMode::DeploymentList;
+        state.deployments.push(crate::kubectl::types::Deployment {
+            name: "web".into(),
+            namespace: "default".into(),
+            ready: "2/2".into(),
+            up_to_date: 2,
+            available: 2,
+            age: "1d".into(),
+            selector: "app=web".into(),
+        });
+
+        state.handle_key_event(key(KeyCode::Char('L'))).unwrap();
+        assert_eq!(state.mode, AppMode::MultiLogs);
+        let view = state.multi_log.as_ref().unwrap();
+        assert_eq!(view.selector, "app=web");
+        assert_eq!(view.label, "Deployment/web");
+        state.handle_key_event(key(KeyCode::Esc)).unwrap();
+        assert_eq!(state.mode, AppMode::DeploymentList);
+
+        // Pod 列表中手动输入选择器
+        state.mode = AppMode::PodList;
+        state.handle_key_event(key(KeyCode::Char('S'))).unwrap();
+        for c in "tier=db".chars() {
+            state.handle_key_event(key(KeyCode::Char(c))).unwrap();
+        }
+        state.handle_key_event(key(KeyCode::Enter)).unwrap();
+        assert!(state.multi_log_selector_input.is_none());
+        assert_eq!(state.mode, AppMode::MultiLogs);
+        assert_eq!(state.multi_log.as_ref().unwrap().label, "-l tier=db");
+    }
+
+    #[test]
+    fn test_log_search_and_grep() {
+        let mut state = create_test_state();
+        state.mode = AppMode::Logs;
+        let logs = ["start", "GET /a 200", "-", "-", "get /b 500", "-", "end"];
+        state.set_logs(logs.iter().map(|l| l.to_string()).collect());
+        let ctrl = |c| KeyEvent::new(KeyCode::Char(c), KeyModifiers::CONTROL);
+
+        state.handle_key_event(key(KeyCode::Char('/'))).unwrap();
+        for c in "get".chars() {
+            state.handle_key_event(key(KeyCode::Char(c))).unwrap();
+        }
+        // 默认不区分大小写
+        assert_eq!(state.log_search_results, vec![1, 4]);
+        state.handle_key_event(ctrl('t')).unwrap();
+        assert_eq!(state.log_search_results, vec![4]);
+        state.handle_key_event(ctrl('t')).unwrap();
+        state.handle_key_event(key(KeyCode::Backspace)).unwrap();
+        state.handle_key_event(key(KeyCode::Backspace)).unwrap();
+        state.handle_key_event(key(KeyCode::Backspace)).unwrap();
+        for c in "/[ab] [25]".chars() {
+            state.handle_key_event(key(KeyCode::Char(c))).unwrap();
+        }
+        assert!(state.log_search_results.is_empty());
+        state.handle_key_event(ctrl('r')).unwrap();
+        assert_eq!(state.log_search_results, vec![1, 4]);
+        state.handle_key_event(key(KeyCode::Enter)).unwrap();
+        assert!(!state.log_search_mode);
+
+        // 增量搜索停在当前位置之后最近的匹配
+        assert_eq!(state.logs_scroll, 4);
+        state.handle_key_event(key(KeyCode::Char('n'))).unwrap();
+        assert_eq!(state.logs_scroll, 1);
+        state.handle_key_event(key(KeyCode::Char('N'))).unwrap();
+        assert_eq!(state.logs_scroll, 4);
+
+        // grep 模式：匹配行及上下一行，不含最后一行
+        state.handle_key_event(key(KeyCode::Char('-'))).unwrap();
+        state.handle_key_event(key(KeyCode::Char('g'))).unwrap();
+        assert_eq!(state.log_view_len(), 6);
+        assert_eq!(state.logs_scroll, 4);
+        state.handle_key_event(key(KeyCode::Char('n'))).unwrap();
+        assert_eq!(state.logs_scroll, 1);
+
+        // Esc 先清除搜索，再返回
+        state.handle_key_event(key(KeyCode::Esc)).unwrap();
+        assert_eq!(state.mode, AppMode::Logs);
+        assert!(!state.log_grep_mode);
+        assert_eq!(state.log_view_len(), 7);
+    }
+
+    #[test]
+    fn test_log_level_filter_and_expand() {
+        let mut state = create_test_state();
+        state.mode = AppMode::Logs;
+        state.logs_auto_scroll = false;
+        state.set_logs(vec![
+            r#"{"level":"info","msg":"started"}"#.to_string(),
+            "level=error msg=\"db down\" retry=1".to_string(),
+            "    at stacktrace line".to_string(),
+            r#"{"level":"warn","msg":"slow"}"#.to_string(),
+        ]);
+
+        state.handle_key_event(key(KeyCode::Char('v'))).unwrap();
+        assert_eq!(state.log_view(), vec![LogViewLine::Line(1)]);
+        state.handle_key_event(key(KeyCode::Char('v'))).unwrap();
+        assert_eq!(state.log_view_len(), 2);
+
+        // 展开时按键只作用于弹窗
+        state.logs_scroll = 1;
+        state.handle_key_event(key(KeyCode::Char('e'))).unwrap();
+        assert_eq!(state.log_expanded, Some(3));
+        state.handle_key_event(key(KeyCode::Char('v'))).unwrap();
+        assert_eq!(state.log_level_filter, Some(LogLevel::Warn));
+        state.handle_key_event(key(KeyCode::Esc)).unwrap();
+        assert!(state.log_expanded.is_none());
+        assert_eq!(state.mode, AppMode::Logs);
+
+        for _ in 0..3 {
+            state.handle_key_event(key(KeyCode::Char('v'))).unwrap();
+        }
+        assert!(state.log_level_filter.is_none());
+        assert_eq!(state.log_view_len(), 4);
+    }
+
+    #[test]
+    fn test_log_ansi_colours() {
+        let mut state = create_test_state();
+        state.mode = AppMode::Logs;
+        state.set_logs(vec![
+            "\x1b[31mERROR\x1b[0m disk full".to_string(),
+            "\x1b[32mINFO\x1b[0m ready".to_string(),
+        ]);
+
+        // 转义序列不影响搜索和级别识别
+        state.log_search_query = "ERROR disk".to_string();
+        state.update_log_search_pattern();
+        assert_eq!(state.log_search_results, vec![0]);
+        state.handle_key_event(key(KeyCode::Char('v'))).unwrap();
+        assert_eq!(state.log_view(), vec![LogViewLine::Line(0)]);
+
+        assert!(state.log_ansi);
+        state.handle_key_event(key(KeyCode::Char('c'))).unwrap();
+        assert!(!state.log_ansi);
+        assert_eq!(state.mode, AppMode::Logs);
+    }
+
+    #[test]
+    fn test_describe_sections_fold_and_jump() {
+        let mut state = create_test_state();
+        state.mode = AppMode::Describe;
+        state.previous_mode = AppMode::PodList;
+        state.set_describe_content(
+            "Name:  web\nLabels:  app=web\n         tier=fe\nContainers:\n  web:\n    Image: nginx\nEvents:  <none>\n"
+                .to_string(),
+        );
+
+        state.handle_key_event(key(KeyCode::Char(']'))).unwrap();
+        assert_eq!(state.describe_scroll, 1);
+        state.handle_key_event(key(KeyCode::Char('z'))).unwrap();
+        assert!(state.describe_outline.folded.contains("Labels"));
+        assert_eq!(state.describe_outline.rows(), vec![0, 1, 3, 4, 5]);
+
+        // 段落列表：跳转时展开折叠的段落
+        state.handle_key_event(key(KeyCode::Char('s'))).unwrap();
+        assert_eq!(state.describe_outline.picker, Some(1));
+        state.handle_key_event(key(KeyCode::Char('k'))).unwrap();
+        state.handle_key_event(key(KeyCode::Char('j'))).unwrap();
+        state.handle_key_event(key(KeyCode::Enter)).unwrap();
+        assert!(state.describe_outline.picker.is_none());
+        assert!(state.describe_outline.folded.is_empty());
+        assert_eq!(state.describe_scroll, 1);
+
+        state.handle_key_event(key(KeyCode::Char('Z'))).unwrap();
+        assert_eq!(state.describe_outline.rows(), vec![0, 1, 3]);
+        assert_eq!(state.describe_scroll, 1);
+
+        state.handle_key_event(key(KeyCode::Esc)).unwrap();
+        assert_eq!(state.mode, AppMode::PodList);
+    }
+
+    #[test]
+    fn test_yaml_tree_cursor_and_folding() {
+        let mut state = create_test_state();
+        state.mode = AppMode::YamlView;
+        state.previous_mode = AppMode::PodList;
+        state.set_yaml_content(
+            "metadata:\n  name: web\nspec:\n  containers:\n  - image: nginx\n    name: web\nstatus:\n  phase: Running\n"
+                .to_string(),
+        );
+
+        for _ in 0..5 {
+            state.handle_key_event(key(KeyCode::Char('j'))).unwrap();
+        }
+        assert_eq!(state.yaml_cursor_line(), Some(5));
+        assert_eq!(state.yaml_tree.lines[5].path, "spec.containers[0].name");
+
+        // 折叠所在的节点后光标停在节点上
+        state.handle_key_event(key(KeyCode::Char('Z'))).unwrap();
+        assert_eq!(state.yaml_tree.rows(), vec![0, 2, 6]);
+        assert_eq!(state.yaml_cursor_line(), Some(2));
+        state.handle_key_event(key(KeyCode::Char('z'))).unwrap();
+        assert_eq!(state.yaml_tree.rows(), vec![0, 2, 3, 6]);
+
+        state.handle_key_event(key(KeyCode::Char('S'))).unwrap();
+        assert_eq!(state.yaml_tree.rows(), vec![0, 2, 3, 4, 5, 6]);
+        state.handle_key_event(key(KeyCode::Char('j'))).unwrap();
+        state.handle_key_event(key(KeyCode::Char('j'))).unwrap();
+        state.handle_key_event(key(KeyCode::Char('j'))).unwrap();
+        state.handle_key_event(key(KeyCode::Char('j'))).unwrap();
+        assert_eq!(state.yaml_cursor_line(), Some(6));
+        state.handle_key_event(key(KeyCode::Char('j'))).unwrap();
+        assert_eq!(state.yaml_cursor_line(), Some(6));
+    }
+
+    #[test]
+    fn test_content_search_reveals_folded_matches() {
+        let mut state = create_test_state();
+        state.mode = AppMode::YamlView;
+        state.previous_mode = AppMode::PodList;
+        state.set_yaml_content(
+            "metadata:\n  name: web\nspec:\n  containers:\n  - image: nginx\n    name: web\nstatus:\n  phase: Running\n"
+                .to_string(),
+        );
+
+        // 增量搜索：跳到匹配行，并展开折叠了它的节点
+        state.handle_key_event(key(KeyCode::Char('Z'))).unwrap();
+        state.handle_key_event(key(KeyCode::Char('/'))).unwrap();
+        for c in "web".chars() {
+            state.handle_key_event(key(KeyCode::Char(c))).unwrap();
+        }
+        assert_eq!(state.content_search.matches, vec![1, 5]);
+        assert_eq!(state.yaml_cursor_line(), Some(1));
+        state.handle_key_event(key(KeyCode::Enter)).unwrap();
+        state.handle_key_event(key(KeyCode::Char('n'))).unwrap();
+        assert_eq!(state.yaml_cursor_line(), Some(5));
+        assert_eq!(state.content_search.status(), " [/web 2/2]");
+
+        // 第一次 Esc 只清除搜索
+        state.handle_key_event(key(KeyCode::Esc)).unwrap();
+        assert!(!state.content_search.is_active());
+        assert_eq!(state.mode, AppMode::YamlView);
+
+        state.mode = AppMode::Describe;
+        state.set_describe_content(
+            "Name:  web\nLabels:  app=web\nEvents:\n  Normal  Pulled  web\n".to_string(),
+        );
+        state.describe_outline.toggle(1);
+        state.handle_key_event(key(KeyCode::Char('/'))).unwrap();
+        state.handle_key_event(key(KeyCode::Char('a'))).unwrap();
+        state.handle_key_event(key(KeyCode::Char('p'))).unwrap();
+        assert_eq!(state.content_search.current_line(), Some(1));
+        assert_eq!(state.describe_scroll, 1);
+        state.handle_key_event(key(KeyCode::Backspace)).unwrap();
+        state.handle_key_event(key(KeyCode::Backspace)).unwrap();
+        for c in "pull".chars() {
+            state.handle_key_event(key(KeyCode::Char(c))).unwrap();
+        }
+        state.handle_key_event(key(KeyCode::Enter)).unwrap();
+        // Events 中的匹配只在固定面板中显示
+        assert_eq!(state.content_search.current_line(), Some(3));
+        assert_eq!(state.describe_scroll, 1);
+    }
+
+    #[test]
+    fn test_yaml_neat_toggle_and_export() {
+        let mut state = create_test_state();
+        state.current_namespace = "prod".to_string();
+        state.pods.push(create_exec_test_pod(&["app"]));
+        state.mode = AppMode::YamlView;
+        state.previous_mode = AppMode::PodList;
+        let live = "metadata:\n  name: web-abc\n  uid: 1234\nspec:\n  nodeName: n1\nstatus:\n  phase: Running\n";
+        state.set_yaml_content(live.to_string());
+
+        // 光标在 spec.nodeName 上切换精简模式后停在同一路径
+        for _ in 0..4 {
+            state.handle_key_event(key(KeyCode::Char('j'))).unwrap();
+        }
+        state.handle_key_event(key(KeyCode::Char('c'))).unwrap();
+        assert_eq!(state.yaml_lines_cache.len(), 4);
+        assert_eq!(state.yaml_cursor_line(), Some(3));
+        assert_eq!(
+            state.save_content(SaveKind::Yaml),
+            "metadata:\n  name: web-abc\nspec:\n  nodeName: n1\n"
+        );
+        state.handle_key_event(key(KeyCode::Char('c'))).unwrap();
+        assert_eq!(state.yaml_cursor_line(), Some(4));
+        assert_eq!(state.save_content(SaveKind::Yaml), live);
+
+        // W 总是导出精简清单
+        state.handle_key_event(key(KeyCode::Char('W'))).unwrap();
+        let dialog = state.save_dialog.as_ref().unwrap();
+        assert_eq!(dialog.kind, SaveKind::NeatYaml);
+        assert!(dialog.input.ends_with(".yaml"));
+        assert!(!state.save_content(SaveKind::NeatYaml).contains("status"));
+    }
+
+    #[test]
+    fn test_save_dialog() {
+        let mut state = create_test_state();
+        state.current_namespace = "prod".to_string();
+        state.pods.push(create_exec_test_pod(&["app"]));
+        state.mode = AppMode::Describe;
+        state.previous_mode = AppMode::PodList;
+        state.set_describe_content("Name: web-abc".to_string());
+
+        // 详情视图不支持完整日志
+        state.handle_key_event(key(KeyCode::Char('W'))).unwrap();
+        assert!(state.save_dialog.is_none());
+        state.handle_key_event(key(KeyCode::Char('w'))).unwrap();
+        let dialog = state.save_dialog.as_ref().unwrap();
+        assert_eq!(dialog.kind, SaveKind::Describe);
+        assert!(dialog.input.starts_with("prod_web-abc_"));
+        assert!(dialog.input.ends_with(".txt"));
+        assert_eq!(state.save_content(SaveKind::Describe), "Name: web-abc\n");
+
+        // 弹窗打开时按键用于编辑路径
+        state.handle_key_event(key(KeyCode::Char('q'))).unwrap();
+        assert!(!state.should_quit);
+        assert!(state.save_dialog.as_ref().unwrap().input.ends_with(".txtq"));
+        state.handle_key_event(key(KeyCode::Esc)).unwrap();
+        assert!(state.save_dialog.is_none());
+        assert_eq!(state.mode, AppMode::Describe);
+
+        state.mode = AppMode::Logs;
+        state.handle_key_event(key(KeyCode::Char('W'))).unwrap();
+        let dialog = state.save_dialog.as_mut().unwrap();
+        assert_eq!(dialog.kind, SaveKind::FullLogs);
+        dialog.result = Some(Ok("saved".to_string()));
+        state.handle_key_event(key(KeyCode::Char('x'))).unwrap();
+        assert!(state.save_dialog.is_none());
+    }
+
+    #[test]
+    fn test_log_captures_view() {
+        let mut state = create_test_state();
+
+        // 只能从资源列表打开
+        state.mode = AppMode::Logs;
+        state.handle_key_event(key(KeyCode::Char('B'))).unwrap();
+        assert_eq!(state.mode, AppMode::Logs);
+
+        state.mode = AppMode::ServiceList;
+        state.handle_key_event(key(KeyCode::Char('B'))).unwrap();
+        assert_eq!(state.mode, AppMode::LogCaptures);
+        // 空列表上的操作不会越界
+        state.handle_key_event(key(KeyCode::Char('j'))).unwrap();
+        state.handle_key_event(key(KeyCode::Char('x'))).unwrap();
+        assert_eq!(state.log_captures.selected, 0);
+        state.handle_key_event(key(KeyCode::Esc)).unwrap();
+        assert_eq!(state.mode, AppMode::ServiceList);
+    }
+
+    #[test]
+    fn test_node_cordon_and_drain_dialog() {
+        let mut state = create_test_state();
+        state.mode = AppMode::NodeList;
+        state.nodes = vec![create_test_node("worker-1")];
+
+        state.handle_key_event(key(KeyCode::Char('C'))).unwrap();
+        assert_eq!(
+            state.pending_node_action,
+            Some(NodeAction::Cordon("worker-1".to_string()))
+        );
+        state.pending_node_action = None;
+        state.nodes[0].unschedulable = true;
+        state.handle_key_event(key(KeyCode::Char('C'))).unwrap();
+        assert_eq!(
+            state.pending_node_action,
+            Some(NodeAction::Uncordon("worker-1".to_string()))
+        );
+        state.pending_node_action = None;
+
+        // D 打开排空选项而不是删除确认
+        state.handle_key_event(key(KeyCode::Char('D'))).unwrap();
+        assert!(state.drain_dialog.is_some());
+        assert!(state.confirm_action.is_none());
+        state.handle_key_event(key(KeyCode::Char('j'))).unwrap();
+        state.handle_key_event(key(KeyCode::Char(' '))).unwrap();
+        state.handle_key_event(key(KeyCode::Char('j'))).unwrap();
+        state.handle_key_event(key(KeyCode::Char('3'))).unwrap();
+        state.handle_key_event(key(KeyCode::Char('0'))).unwrap();
+        state.handle_key_event(key(KeyCode::Enter)).unwrap();
+        assert!(state.drain_dialog.is_none());
+        match &state.pending_node_action {
+            Some(NodeAction::Drain(node, options)) => {
+                assert_eq!(node, "worker-1");
+                assert!(options.ignore_daemonsets);
+                assert!(options.delete_emptydir_data);
+                assert_eq!(options.grace_period, Some(30));
+                assert_eq!(options.timeout, "5m");
+            }
+            other => panic!("unexpected action {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_node_detail_jump_to_pod() {
+        use crate::kubectl::types::{NodeDetail, NodePod, ResourceAmounts};
+        let mut state = create_test_state();
+        state.mode = AppMode::NodeList;
+        state.nodes = vec![create_test_node("worker-1")];
+
+        state.handle_key_event(key(KeyCode::Enter)).unwrap();
+        assert_eq!(state.mode, AppMode::NodeDetail);
+        let view = state.node_detail.as_mut().unwrap();
+        assert!(view.lifecycle.pending);
+        let pod = |namespace: &str, name: &str| NodePod {
+            namespace: namespace.to_string(),
+            name: name.to_string(),
+            phase: "Running".to_string(),
+            requests: ResourceAmounts::default(),
+            limits: ResourceAmounts::default(),
+        };
+        view.set_result(Ok(NodeDetail {
+            name: "worker-1".to_string(),
+            capacity: ResourceAmounts::default(),
+            allocatable: ResourceAmounts::default(),
+            requests: ResourceAmounts::default(),
+            limits: ResourceAmounts::default(),
+            conditions: Vec::new(),
+            taints: Vec::new(),
+            labels: Vec::new(),
+            pods: vec![pod("default", "api"), pod("prod", "web-abc")],
+        }));
+        assert!(!state.node_detail.as_ref().unwrap().lifecycle.pending);
+
+        state.handle_key_event(key(KeyCode::Char('j'))).unwrap();
+        state.handle_key_event(key(KeyCode::Char('j'))).unwrap();
+        state.handle_key_event(key(KeyCode::Char('R'))).unwrap();
+        assert!(state.node_detail.as_ref().unwrap().lifecycle.pending);
+        state.handle_key_event(key(KeyCode::Enter)).unwrap();
+        assert_eq!(state.mode, AppMode::PodList);
+        assert_eq!(state.current_namespace, "prod");
+        assert!(state.node_detail.is_none());
+
+        // Pod 列表加载后选中跳转的 Pod
+        let mut other = create_exec_test_pod(&["app"]);
+        other.name = "other".to_string();
+        state.set_pods(vec![other, create_exec_test_pod(&["app"])]);
+        assert_eq!(state.selected_pod_index, 1);
+        assert!(state.pending_pod_focus.is_none());
+    }
+
+    #[test]
+    fn test_secret_reveal_is_audited() {
+        use crate::kubectl::types::Secret;
+        let mut state = create_test_state();
+        state.mode = AppMode::SecretList;
+        state.secrets = vec![Secret {
+            name: "db".to_string(),
+            namespace: "prod".to_string(),
+            type_: "Opaque".to_string(),
+            data_count: 2,
+            age: "1d".to_string(),
+            tls: None,
+        }];
+
+        state.handle_key_event(key(KeyCode::Enter)).unwrap();
+        assert_eq!(state.mode, AppMode::SecretDetail);
+        state
+            .secret_view
+            .as_mut()
+            .unwrap()
+            .set_result(Ok(create_test_secret_detail(&[
+                ("password", b"s3cr3t"),
+                ("user", b"s3cr3t"),
+            ])));
+
+        state.handle_key_event(key(KeyCode::Char('j'))).unwrap();
+        state.handle_key_event(key(KeyCode::Char('r'))).unwrap();
+        let view = state.secret_view.as_ref().unwrap();
+        assert!(view.is_revealed("user"));
+        assert!(!view.is_revealed("password"));
+        assert_eq!(state.audit_log.entries.len(), 1);
+        assert_eq!(state.audit_log.entries[0].action, "secret.reveal");
+        assert_eq!(state.audit_log.entries[0].target, "prod/db:user");
+
+        // 隐藏不记录
+        state.handle_key_event(key(KeyCode::Char('r'))).unwrap();
+        assert!(!state.secret_view.as_ref().unwrap().is_revealed("user"));
+        assert_eq!(state.audit_log.entries.len(), 1);
+
+        state.handle_key_event(key(KeyCode::Esc)).unwrap();
+        assert_eq!(state.mode, AppMode::SecretList);
+        assert!(state.secret_view.is_none());
+    }
+
+    #[test]
+    fn test_secret_edit_is_audited() {
+        use crate::app::secret_view::SecretView;
+        let mut state = create_test_state();
+        state.mode = AppMode::SecretDetail;
+        let mut view = SecretView::new("prod", "db");
+        view.set_result(Ok(create_test_secret_detail(&[("password", b"old")])));
+        state.secret_view = Some(view);
+
+        state.handle_key_event(key(KeyCode::Char('e'))).unwrap();
+        let view = state.secret_view.as_mut().unwrap();
+        assert!(view.lifecycle.pending_edit.is_some());
+        assert_eq!(state.audit_log.entries[0].action, "secret.edit");
+        assert_eq!(state.audit_log.entries[0].target, "prod/db");
+
+        view.lifecycle.pending_edit = None;
+        view.set_edited(Ok("password: |-\n  new\ntoken: abc\n".to_string()));
+        state.handle_key_event(key(KeyCode::Char('y'))).unwrap();
+        assert!(state.secret_view.as_ref().unwrap().lifecycle.pending_patch);
+        let targets: Vec<&str> = state.audit_log.entries[1..]
+            .iter()
+            .map(|entry| entry.target.as_str())
+            .collect();
+        assert_eq!(targets, ["prod/db:password", "prod/db:token"]);
+        assert_eq!(state.mode, AppMode::SecretDetail);
+    }
+
+    #[test]
+    fn test_configmap_edit_requires_confirmation() {
+        use crate::kubectl::types::ConfigMap;
+        let mut state = create_test_state();
+        state.mode = AppMode::ConfigMapList;
+        state.configmaps = vec![ConfigMap {
+            name: "app".to_string(),
+            namespace: "prod".to_string(),
+            data_count: 1,
+            age: "1d".to_string(),
+        }];
+
+        state.handle_key_event(key(KeyCode::Enter)).unwrap();
+        assert_eq!(state.mode, AppMode::ConfigMapDetail);
+        let view = state.configmap_view.as_mut().unwrap();
+        let detail = create_test_configmap_detail(&[("app.yaml", "port: 80\n")]);
+        view.set_result(Ok(detail));
+
+        state.handle_key_event(key(KeyCode::Char('e'))).unwrap();
+        let view = state.configmap_view.as_mut().unwrap();
+        assert_eq!(
+            view.lifecycle.pending_edit.take().as_deref(),
+            Some("app.yaml")
+        );
+        view.set_edited("app.yaml", Ok("port: 8080\n".to_string()));
+
+        // 确认前 Esc 只放弃修改，不离开视图
+        state.handle_key_event(key(KeyCode::Esc)).unwrap();
+        let view = state.configmap_view.as_ref().unwrap();
+        assert!(view.lifecycle.edit.is_none());
+        assert!(!view.lifecycle.pending_patch);
+        assert_eq!(state.mode, AppMode::ConfigMapDetail);
+
+        let view = state.configmap_view.as_mut().unwrap();
+        view.set_edited("app.yaml", Ok("port: 8080\n".to_string()));
+        state.handle_key_event(key(KeyCode::Char('y'))).unwrap();
+        let view = state.configmap_view.as_mut().unwrap();
+        assert!(view.lifecycle.pending_patch);
+
+        view.lifecycle.edit = None;
+        state.handle_key_event(key(KeyCode::Esc)).unwrap();
+        assert_eq!(state.mode, AppMode::ConfigMapList);
+        assert!(state.configmap_view.is_none());
+    }
+
+    #[test]
+    fn test_node_top_view_sorting() {
+        use crate::app::state::NodeMetricsSort;
+        use crate::kubectl::types::NodeMetrics;
+        let mut state = create_test_state();
+        state.mode = AppMode::NodeList;
+        state.handle_key_event(key(KeyCode::Char('T'))).unwrap();
+        assert_eq!(state.mode, AppMode::TopView);
+        assert!(state.top_nodes);
+
+        let node = |name: &str, cpu: Option<f64>, memory: Option<f64>| NodeMetrics {
+            name: name.to_string(),
+            cpu: "100m".to_string(),
+            cpu_percentage: cpu,
+            memory: "1024Mi".to_string(),
+            memory_percentage: memory,
+        };
+        state.set_node_metrics(vec![
+            node("c", Some(10.0), Some(90.0)),
+            node("a", None, None),
+            node("b", Some(80.0), Some(20.0)),
+        ]);
+        let names = |state: &AppState| {
+            state
+                .node_metrics
+                .iter()
+                .map(|m| m.name.clone())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(names(&state), ["a", "b", "c"]);
+        state.handle_key_event(key(KeyCode::Char('s'))).unwrap();
+        assert_eq!(state.node_metrics_sort, NodeMetricsSort::Cpu);
+        assert_eq!(names(&state), ["b", "c", "a"]);
+        state.handle_key_event(key(KeyCode::Char('s'))).unwrap();
+        assert_eq!(names(&state), ["c", "b", "a"]);
+
+        // 选择不超出节点数
+        for _ in 0..5 {
+            state.handle_key_event(key(KeyCode::Char('j'))).unwrap();
+        }
+        assert_eq!(state.metrics_scroll, 2);
+    }
 }
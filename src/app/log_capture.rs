@@ -0,0 +1,347 @@
+//! Background capture of pod logs into rotating files
+
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, Local};
+
+use super::log_stream::LogStream;
+use crate::kubectl::types::{LogOptions, Pod};
+
+/// 单个日志文件的大小上限，超过后轮转
+pub const MAX_FILE_BYTES: u64 = 10 * 1024 * 1024;
+
+/// 保留的轮转文件数：`<name>.1.log` … `<name>.5.log`
+pub const ROTATED_FILES: usize = 5;
+
+/// 重新查询 Pod 列表、寻找替代 Pod 的间隔
+pub const DISCOVERY_INTERVAL: Duration = Duration::from_secs(5);
+
+/// 开始捕获时带上的历史行数
+const INITIAL_TAIL: u32 = 100;
+
+/// 切换到替代 Pod 时从头记录（kubectl 的 --tail 需要一个上限）
+const REPLACEMENT_TAIL: u32 = 100_000;
+
+/// 本次会话的捕获目录 `~/.local/share/kube-tui/captures/<时间>`
+pub fn session_dir(time: DateTime<Local>) -> PathBuf {
+    let home = std::env::var("HOME").unwrap_or_else(|_| "/".to_string());
+    PathBuf::from(home)
+        .join(".local/share/kube-tui/captures")
+        .join(time.format("%Y%m%d-%H%M%S").to_string())
+}
+
+fn rotated_path(path: &Path, index: usize) -> PathBuf {
+    path.with_extension(format!("{}.log", index))
+}
+
+/// `<name>.log` → `<name>.1.log` → … ，最旧的一个被覆盖
+fn rotate(path: &Path) -> std::io::Result<()> {
+    for index in (1..ROTATED_FILES).rev() {
+        let from = rotated_path(path, index);
+        if from.exists() {
+            std::fs::rename(&from, rotated_path(path, index + 1))?;
+        }
+    }
+    std::fs::rename(path, rotated_path(path, 1))
+}
+
+fn capture_options(tail: u32) -> LogOptions {
+    LogOptions {
+        all_containers: true,
+        timestamps: true,
+        tail,
+        ..LogOptions::default()
+    }
+}
+
+/// 原 Pod 消失后的替代：同一 owner 下未被占用的 Pod，优先 Running
+fn replacement<'a>(
+    pod: &str,
+    owner: Option<&str>,
+    pods: &'a [Pod],
+    taken: &[String],
+) -> Option<&'a Pod> {
+    if pods.iter().any(|p| p.name == pod) {
+        return None;
+    }
+    let owner = owner?;
+    pods.iter()
+        .filter(|p| p.owner.as_deref() == Some(owner) && !taken.contains(&p.name))
+        .max_by_key(|p| p.status.phase == "Running")
+}
+
+/// 一个 Pod（及其同一 owner 下的替代 Pod）的日志捕获
+#[derive(Debug, Clone)]
+pub struct LogCapture {
+    pub namespace: String,
+    pub pod: String,
+    /// 所属工作负载，Pod 被替换后据此找到新 Pod
+    pub owner: Option<String>,
+    pub path: PathBuf,
+    pub stream: LogStream,
+    /// 当前文件的字节数，用于判断轮转
+    pub file_bytes: u64,
+    pub total_bytes: u64,
+    pub started: Instant,
+    /// 跟随过的替代 Pod 数量
+    pub switches: usize,
+    pub error: Option<String>,
+}
+
+impl LogCapture {
+    pub fn start(dir: &Path, namespace: &str, pod: &str, owner: Option<String>) -> Self {
+        let path = dir.join(format!("{}_{}.log", namespace, pod));
+        // 同一会话内重新捕获时接着写已有文件
+        let file_bytes = std::fs::metadata(&path).map(|m| m.len()).unwrap_or(0);
+        Self {
+            namespace: namespace.to_string(),
+            pod: pod.to_string(),
+            owner,
+            path,
+            stream: LogStream::start(namespace, pod, capture_options(INITIAL_TAIL)),
+            file_bytes,
+            total_bytes: 0,
+            started: Instant::now(),
+            switches: 0,
+            error: None,
+        }
+    }
+
+    /// 追加到当前文件，超过上限时先轮转
+    pub fn write_lines(&mut self, lines: &[String]) {
+        if lines.is_empty() {
+            return;
+        }
+        let mut content = lines.join("\n");
+        content.push('\n');
+        let result = (|| -> std::io::Result<()> {
+            if let Some(parent) = self.path.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            if self.file_bytes > 0 && self.file_bytes + content.len() as u64 > MAX_FILE_BYTES {
+                rotate(&self.path)?;
+                self.file_bytes = 0;
+            }
+            let mut file = OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(&self.path)?;
+            file.write_all(content.as_bytes())
+        })();
+        match result {
+            Ok(()) => {
+                self.file_bytes += content.len() as u64;
+                self.total_bytes += content.len() as u64;
+                self.error = None;
+            }
+            Err(e) => self.error = Some(e.to_string()),
+        }
+    }
+
+    /// 收集新日志写入文件
+    pub fn poll(&mut self) {
+        let lines = self.stream.take_lines();
+        self.write_lines(&lines);
+    }
+
+    /// 原 Pod 已不存在时，改为跟随同一 owner 下未被其它捕获占用的 Pod
+    pub fn follow_replacement(&mut self, pods: &[Pod], taken: &[String]) -> bool {
+        let Some(next) = replacement(&self.pod, self.owner.as_deref(), pods, taken) else {
+            return false;
+        };
+        self.stream.stop();
+        let marker = format!(
+            "===== kube-tui: {} replaced by {} =====",
+            self.pod, next.name
+        );
+        self.write_lines(&[marker]);
+        self.pod = next.name.clone();
+        self.stream = LogStream::start(
+            &self.namespace,
+            &self.pod,
+            capture_options(REPLACEMENT_TAIL),
+        );
+        self.switches += 1;
+        true
+    }
+
+    pub fn stop(&self) {
+        self.stream.stop();
+    }
+}
+
+/// 会话内的所有后台捕获，离开任何视图都继续运行
+#[derive(Debug, Clone)]
+pub struct LogCaptureManager {
+    pub session_dir: PathBuf,
+    pub captures: Vec<LogCapture>,
+    pub selected: usize,
+    pub last_discovery: Option<Instant>,
+}
+
+impl LogCaptureManager {
+    pub fn new(session_dir: PathBuf) -> Self {
+        Self {
+            session_dir,
+            captures: Vec::new(),
+            selected: 0,
+            last_discovery: None,
+        }
+    }
+
+    pub fn is_capturing(&self, namespace: &str, pod: &str) -> bool {
+        self.captures
+            .iter()
+            .any(|c| c.namespace == namespace && c.pod == pod)
+    }
+
+    /// 开始或停止捕获一个 Pod，返回是否开始
+    pub fn toggle(&mut self, namespace: &str, pod: &str, owner: Option<String>) -> bool {
+        match self
+            .captures
+            .iter()
+            .position(|c| c.namespace == namespace && c.pod == pod)
+        {
+            Some(index) => {
+                self.stop(index);
+                false
+            }
+            None => {
+                let capture = LogCapture::start(&self.session_dir, namespace, pod, owner);
+                self.captures.push(capture);
+                true
+            }
+        }
+    }
+
+    pub fn stop(&mut self, index: usize) {
+        if index < self.captures.len() {
+            self.captures.remove(index).stop();
+            self.selected = self.selected.min(self.captures.len().saturating_sub(1));
+        }
+    }
+
+    pub fn stop_all(&mut self) {
+        for capture in self.captures.drain(..) {
+            capture.stop();
+        }
+        self.selected = 0;
+    }
+
+    pub fn poll(&mut self) {
+        for capture in &mut self.captures {
+            capture.poll();
+        }
+    }
+
+    pub fn should_discover(&self) -> bool {
+        !self.captures.is_empty()
+            && self
+                .last_discovery
+                .is_none_or(|last| last.elapsed() >= DISCOVERY_INTERVAL)
+    }
+
+    /// 有捕获的命名空间（去重）
+    pub fn namespaces(&self) -> Vec<String> {
+        let mut namespaces: Vec<String> =
+            self.captures.iter().map(|c| c.namespace.clone()).collect();
+        namespaces.sort();
+        namespaces.dedup();
+        namespaces
+    }
+
+    /// 按命名空间的最新 Pod 列表为已消失的 Pod 寻找替代
+    pub fn sync_pods(&mut self, namespace: &str, pods: &[Pod]) {
+        for index in 0..self.captures.len() {
+            if self.captures[index].namespace != namespace {
+                continue;
+            }
+            let taken: Vec<String> = self
+                .captures
+                .iter()
+                .filter(|c| c.namespace == namespace)
+                .map(|c| c.pod.clone())
+                .collect();
+            self.captures[index].follow_replacement(pods, &taken);
+        }
+    }
+
+    pub fn total_bytes(&self) -> u64 {
+        self.captures.iter().map(|c| c.total_bytes).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rotation_keeps_limited_files() {
+        let dir = std::env::temp_dir().join(format!("kube-tui-capture-{}", std::process::id()));
+        let path = dir.join("prod_web.log");
+        std::fs::create_dir_all(&dir).unwrap();
+        for round in 0..=ROTATED_FILES + 1 {
+            std::fs::write(&path, round.to_string()).unwrap();
+            rotate(&path).unwrap();
+        }
+        assert!(!path.exists());
+        // 最新的在 .1，最旧的被丢弃
+        let newest = std::fs::read_to_string(rotated_path(&path, 1)).unwrap();
+        assert_eq!(newest, (ROTATED_FILES + 1).to_string());
+        assert!(rotated_path(&path, ROTATED_FILES).exists());
+        assert!(!rotated_path(&path, ROTATED_FILES + 1).exists());
+        assert_eq!(
+            rotated_path(&path, 2).file_name().unwrap(),
+            "prod_web.2.log"
+        );
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_replacement_follows_same_owner() {
+        let pod = |name: &str, owner: &str, phase: &str| Pod {
+            name: name.to_string(),
+            namespace: "prod".to_string(),
+            status: crate::kubectl::types::PodStatus {
+                phase: phase.to_string(),
+                conditions: None,
+                container_statuses: None,
+            },
+            ready: "1/1".to_string(),
+            restarts: 0,
+            age: "1m".to_string(),
+            node: None,
+            ip: None,
+            containers: vec!["app".to_string()],
+            owner: Some(owner.to_string()),
+        };
+        let pods = vec![
+            pod("web-b", "Deployment/web", "Pending"),
+            pod("web-c", "Deployment/web", "Running"),
+            pod("api-a", "Deployment/api", "Running"),
+        ];
+        let taken = vec!["web-a".to_string()];
+        let next = replacement("web-a", Some("Deployment/web"), &pods, &taken);
+        assert_eq!(next.map(|p| p.name.as_str()), Some("web-c"));
+
+        // 原 Pod 仍在、已被占用或没有 owner 时不切换
+        assert!(replacement("web-b", Some("Deployment/web"), &pods, &taken).is_none());
+        let taken = vec![
+            "web-a".to_string(),
+            "web-b".to_string(),
+            "web-c".to_string(),
+        ];
+        assert!(replacement("web-a", Some("Deployment/web"), &pods, &taken).is_none());
+        assert!(replacement("web-a", None, &pods, &[]).is_none());
+    }
+
+    #[test]
+    fn test_session_dir() {
+        use chrono::TimeZone;
+        let time = Local.with_ymd_and_hms(2024, 5, 1, 9, 30, 5).unwrap();
+        assert!(session_dir(time).ends_with(".local/share/kube-tui/captures/20240501-093005"));
+    }
+}
@@ -0,0 +1,91 @@
+//! Log search: pattern compilation and grep-style filtered views
+
+use regex::{Regex, RegexBuilder};
+
+/// 日志视图中的一行：原始日志下标，或 grep 模式中不连续片段之间的分隔线
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LogViewLine {
+    Line(usize),
+    Separator,
+}
+
+/// 编译搜索模式；普通模式下按字面量匹配，空查询返回 None
+pub fn build_pattern(
+    query: &str,
+    regex: bool,
+    case_sensitive: bool,
+) -> Result<Option<Regex>, String> {
+    if query.is_empty() {
+        return Ok(None);
+    }
+    let source = if regex {
+        query.to_string()
+    } else {
+        regex::escape(query)
+    };
+    RegexBuilder::new(&source)
+        .case_insensitive(!case_sensitive)
+        .build()
+        .map(Some)
+        .map_err(|e| e.to_string())
+}
+
+/// 只保留匹配行及其前后 `context` 行，不相邻的片段之间插入分隔线
+pub fn grep_view(total: usize, matches: &[usize], context: usize) -> Vec<LogViewLine> {
+    let mut view = Vec::new();
+    let mut next = 0;
+    for &line in matches {
+        let start = line.saturating_sub(context).max(next);
+        let end = (line + context + 1).min(total);
+        if start >= end {
+            continue;
+        }
+        if start > next && !view.is_empty() {
+            view.push(LogViewLine::Separator);
+        }
+        view.extend((start..end).map(LogViewLine::Line));
+        next = end;
+    }
+    view
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_pattern() {
+        let plain = build_pattern("a.b", false, false).unwrap().unwrap();
+        assert!(plain.is_match("x A.B y"));
+        assert!(!plain.is_match("axb"));
+
+        let regex = build_pattern("a.b", true, true).unwrap().unwrap();
+        assert!(regex.is_match("axb"));
+        assert!(!regex.is_match("AXB"));
+
+        assert!(build_pattern("(", true, false).is_err());
+        assert!(build_pattern("", true, false).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_grep_view_merges_context() {
+        use LogViewLine::{Line, Separator};
+        // 匹配行 2 和 4 的上下文重叠合并，8 单独成段
+        let view = grep_view(10, &[2, 4, 8], 1);
+        assert_eq!(
+            view,
+            vec![
+                Line(1),
+                Line(2),
+                Line(3),
+                Line(4),
+                Line(5),
+                Separator,
+                Line(7),
+                Line(8),
+                Line(9)
+            ]
+        );
+        assert_eq!(grep_view(3, &[0], 0), vec![Line(0)]);
+    }
+}
@@ -0,0 +1,263 @@
+//! Long-lived `kubectl logs -f` follower with automatic reconnect
+
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Read};
+use std::process::Child;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use chrono::{DateTime, SecondsFormat, Utc};
+
+use crate::kubectl::commands;
+use crate::kubectl::types::LogOptions;
+
+/// 断开后等待多久重连
+const RECONNECT_DELAY: Duration = Duration::from_secs(2);
+
+/// 续接去重：按来源（`--prefix` 加的 `[pod/x/c] `）记录最后一行的时间戳。
+/// `--since-time` 只精确到秒，重连后会重放同一秒内已收到的行
+#[derive(Debug, Default)]
+struct ReplayFilter {
+    last_seen: HashMap<String, DateTime<Utc>>,
+}
+
+impl ReplayFilter {
+    /// 处理一行带 `--timestamps` 的输出：已收到过的行返回 None；
+    /// keep_timestamp 为 false 时去掉时间戳。无法识别时间戳的行原样保留
+    fn accept(&mut self, line: &str, keep_timestamp: bool) -> Option<String> {
+        let prefix_len = match line.find("] ") {
+            Some(end) if line.starts_with('[') => end + 2,
+            _ => 0,
+        };
+        let (prefix, rest) = line.split_at(prefix_len);
+        let (time, message) = rest.split_once(' ').unwrap_or((rest, ""));
+        let Ok(time) = DateTime::parse_from_rfc3339(time) else {
+            return Some(line.to_string());
+        };
+        let time = time.with_timezone(&Utc);
+        if self.last_seen.get(prefix).is_some_and(|last| time <= *last) {
+            return None;
+        }
+        self.last_seen.insert(prefix.to_string(), time);
+        if keep_timestamp {
+            Some(line.to_string())
+        } else {
+            Some(format!("{}{}", prefix, message))
+        }
+    }
+
+    /// 续接的 `--since-time`：各来源中最早的最后时间戳，向下取整到秒
+    fn since(&self) -> Option<String> {
+        self.last_seen
+            .values()
+            .min()
+            .map(|time| time.to_rfc3339_opts(SecondsFormat::Secs, true))
+    }
+}
+
+/// 跟随一个 Pod 的日志；克隆得到的是同一个后台读取线程的句柄
+#[derive(Clone)]
+pub struct LogStream {
+    pub namespace: String,
+    pub pod_name: String,
+    pub options: LogOptions,
+    pending: Arc<Mutex<Vec<String>>>,
+    child: Arc<Mutex<Option<Child>>>,
+    stopped: Arc<AtomicBool>,
+    connected: Arc<AtomicBool>,
+    reconnects: Arc<AtomicUsize>,
+    last_error: Arc<Mutex<Option<String>>>,
+}
+
+impl std::fmt::Debug for LogStream {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LogStream")
+            .field("namespace", &self.namespace)
+            .field("pod_name", &self.pod_name)
+            .field("options", &self.options)
+            .field("connected", &self.is_connected())
+            .field("reconnects", &self.reconnects())
+            .finish()
+    }
+}
+
+impl LogStream {
+    /// 按日志选项开始跟随；进程退出（如容器重启）后从最后收到的时间戳用 `--since-time`
+    /// 续上，重放的行按时间戳丢弃。`--previous` 读取的是已结束的实例，输出完毕后不再重连
+    pub fn start(namespace: &str, pod_name: &str, options: LogOptions) -> Self {
+        let stream = Self {
+            namespace: namespace.to_string(),
+            pod_name: pod_name.to_string(),
+            options,
+            pending: Arc::new(Mutex::new(Vec::new())),
+            child: Arc::new(Mutex::new(None)),
+            stopped: Arc::new(AtomicBool::new(false)),
+            connected: Arc::new(AtomicBool::new(false)),
+            reconnects: Arc::new(AtomicUsize::new(0)),
+            last_error: Arc::new(Mutex::new(None)),
+        };
+        let worker = stream.clone();
+        std::thread::spawn(move || worker.run());
+        stream
+    }
+
+    fn run(&self) {
+        let mut replay = ReplayFilter::default();
+        while !self.stopped.load(Ordering::Relaxed) {
+            // 收到过日志后才续接，避免容器等待启动期间丢失开头的日志
+            let since = replay.since();
+            let mut options = self.options.clone();
+            if let Some(time) = &since {
+                options.since = time.clone();
+            }
+            // 始终带时间戳读取以便去重，不需要时在 accept 中去掉
+            options.timestamps = true;
+            let follow = !options.previous;
+            let mut args = commands::log_args(&self.namespace, &self.pod_name, &options, follow);
+            if since.is_some() {
+                // 续接时需要 --since-time 之后的全部日志，去掉末尾的 --tail N
+                args.truncate(args.len() - 2);
+            }
+            let args: Vec<&str> = args.iter().map(String::as_str).collect();
+
+            if let Ok(mut child) = commands::spawn_kubectl(&args) {
+                let stdout = child.stdout.take();
+                let mut stderr = child.stderr.take();
+                if let Ok(mut slot) = self.child.lock() {
+                    *slot = Some(child);
+                }
+                // 停止请求可能发生在进程登记之前
+                if self.stopped.load(Ordering::Relaxed) {
+                    self.kill_child();
+                }
+                self.connected.store(true, Ordering::Relaxed);
+
+                if let Some(stdout) = stdout {
+                    for line in BufReader::new(stdout).lines() {
+                        let Ok(line) = line else {
+                            break;
+                        };
+                        let Some(line) = replay.accept(&line, self.options.timestamps) else {
+                            continue;
+                        };
+                        if let Ok(mut pending) = self.pending.lock() {
+                            pending.push(line);
+                        }
+                    }
+                }
+
+                self.connected.store(false, Ordering::Relaxed);
+                let mut error = String::new();
+                if let Some(stderr) = stderr.as_mut() {
+                    let _ = stderr.read_to_string(&mut error);
+                }
+                if let Ok(mut last_error) = self.last_error.lock() {
+                    *last_error = Some(error.trim().to_string()).filter(|e| !e.is_empty());
+                }
+                self.kill_child();
+            }
+
+            if self.stopped.load(Ordering::Relaxed) || self.options.previous {
+                break;
+            }
+            self.reconnects.fetch_add(1, Ordering::Relaxed);
+            let mut waited = Duration::ZERO;
+            while waited < RECONNECT_DELAY && !self.stopped.load(Ordering::Relaxed) {
+                std::thread::sleep(Duration::from_millis(100));
+                waited += Duration::from_millis(100);
+            }
+        }
+    }
+
+    fn kill_child(&self) {
+        if let Ok(mut slot) = self.child.lock()
+            && let Some(mut child) = slot.take()
+        {
+            let _ = child.kill();
+            let _ = child.wait();
+        }
+    }
+
+    /// 取走自上次调用以来收到的行
+    pub fn take_lines(&self) -> Vec<String> {
+        self.pending
+            .lock()
+            .map(|mut pending| std::mem::take(&mut *pending))
+            .unwrap_or_default()
+    }
+
+    pub fn is_connected(&self) -> bool {
+        self.connected.load(Ordering::Relaxed)
+    }
+
+    pub fn reconnects(&self) -> usize {
+        self.reconnects.load(Ordering::Relaxed)
+    }
+
+    pub fn last_error(&self) -> Option<String> {
+        self.last_error.lock().ok().and_then(|e| e.clone())
+    }
+
+    pub fn stop(&self) {
+        self.stopped.store(true, Ordering::Relaxed);
+        self.kill_child();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_replay_filter_drops_overlap() {
+        let mut replay = ReplayFilter::default();
+        assert_eq!(replay.since(), None);
+        assert_eq!(
+            replay.accept("2024-05-01T09:30:05.100000000Z started", false),
+            Some("started".to_string())
+        );
+        assert_eq!(
+            replay.accept("2024-05-01T09:30:05.200000000Z ready", true),
+            Some("2024-05-01T09:30:05.200000000Z ready".to_string())
+        );
+        assert_eq!(replay.since().as_deref(), Some("2024-05-01T09:30:05Z"));
+
+        // 重连后从 09:30:05 重放：已收到的行丢弃，之后的行保留
+        assert_eq!(
+            replay.accept("2024-05-01T09:30:05.100000000Z started", false),
+            None
+        );
+        assert_eq!(
+            replay.accept("2024-05-01T09:30:05.200000000Z ready", false),
+            None
+        );
+        assert_eq!(
+            replay.accept("2024-05-01T09:30:05.300000000Z serving", false),
+            Some("serving".to_string())
+        );
+        assert_eq!(
+            replay.accept("no timestamp", false),
+            Some("no timestamp".to_string())
+        );
+    }
+
+    #[test]
+    fn test_replay_filter_tracks_each_container() {
+        let mut replay = ReplayFilter::default();
+        let app = "[pod/web/app] 2024-05-01T09:30:07.000000000Z app line";
+        let sidecar = "[pod/web/sidecar] 2024-05-01T09:30:02.000000000Z sidecar line";
+        assert_eq!(
+            replay.accept(app, false).as_deref(),
+            Some("[pod/web/app] app line")
+        );
+        // 其他容器较早的行不受影响
+        assert_eq!(
+            replay.accept(sidecar, false).as_deref(),
+            Some("[pod/web/sidecar] sidecar line")
+        );
+        assert_eq!(replay.since().as_deref(), Some("2024-05-01T09:30:02Z"));
+        assert_eq!(replay.accept(app, false), None);
+        assert_eq!(replay.accept(sidecar, false), None);
+    }
+}
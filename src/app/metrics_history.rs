@@ -0,0 +1,175 @@
+//! Rolling CPU/memory history for TopView sparklines
+
+use std::collections::{HashMap, VecDeque};
+
+use crate::kubectl::quantity::parse_quantity;
+use crate::kubectl::types::PodMetrics;
+
+/// 每个 Pod/容器保留的样本数（每次刷新一个样本）
+pub const METRICS_HISTORY_LEN: usize = 60;
+
+/// 单个 Pod 或容器的历史：CPU 为毫核，内存为字节
+#[derive(Debug, Clone, Default)]
+pub struct MetricsSeries {
+    pub cpu: VecDeque<u64>,
+    pub memory: VecDeque<u64>,
+}
+
+/// 保留窗口内的最小/平均/最大值
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SeriesStats {
+    pub min: u64,
+    pub avg: f64,
+    pub max: u64,
+}
+
+impl SeriesStats {
+    pub fn of(samples: &VecDeque<u64>) -> Option<Self> {
+        let min = *samples.iter().min()?;
+        let max = *samples.iter().max()?;
+        let avg = samples.iter().sum::<u64>() as f64 / samples.len() as f64;
+        Some(Self { min, avg, max })
+    }
+}
+
+impl MetricsSeries {
+    fn push(&mut self, cpu: &str, memory: &str) {
+        // 无法解析的样本记为 0，保持 CPU 与内存的样本对齐
+        let cpu = parse_quantity(cpu).map_or(0, |cores| (cores * 1000.0).round() as u64);
+        let memory = parse_quantity(memory).map_or(0, |bytes| bytes.round() as u64);
+        for (samples, value) in [(&mut self.cpu, cpu), (&mut self.memory, memory)] {
+            if samples.len() == METRICS_HISTORY_LEN {
+                samples.pop_front();
+            }
+            samples.push_back(value);
+        }
+    }
+
+    pub fn sample_count(&self) -> usize {
+        self.cpu.len()
+    }
+}
+
+/// TopView 的指标历史，按 "命名空间/Pod" 和 "命名空间/Pod/容器" 索引
+#[derive(Debug, Clone, Default)]
+pub struct MetricsHistory {
+    series: HashMap<String, MetricsSeries>,
+}
+
+impl MetricsHistory {
+    /// 记录一次刷新的结果；不在本次结果中的 Pod（已删除或切换了命名空间）丢弃其历史
+    pub fn record(&mut self, metrics: &[PodMetrics]) {
+        let mut series = HashMap::new();
+        for pod in metrics {
+            let key = Self::pod_key(&pod.namespace, &pod.name);
+            for container in &pod.containers {
+                let key = Self::container_key(&key, &container.name);
+                let mut entry = self.series.remove(&key).unwrap_or_default();
+                entry.push(&container.cpu, &container.memory);
+                series.insert(key, entry);
+            }
+            let mut entry = self.series.remove(&key).unwrap_or_default();
+            entry.push(&pod.cpu, &pod.memory);
+            series.insert(key, entry);
+        }
+        self.series = series;
+    }
+
+    pub fn pod(&self, namespace: &str, pod: &str) -> Option<&MetricsSeries> {
+        self.series.get(&Self::pod_key(namespace, pod))
+    }
+
+    pub fn container(&self, namespace: &str, pod: &str, container: &str) -> Option<&MetricsSeries> {
+        self.series.get(&Self::container_key(
+            &Self::pod_key(namespace, pod),
+            container,
+        ))
+    }
+
+    fn pod_key(namespace: &str, pod: &str) -> String {
+        format!("{}/{}", namespace, pod)
+    }
+
+    fn container_key(pod_key: &str, container: &str) -> String {
+        format!("{}/{}", pod_key, container)
+    }
+}
+
+/// 用方块字符绘制的迷你趋势图，按窗口内最大值归一化，只取最近 width 个样本
+pub fn sparkline(samples: &VecDeque<u64>, width: usize) -> String {
+    const BARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+    let skip = samples.len().saturating_sub(width);
+    let max = samples.iter().skip(skip).max().copied().unwrap_or(0);
+    samples
+        .iter()
+        .skip(skip)
+        .map(|&value| {
+            (value * (BARS.len() as u64 - 1))
+                .checked_div(max)
+                .map_or(BARS[0], |level| BARS[level as usize])
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kubectl::types::{ContainerMetrics, ResourceMetrics};
+
+    fn pod(name: &str, cpu: &str, memory: &str) -> ResourceMetrics {
+        ResourceMetrics {
+            name: name.to_string(),
+            namespace: "default".to_string(),
+            cpu: cpu.to_string(),
+            memory: memory.to_string(),
+            bounds: Default::default(),
+            usage: Default::default(),
+            containers: vec![ContainerMetrics {
+                name: "app".to_string(),
+                cpu: cpu.to_string(),
+                memory: memory.to_string(),
+                bounds: Default::default(),
+                usage: Default::default(),
+            }],
+        }
+    }
+
+    #[test]
+    fn test_history_rolls_and_drops_missing_pods() {
+        let mut history = MetricsHistory::default();
+        history.record(&[pod("web", "100m", "10Mi"), pod("db", "1", "1Gi")]);
+        history.record(&[pod("web", "300m", "30Mi")]);
+        assert!(history.pod("default", "db").is_none());
+
+        let web = history.pod("default", "web").unwrap();
+        assert_eq!(web.cpu, [100, 300]);
+        let stats = SeriesStats::of(&web.memory).unwrap();
+        assert_eq!(stats.min, 10 * 1024 * 1024);
+        assert_eq!(stats.avg, 20.0 * 1024.0 * 1024.0);
+        assert_eq!(stats.max, 30 * 1024 * 1024);
+        assert_eq!(
+            history
+                .container("default", "web", "app")
+                .unwrap()
+                .sample_count(),
+            2
+        );
+
+        for _ in 0..METRICS_HISTORY_LEN {
+            history.record(&[pod("web", "5m", "1Mi")]);
+        }
+        let web = history.pod("default", "web").unwrap();
+        assert_eq!(web.sample_count(), METRICS_HISTORY_LEN);
+        assert!(web.cpu.iter().all(|&cpu| cpu == 5));
+    }
+
+    #[test]
+    fn test_sparkline() {
+        let samples = VecDeque::from(vec![0, 7, 14, 7]);
+        assert_eq!(sparkline(&samples, 10), "▁▄█▄");
+        assert_eq!(sparkline(&samples, 2), "█▄");
+        assert_eq!(sparkline(&VecDeque::from(vec![0, 0]), 10), "▁▁");
+        assert_eq!(sparkline(&VecDeque::new(), 10), "");
+        assert!(SeriesStats::of(&VecDeque::new()).is_none());
+    }
+}
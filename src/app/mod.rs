@@ -1,6 +1,30 @@
+pub mod ansi;
+pub mod audit;
+pub mod clipboard;
 pub mod config;
+pub mod configmap_view;
+pub mod content_search;
+pub mod describe_view;
+pub mod detail_lifecycle;
+pub mod diff;
+pub mod editor;
+pub mod file_browser;
 pub mod key_handler;
+pub mod log_capture;
+pub mod log_search;
+pub mod log_stream;
+pub mod metrics_history;
+pub mod multi_log;
+pub mod node_detail;
+pub mod node_drain;
+pub mod save;
+pub mod secret_edit;
+pub mod secret_view;
 pub mod state;
+pub mod structured_log;
+pub mod terminal;
+pub mod yaml_neat;
+pub mod yaml_tree;
 
 // Re-export commonly used types
 pub use state::{AppMode, AppState};
@@ -0,0 +1,201 @@
+//! stern-style aggregated tailing of every pod matching a label selector
+
+use std::collections::BTreeMap;
+use std::time::{Duration, Instant};
+
+use super::log_stream::LogStream;
+use super::state::LOG_BUFFER_LINES;
+use crate::kubectl::types::LogOptions;
+
+/// 重新按选择器查找 Pod 的间隔，用于发现新 Pod
+pub const DISCOVERY_INTERVAL: Duration = Duration::from_secs(5);
+
+/// 每个新 Pod 开始跟随时带上的历史行数
+const INITIAL_TAIL: u32 = 50;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AggregatedLine {
+    pub pod: String,
+    pub container: String,
+    /// kubectl --timestamps 输出的 RFC3339Nano 时间，固定宽度可直接按字符串排序
+    pub timestamp: String,
+    pub text: String,
+}
+
+impl AggregatedLine {
+    /// 解析 `kubectl logs --prefix --timestamps` 的一行：`[pod/<pod>/<container>] <time> <text>`
+    pub fn parse(pod: &str, line: &str) -> Self {
+        let (container, rest) = match line.strip_prefix('[').and_then(|l| l.split_once("] ")) {
+            Some((prefix, rest)) => {
+                let container = prefix.rsplit('/').next().unwrap_or_default();
+                (container.to_string(), rest)
+            }
+            None => (String::new(), line),
+        };
+        let (timestamp, text) = match rest.split_once(' ') {
+            Some((time, text)) if chrono::DateTime::parse_from_rfc3339(time).is_ok() => {
+                (time.to_string(), text.to_string())
+            }
+            _ => (String::new(), rest.to_string()),
+        };
+        Self {
+            pod: pod.to_string(),
+            container,
+            timestamp,
+            text,
+        }
+    }
+
+    /// 用于配色的来源标识
+    pub fn source(&self) -> String {
+        if self.container.is_empty() {
+            self.pod.clone()
+        } else {
+            format!("{}/{}", self.pod, self.container)
+        }
+    }
+}
+
+/// 聚合日志视图：每个匹配的 Pod 一个跟随进程，按时间戳交错合并
+#[derive(Debug, Clone)]
+pub struct MultiLogView {
+    pub namespace: String,
+    pub selector: String,
+    /// 标题中显示的来源，如 `Deployment/web` 或 `-l app=web`
+    pub label: String,
+    pub streams: BTreeMap<String, LogStream>,
+    pub lines: Vec<AggregatedLine>,
+    pub scroll: usize,
+    pub auto_scroll: bool,
+    pub last_discovery: Option<Instant>,
+    pub error: Option<String>,
+}
+
+impl MultiLogView {
+    pub fn new(namespace: &str, selector: &str, label: &str) -> Self {
+        Self {
+            namespace: namespace.to_string(),
+            selector: selector.to_string(),
+            label: label.to_string(),
+            streams: BTreeMap::new(),
+            lines: Vec::new(),
+            scroll: 0,
+            auto_scroll: true,
+            last_discovery: None,
+            error: None,
+        }
+    }
+
+    pub fn should_discover(&self) -> bool {
+        self.last_discovery
+            .is_none_or(|last| last.elapsed() >= DISCOVERY_INTERVAL)
+    }
+
+    /// 按最新的 Pod 列表启动新 Pod 的跟随、停止已消失的 Pod（保留其已收到的日志）
+    pub fn sync_pods(&mut self, pods: &[String]) {
+        self.last_discovery = Some(Instant::now());
+        self.error = None;
+        self.streams.retain(|name, stream| {
+            let keep = pods.contains(name);
+            if !keep {
+                stream.stop();
+            }
+            keep
+        });
+        for pod in pods {
+            if !self.streams.contains_key(pod) {
+                let options = LogOptions {
+                    all_containers: true,
+                    timestamps: true,
+                    tail: INITIAL_TAIL,
+                    ..LogOptions::default()
+                };
+                let stream = LogStream::start(&self.namespace, pod, options);
+                self.streams.insert(pod.clone(), stream);
+            }
+        }
+    }
+
+    /// 收集所有跟随进程的新日志
+    pub fn poll(&mut self) {
+        let mut incoming = Vec::new();
+        for (pod, stream) in &self.streams {
+            incoming.extend(
+                stream
+                    .take_lines()
+                    .iter()
+                    .map(|line| AggregatedLine::parse(pod, line)),
+            );
+        }
+        self.insert_lines(incoming);
+    }
+
+    /// 按时间戳插入；实时日志基本落在末尾，新 Pod 的历史行会插回到对应位置
+    pub fn insert_lines(&mut self, incoming: Vec<AggregatedLine>) {
+        if incoming.is_empty() {
+            return;
+        }
+        for line in incoming {
+            let index = self
+                .lines
+                .partition_point(|existing| existing.timestamp <= line.timestamp);
+            self.lines.insert(index, line);
+        }
+        let overflow = self.lines.len().saturating_sub(LOG_BUFFER_LINES);
+        if overflow > 0 {
+            self.lines.drain(..overflow);
+        }
+        if self.auto_scroll {
+            self.scroll = self.lines.len().saturating_sub(1);
+        } else {
+            self.scroll = self.scroll.saturating_sub(overflow);
+        }
+    }
+
+    pub fn stop(&mut self) {
+        for stream in self.streams.values() {
+            stream.stop();
+        }
+        self.streams.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_prefixed_line() {
+        let line = AggregatedLine::parse(
+            "web-1",
+            "[pod/web-1/app] 2024-05-01T10:00:00.000000001Z GET /healthz 200",
+        );
+        assert_eq!(line.container, "app");
+        assert_eq!(line.timestamp, "2024-05-01T10:00:00.000000001Z");
+        assert_eq!(line.text, "GET /healthz 200");
+        assert_eq!(line.source(), "web-1/app");
+
+        let plain = AggregatedLine::parse("web-1", "no prefix here");
+        assert_eq!(plain.container, "");
+        assert_eq!(plain.timestamp, "");
+        assert_eq!(plain.text, "no prefix here");
+    }
+
+    #[test]
+    fn test_interleave_by_timestamp() {
+        let mut view = MultiLogView::new("default", "app=web", "Deployment/web");
+        let line = |pod: &str, time: &str| AggregatedLine {
+            pod: pod.to_string(),
+            container: "app".to_string(),
+            timestamp: format!("2024-05-01T10:00:0{}.000000000Z", time),
+            text: time.to_string(),
+        };
+        view.insert_lines(vec![line("a", "1"), line("a", "4")]);
+        // 新 Pod 的历史日志交错插入
+        view.insert_lines(vec![line("b", "2"), line("b", "5")]);
+        view.insert_lines(vec![line("a", "3")]);
+        let order: Vec<_> = view.lines.iter().map(|l| l.text.as_str()).collect();
+        assert_eq!(order, vec!["1", "2", "3", "4", "5"]);
+        assert_eq!(view.scroll, 4);
+    }
+}
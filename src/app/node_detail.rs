@@ -0,0 +1,50 @@
+//! Node capacity vs allocatable vs summed pod requests/limits
+
+use super::detail_lifecycle::DetailLifecycle;
+use crate::kubectl::types::{NodeDetail, NodePod};
+
+/// 节点详情视图：只读，不进入编辑流程
+#[derive(Debug, Clone)]
+pub struct NodeDetailView {
+    pub node: String,
+    pub lifecycle: DetailLifecycle<NodeDetail>,
+    /// 选中的 Pod，Enter 跳转到其命名空间的 Pod 列表
+    pub selected: usize,
+}
+
+impl NodeDetailView {
+    pub fn new(node: &str) -> Self {
+        Self {
+            node: node.to_string(),
+            lifecycle: DetailLifecycle::default(),
+            selected: 0,
+        }
+    }
+
+    pub fn set_result(&mut self, result: anyhow::Result<NodeDetail>) {
+        if let Some(detail) = self.lifecycle.set_result(result) {
+            self.selected = self.selected.min(detail.pods.len().saturating_sub(1));
+        }
+    }
+
+    pub fn pods(&self) -> &[NodePod] {
+        self.lifecycle
+            .detail
+            .as_ref()
+            .map_or(&[], |d| d.pods.as_slice())
+    }
+
+    pub fn selected_pod(&self) -> Option<&NodePod> {
+        self.pods().get(self.selected)
+    }
+
+    pub fn select_next(&mut self) {
+        if self.selected + 1 < self.pods().len() {
+            self.selected += 1;
+        }
+    }
+
+    pub fn select_previous(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+}
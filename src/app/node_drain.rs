@@ -0,0 +1,415 @@
+//! Node cordon / uncordon and `kubectl drain` with per-pod eviction progress
+
+use std::io::{BufRead, BufReader, Read};
+use std::process::Child;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
+use crate::kubectl::commands;
+use crate::kubectl::types::DrainOptions;
+
+/// 排空选项弹窗的行数
+pub const DRAIN_OPTION_ROWS: usize = 4;
+
+/// 等待主循环执行的节点操作
+#[derive(Debug, Clone, PartialEq)]
+pub enum NodeAction {
+    Cordon(String),
+    Uncordon(String),
+    Drain(String, DrainOptions),
+}
+
+/// `kubectl drain --timeout` 接受的时长，如 `90s`、`5m`、`1h30m`
+pub fn is_valid_duration(value: &str) -> bool {
+    let mut digits = 0;
+    for c in value.chars() {
+        match c {
+            '0'..='9' => digits += 1,
+            's' | 'm' | 'h' if digits > 0 => digits = 0,
+            _ => return false,
+        }
+    }
+    !value.is_empty() && digits == 0
+}
+
+/// 排空选项弹窗：宽限期和超时为文本输入，Enter 校验通过后开始排空
+#[derive(Debug, Clone)]
+pub struct DrainDialog {
+    pub node: String,
+    pub options: DrainOptions,
+    pub selected: usize,
+    pub grace_input: String,
+    pub error: Option<String>,
+}
+
+impl DrainDialog {
+    pub fn new(node: &str) -> Self {
+        Self {
+            node: node.to_string(),
+            options: DrainOptions::default(),
+            selected: 0,
+            grace_input: String::new(),
+            error: None,
+        }
+    }
+
+    pub fn validated(&self) -> Result<DrainOptions, String> {
+        let mut options = self.options.clone();
+        let grace = self.grace_input.trim();
+        options.grace_period = if grace.is_empty() {
+            None
+        } else {
+            Some(
+                grace
+                    .parse()
+                    .map_err(|_| format!("Invalid grace period '{}': expected seconds", grace))?,
+            )
+        };
+        options.timeout = options.timeout.trim().to_string();
+        if !options.timeout.is_empty() && !is_valid_duration(&options.timeout) {
+            return Err(format!(
+                "Invalid timeout '{}': use a duration like 90s / 5m",
+                options.timeout
+            ));
+        }
+        Ok(options)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum EvictionState {
+    Evicting,
+    /// 驱逐被拒绝，kubectl 会稍后重试
+    Retrying(String),
+    Evicted,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DrainPod {
+    pub namespace: String,
+    pub name: String,
+    pub state: EvictionState,
+    /// 被拒绝的次数
+    pub attempts: usize,
+    /// 被 PodDisruptionBudget 阻止过
+    pub pdb_blocked: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum DrainStatus {
+    Running,
+    Succeeded,
+    Failed(String),
+    Cancelled,
+}
+
+/// 从 kubectl drain 的输出逐行解析出的进度
+#[derive(Debug, Clone)]
+pub struct DrainProgress {
+    pub cordoned: bool,
+    pub drained: bool,
+    pub pods: Vec<DrainPod>,
+    /// 被忽略的 DaemonSet Pod
+    pub ignored: Vec<String>,
+    pub errors: Vec<String>,
+    pub status: DrainStatus,
+    pub finished: Option<Instant>,
+}
+
+impl Default for DrainProgress {
+    fn default() -> Self {
+        Self {
+            cordoned: false,
+            drained: false,
+            pods: Vec::new(),
+            ignored: Vec::new(),
+            errors: Vec::new(),
+            status: DrainStatus::Running,
+            finished: None,
+        }
+    }
+}
+
+impl DrainProgress {
+    fn pod_mut(&mut self, namespace: Option<&str>, name: &str) -> &mut DrainPod {
+        let index = self
+            .pods
+            .iter()
+            .position(|pod| pod.name == name && namespace.is_none_or(|ns| pod.namespace == ns));
+        let index = index.unwrap_or_else(|| {
+            self.pods.push(DrainPod {
+                namespace: namespace.unwrap_or_default().to_string(),
+                name: name.to_string(),
+                state: EvictionState::Evicting,
+                attempts: 0,
+                pdb_blocked: false,
+            });
+            self.pods.len() - 1
+        });
+        &mut self.pods[index]
+    }
+
+    /// 解析一行 stdout/stderr 输出
+    pub fn apply_line(&mut self, line: &str) {
+        let line = line.trim();
+        if let Some(pod) = line.strip_prefix("evicting pod ") {
+            let (namespace, name) = pod.split_once('/').unwrap_or(("", pod));
+            self.pod_mut(Some(namespace), name);
+        } else if let Some(name) = line
+            .strip_prefix("pod/")
+            .and_then(|rest| rest.strip_suffix(" evicted"))
+        {
+            self.pod_mut(None, name).state = EvictionState::Evicted;
+        } else if let Some(rest) = line.strip_prefix("error when evicting pods/") {
+            // error when evicting pods/"web-1" -n "prod" (will retry after 5s): <原因>
+            let mut quoted = rest.split('"').skip(1).step_by(2);
+            let name = quoted.next().unwrap_or_default().to_string();
+            let namespace = quoted.next().unwrap_or_default().to_string();
+            let reason = rest
+                .split_once("): ")
+                .map(|(_, reason)| reason.to_string())
+                .unwrap_or_else(|| rest.to_string());
+            let pod = self.pod_mut(Some(&namespace), &name);
+            pod.attempts += 1;
+            pod.pdb_blocked |= reason.contains("disruption budget");
+            pod.state = EvictionState::Retrying(reason);
+        } else if let Some(pods) = line.strip_prefix("Warning: ignoring DaemonSet-managed Pods: ") {
+            self.ignored.extend(pods.split(", ").map(String::from));
+        } else if line.starts_with("node/") && line.ends_with(" cordoned") {
+            self.cordoned = true;
+        } else if line.starts_with("node/") && line.ends_with(" drained") {
+            self.drained = true;
+        } else if line.starts_with("error:") {
+            self.errors.push(line.to_string());
+        }
+    }
+
+    pub fn count(&self, state: fn(&EvictionState) -> bool) -> usize {
+        self.pods.iter().filter(|pod| state(&pod.state)).count()
+    }
+
+    pub fn pdb_blocked(&self) -> usize {
+        self.pods.iter().filter(|pod| pod.pdb_blocked).count()
+    }
+}
+
+/// 后台运行的 kubectl drain；克隆得到的是同一进程的句柄
+#[derive(Clone)]
+pub struct NodeDrain {
+    pub node: String,
+    pub options: DrainOptions,
+    pub started: Instant,
+    pub selected: usize,
+    /// 结束后是否已刷新节点列表
+    pub refreshed: bool,
+    progress: Arc<Mutex<DrainProgress>>,
+    child: Arc<Mutex<Option<Child>>>,
+    cancelled: Arc<AtomicBool>,
+}
+
+impl std::fmt::Debug for NodeDrain {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("NodeDrain")
+            .field("node", &self.node)
+            .field("options", &self.options)
+            .field("status", &self.progress().status)
+            .finish()
+    }
+}
+
+impl NodeDrain {
+    pub fn start(node: &str, options: DrainOptions) -> Self {
+        let drain = Self::new(node, options);
+        let worker = drain.clone();
+        std::thread::spawn(move || worker.run());
+        drain
+    }
+
+    fn new(node: &str, options: DrainOptions) -> Self {
+        Self {
+            node: node.to_string(),
+            options,
+            started: Instant::now(),
+            selected: 0,
+            refreshed: false,
+            progress: Arc::new(Mutex::new(DrainProgress::default())),
+            child: Arc::new(Mutex::new(None)),
+            cancelled: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    fn read_lines(&self, reader: impl Read + Send + 'static) -> std::thread::JoinHandle<()> {
+        let progress = self.progress.clone();
+        std::thread::spawn(move || {
+            for line in BufReader::new(reader).lines() {
+                let Ok(line) = line else {
+                    break;
+                };
+                if let Ok(mut progress) = progress.lock() {
+                    progress.apply_line(&line);
+                }
+            }
+        })
+    }
+
+    fn run(&self) {
+        let args = commands::drain_args(&self.node, &self.options);
+        let args: Vec<&str> = args.iter().map(String::as_str).collect();
+        let mut child = match commands::spawn_kubectl(&args) {
+            Ok(child) => child,
+            Err(e) => {
+                self.finish(DrainStatus::Failed(e.to_string()));
+                return;
+            }
+        };
+        let readers: Vec<_> = [
+            child.stdout.take().map(|out| self.read_lines(out)),
+            child.stderr.take().map(|err| self.read_lines(err)),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+        self.store_child(child);
+        // 两个管道都关闭说明进程已退出（或被取消）
+        for reader in readers {
+            let _ = reader.join();
+        }
+        let exit = self
+            .child
+            .lock()
+            .ok()
+            .and_then(|mut slot| slot.take())
+            .and_then(|mut child| child.wait().ok());
+
+        let status = if self.cancelled.load(Ordering::Relaxed) {
+            DrainStatus::Cancelled
+        } else if exit.is_some_and(|status| status.success()) {
+            DrainStatus::Succeeded
+        } else {
+            let error = self
+                .progress()
+                .errors
+                .last()
+                .cloned()
+                .unwrap_or_else(|| "kubectl drain failed".to_string());
+            DrainStatus::Failed(error)
+        };
+        self.finish(status);
+    }
+
+    /// 登记进程以便取消；在登记之前已取消时立即终止，不让 drain 在后台继续驱逐
+    fn store_child(&self, mut child: Child) {
+        if let Ok(mut slot) = self.child.lock() {
+            if self.cancelled.load(Ordering::Relaxed) {
+                let _ = child.kill();
+            }
+            *slot = Some(child);
+        }
+    }
+
+    fn finish(&self, status: DrainStatus) {
+        if let Ok(mut progress) = self.progress.lock() {
+            progress.status = status;
+            progress.finished = Some(Instant::now());
+        }
+    }
+
+    pub fn progress(&self) -> DrainProgress {
+        self.progress
+            .lock()
+            .map(|p| p.clone())
+            .unwrap_or_else(|_| DrainProgress::default())
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.progress().status == DrainStatus::Running
+    }
+
+    /// 终止 kubectl drain；已驱逐的 Pod 不会恢复，节点保持 cordon
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+        if let Ok(mut slot) = self.child.lock()
+            && let Some(child) = slot.as_mut()
+        {
+            let _ = child.kill();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_drain_output() {
+        let mut progress = DrainProgress::default();
+        for line in [
+            "node/worker-1 cordoned",
+            "Warning: ignoring DaemonSet-managed Pods: kube-system/kube-proxy-x, kube-system/fluentd-y",
+            "evicting pod prod/web-1",
+            "evicting pod prod/db-0",
+            r#"error when evicting pods/"db-0" -n "prod" (will retry after 5s): Cannot evict pod as it would violate the pod's disruption budget."#,
+            r#"error when evicting pods/"db-0" -n "prod" (will retry after 5s): Cannot evict pod as it would violate the pod's disruption budget."#,
+            "pod/web-1 evicted",
+            "error: unable to drain node \"worker-1\" due to error: timeout",
+        ] {
+            progress.apply_line(line);
+        }
+        assert!(progress.cordoned && !progress.drained);
+        assert_eq!(progress.ignored.len(), 2);
+        assert_eq!(progress.pods.len(), 2);
+        assert_eq!(progress.pods[0].state, EvictionState::Evicted);
+        let db = &progress.pods[1];
+        assert_eq!((db.namespace.as_str(), db.attempts), ("prod", 2));
+        assert!(db.pdb_blocked);
+        assert!(
+            matches!(db.state, EvictionState::Retrying(ref r) if r.starts_with("Cannot evict"))
+        );
+        assert_eq!(progress.count(|s| *s == EvictionState::Evicted), 1);
+        assert_eq!(progress.pdb_blocked(), 1);
+        assert_eq!(progress.errors.len(), 1);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_cancel_before_child_is_stored() {
+        let drain = NodeDrain::new("worker-1", DrainOptions::default());
+        drain.cancel();
+        let child = std::process::Command::new("sleep")
+            .arg("30")
+            .spawn()
+            .unwrap();
+        drain.store_child(child);
+        let mut child = drain.child.lock().unwrap().take().unwrap();
+        // 被终止而不是正常退出
+        assert!(!child.wait().unwrap().success());
+    }
+
+    #[test]
+    fn test_drain_dialog_validation() {
+        let mut dialog = DrainDialog::new("worker-1");
+        assert_eq!(dialog.validated().unwrap(), DrainOptions::default());
+
+        dialog.grace_input = "30".to_string();
+        dialog.options.timeout = "1h30m".to_string();
+        let options = dialog.validated().unwrap();
+        assert_eq!(options.grace_period, Some(30));
+        assert_eq!(
+            commands::drain_args("worker-1", &options),
+            vec![
+                "drain",
+                "worker-1",
+                "--ignore-daemonsets",
+                "--grace-period=30",
+                "--timeout=1h30m"
+            ]
+        );
+
+        dialog.options.timeout = "5 minutes".to_string();
+        assert!(dialog.validated().is_err());
+        dialog.options.timeout.clear();
+        dialog.grace_input = "abc".to_string();
+        assert!(dialog.validated().is_err());
+    }
+}
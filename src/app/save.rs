@@ -0,0 +1,142 @@
+//! Saving the logs / describe / YAML buffer to a local file
+
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use chrono::{DateTime, Local};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SaveKind {
+    /// 当前日志缓冲区
+    Logs,
+    /// 不带 --tail 重新获取的完整日志
+    FullLogs,
+    Describe,
+    Yaml,
+    /// 去掉服务端字段、可重新 apply 的 YAML
+    NeatYaml,
+}
+
+impl SaveKind {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Logs | Self::FullLogs => "log",
+            Self::Describe => "txt",
+            Self::Yaml | Self::NeatYaml => "yaml",
+        }
+    }
+}
+
+/// 默认文件名 `<namespace>_<name>_<timestamp>.<ext>`
+pub fn default_file_name(
+    namespace: &str,
+    name: &str,
+    kind: SaveKind,
+    time: DateTime<Local>,
+) -> String {
+    let clean = |s: &str| s.replace(['/', '\\', ' '], "_");
+    format!(
+        "{}_{}_{}.{}",
+        clean(namespace),
+        clean(name),
+        time.format("%Y%m%d-%H%M%S"),
+        kind.extension()
+    )
+}
+
+/// 展开开头的 `~/`，相对路径相对于当前工作目录
+pub fn expand_path(input: &str) -> PathBuf {
+    match input.strip_prefix("~/") {
+        Some(rest) => match std::env::var_os("HOME") {
+            Some(home) => Path::new(&home).join(rest),
+            None => PathBuf::from(input),
+        },
+        None => PathBuf::from(input),
+    }
+}
+
+/// 写入文件，缺少的上级目录一并创建
+pub fn write_file(path: &Path, content: &str) -> Result<()> {
+    if let Some(parent) = path.parent()
+        && !parent.as_os_str().is_empty()
+    {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(path, content)?;
+    Ok(())
+}
+
+/// 保存弹窗：输入路径，Enter 后由主循环取内容并写入
+#[derive(Debug, Clone)]
+pub struct SaveDialog {
+    pub kind: SaveKind,
+    pub input: String,
+    /// 等待主循环写入
+    pub pending: bool,
+    /// 目标文件已存在时需再按一次 Enter 确认覆盖
+    pub confirm_overwrite: bool,
+    /// 写入结果：成功提示或错误
+    pub result: Option<Result<String, String>>,
+}
+
+impl SaveDialog {
+    pub fn new(kind: SaveKind, default_path: String) -> Self {
+        Self {
+            kind,
+            input: default_path,
+            pending: false,
+            confirm_overwrite: false,
+            result: None,
+        }
+    }
+
+    /// 确认输入的路径；已存在的文件需要二次确认
+    pub fn submit(&mut self) {
+        if self.input.trim().is_empty() {
+            return;
+        }
+        if !self.confirm_overwrite && expand_path(self.input.trim()).exists() {
+            self.confirm_overwrite = true;
+            return;
+        }
+        self.pending = true;
+    }
+
+    pub fn edit(&mut self) {
+        self.confirm_overwrite = false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_default_file_name() {
+        let time = Local.with_ymd_and_hms(2024, 5, 1, 9, 30, 5).unwrap();
+        assert_eq!(
+            default_file_name("prod", "web-1", SaveKind::FullLogs, time),
+            "prod_web-1_20240501-093005.log"
+        );
+        assert_eq!(
+            default_file_name("", "a/b", SaveKind::Yaml, time),
+            "_a_b_20240501-093005.yaml"
+        );
+    }
+
+    #[test]
+    fn test_write_and_overwrite_confirm() {
+        let dir = std::env::temp_dir().join(format!("kube-tui-save-{}", std::process::id()));
+        let path = dir.join("nested").join("out.txt");
+        write_file(&path, "hello\n").unwrap();
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "hello\n");
+
+        let mut dialog = SaveDialog::new(SaveKind::Describe, path.display().to_string());
+        dialog.submit();
+        assert!(dialog.confirm_overwrite && !dialog.pending);
+        dialog.submit();
+        assert!(dialog.pending);
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
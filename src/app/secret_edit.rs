@@ -0,0 +1,247 @@
+//! Edit a Secret's decoded values as text; only changed keys are patched back
+
+use super::diff::{diff_stats, line_diff};
+use crate::kubectl::types::SecretDetail;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ChangeKind {
+    Added,
+    Changed,
+    Removed,
+}
+
+/// 一个键的修改；value 为解码后的新值，删除时为 None
+#[derive(Debug, Clone, PartialEq)]
+pub struct SecretChange {
+    pub key: String,
+    pub kind: ChangeKind,
+    pub value: Option<Vec<u8>>,
+    /// 新增与删除的行数，确认时代替值本身显示
+    pub lines: (usize, usize),
+}
+
+/// 编辑器中的文档：每个文本键一个 `key: |` 块，值按原样缩进两格；
+/// 块标记保存末尾换行：`|-` 无换行，`|` 一个，`|+` 多个
+pub fn edit_document(detail: &SecretDetail) -> String {
+    let mut doc = format!(
+        "# {}/{}: values are decoded text and re-encoded to base64 on save\n\
+         # Only changed keys are patched; delete a key's block to remove it\n",
+        detail.namespace, detail.name
+    );
+    let binary: Vec<&str> = detail
+        .entries
+        .iter()
+        .filter(|e| e.text().is_none())
+        .map(|e| e.key.as_str())
+        .collect();
+    if !binary.is_empty() {
+        doc.push_str(&format!(
+            "# Binary keys are not shown and stay unchanged: {}\n",
+            binary.join(", ")
+        ));
+    }
+    for entry in &detail.entries {
+        let Some(value) = entry.text() else {
+            continue;
+        };
+        let body = value.trim_end_matches('\n');
+        let (indicator, lines) = match value.len() - body.len() {
+            0 => ("|-", value),
+            1 if !body.is_empty() => ("|", body),
+            _ => ("|+", &value[..value.len() - 1]),
+        };
+        doc.push_str(&format!("{}: {}\n", entry.key, indicator));
+        if !lines.is_empty() || indicator == "|+" {
+            for line in lines.split('\n') {
+                if line.is_empty() {
+                    doc.push('\n');
+                } else {
+                    doc.push_str(&format!("  {}\n", line));
+                }
+            }
+        }
+    }
+    doc
+}
+
+fn valid_key(key: &str) -> bool {
+    !key.is_empty()
+        && key
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+}
+
+fn finish_block(indicator: &str, mut lines: Vec<String>) -> String {
+    if indicator == "|+" {
+        return lines.join("\n") + "\n";
+    }
+    while lines.last().is_some_and(|line| line.is_empty()) {
+        lines.pop();
+    }
+    match (indicator, lines.is_empty()) {
+        ("|", false) => lines.join("\n") + "\n",
+        _ => lines.join("\n"),
+    }
+}
+
+/// 解析编辑后的文档，按出现顺序返回键值；也接受单行的 `key: value`。
+/// 按 `\n` 切分而不是 `lines()`，值中的 `\r\n` 原样保留
+pub fn parse_document(text: &str) -> Result<Vec<(String, String)>, String> {
+    let mut values: Vec<(String, String)> = Vec::new();
+    // 正在读取的块：键、块标记、已读取的行
+    let mut block: Option<(String, String, Vec<String>)> = None;
+
+    let body = text.strip_suffix('\n').unwrap_or(text);
+    for (index, line) in body.split('\n').enumerate() {
+        if let Some((_, _, lines)) = block.as_mut() {
+            if let Some(content) = line.strip_prefix("  ") {
+                lines.push(content.to_string());
+                continue;
+            }
+            if line.trim().is_empty() {
+                lines.push(String::new());
+                continue;
+            }
+        }
+        if let Some((key, indicator, lines)) = block.take() {
+            values.push((key, finish_block(&indicator, lines)));
+        }
+        if line.trim().is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let line_no = index + 1;
+        let (key, rest) = line
+            .split_once(':')
+            .ok_or_else(|| format!("line {}: expected `key: |` or `key: value`", line_no))?;
+        if !valid_key(key) {
+            return Err(format!("line {}: invalid key {:?}", line_no, key));
+        }
+        if values.iter().any(|(k, _)| k == key) {
+            return Err(format!("line {}: duplicate key {}", line_no, key));
+        }
+        match rest.trim() {
+            indicator @ ("|" | "|-" | "|+") => {
+                block = Some((key.to_string(), indicator.to_string(), Vec::new()));
+            }
+            value => values.push((key.to_string(), value.to_string())),
+        }
+    }
+    if let Some((key, indicator, lines)) = block {
+        values.push((key, finish_block(&indicator, lines)));
+    }
+    Ok(values)
+}
+
+/// 与原 Secret 比较；文档中不出现的二进制键视为未修改
+pub fn secret_changes(detail: &SecretDetail, edited: &[(String, String)]) -> Vec<SecretChange> {
+    let mut changes = Vec::new();
+    for (key, value) in edited {
+        let original = detail.entries.iter().find(|e| &e.key == key);
+        let kind = match original {
+            Some(entry) if entry.value == value.as_bytes() => continue,
+            Some(_) => ChangeKind::Changed,
+            None => ChangeKind::Added,
+        };
+        let old = original.and_then(|e| e.text()).unwrap_or_default();
+        changes.push(SecretChange {
+            key: key.clone(),
+            kind,
+            value: Some(value.as_bytes().to_vec()),
+            lines: diff_stats(&line_diff(old, value)),
+        });
+    }
+    for entry in &detail.entries {
+        let Some(old) = entry.text() else {
+            continue;
+        };
+        if !edited.iter().any(|(key, _)| key == &entry.key) {
+            changes.push(SecretChange {
+                key: entry.key.clone(),
+                kind: ChangeKind::Removed,
+                value: None,
+                lines: (0, old.lines().count()),
+            });
+        }
+    }
+    changes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kubectl::types::SecretEntry;
+
+    fn detail(entries: &[(&str, &[u8])]) -> SecretDetail {
+        SecretDetail {
+            name: "db".to_string(),
+            namespace: "prod".to_string(),
+            type_: "Opaque".to_string(),
+            entries: entries
+                .iter()
+                .map(|(key, value)| SecretEntry {
+                    key: key.to_string(),
+                    value: value.to_vec(),
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_document_round_trip() {
+        let detail = detail(&[
+            ("empty", b""),
+            ("password", b"s3cr3t"),
+            ("config", b"a: 1\n\n  b: 2\n"),
+            ("blank", b"\n"),
+            ("trailing", b"x\n\n"),
+            ("crlf", b"a\r\nb"),
+            ("cert.der", &[0, 159, 146]),
+        ]);
+        let doc = edit_document(&detail);
+        assert!(doc.contains("Binary keys are not shown and stay unchanged: cert.der"));
+        assert!(doc.contains("password: |-\n  s3cr3t\n"));
+        assert!(doc.contains("config: |\n  a: 1\n\n    b: 2\n"));
+
+        let parsed = parse_document(&doc).unwrap();
+        let keys: Vec<&str> = parsed.iter().map(|(k, _)| k.as_str()).collect();
+        assert_eq!(
+            keys,
+            ["empty", "password", "config", "blank", "trailing", "crlf"]
+        );
+        assert_eq!(parsed[5].1, "a\r\nb");
+        assert!(secret_changes(&detail, &parsed).is_empty());
+    }
+
+    #[test]
+    fn test_secret_changes() {
+        let detail = detail(&[
+            ("password", b"old"),
+            ("user", b"admin"),
+            ("cert.der", &[0, 159]),
+        ]);
+        let edited = parse_document("password: |-\n  new\n\nuser: admin\ntoken: abc\n").unwrap();
+        let changes = secret_changes(&detail, &edited);
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes[0].key, "password");
+        assert_eq!(changes[0].kind, ChangeKind::Changed);
+        assert_eq!(changes[0].value.as_deref(), Some(&b"new"[..]));
+        assert_eq!(changes[0].lines, (1, 1));
+        assert_eq!(changes[1].key, "token");
+        assert_eq!(changes[1].kind, ChangeKind::Added);
+
+        let edited = parse_document("password: |-\n  old\n").unwrap();
+        let changes = secret_changes(&detail, &edited);
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].kind, ChangeKind::Removed);
+        assert_eq!(changes[0].key, "user");
+        assert_eq!(changes[0].value, None);
+    }
+
+    #[test]
+    fn test_parse_document_errors() {
+        assert!(parse_document("  orphan\n").is_err());
+        assert!(parse_document("bad key: x\n").is_err());
+        assert!(parse_document("a: 1\na: 2\n").is_err());
+    }
+}
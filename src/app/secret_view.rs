@@ -0,0 +1,371 @@
+//! Secret detail view: decoded values, masked until revealed per key
+
+use std::collections::HashSet;
+
+use base64::{Engine, engine::general_purpose::STANDARD};
+use chrono::{DateTime, Utc};
+
+use super::detail_lifecycle::{DetailLifecycle, EditableView};
+use super::secret_edit::{SecretChange, edit_document, parse_document, secret_changes};
+use crate::kubectl::types::{Secret, SecretDetail, SecretEntry, TLS_SECRET_TYPE};
+use crate::kubectl::x509::{
+    Certificate, EXPIRY_CRITICAL_DAYS, EXPIRY_WARNING_DAYS, parse_pem_chain,
+};
+
+/// 未显示的值统一用固定长度的掩码，不泄露值的长度
+pub const MASK: &str = "••••••••";
+
+/// Secret 详情视图：整个 Secret 以解码后的文档编辑
+#[derive(Debug, Clone)]
+pub struct SecretView {
+    pub namespace: String,
+    pub name: String,
+    /// 待确认的修改为逐键的变更
+    pub lifecycle: DetailLifecycle<SecretDetail, (), Vec<SecretChange>>,
+    pub selected: usize,
+    /// 已显示明文的键
+    pub revealed: HashSet<String>,
+    /// 上次复制/显示操作的结果
+    pub message: Option<Result<String, String>>,
+    /// kubernetes.io/tls 类型时 tls.crt 中的证书链
+    pub certificates: Option<Result<Vec<Certificate>, String>>,
+    /// 无法解析的编辑内容，再次编辑时从这里继续
+    pub draft: Option<String>,
+}
+
+impl SecretView {
+    pub fn new(namespace: &str, name: &str) -> Self {
+        Self {
+            namespace: namespace.to_string(),
+            name: name.to_string(),
+            lifecycle: DetailLifecycle::default(),
+            selected: 0,
+            revealed: HashSet::new(),
+            message: None,
+            certificates: None,
+            draft: None,
+        }
+    }
+
+    pub fn entries(&self) -> &[SecretEntry] {
+        self.lifecycle
+            .detail
+            .as_ref()
+            .map_or(&[], |d| d.entries.as_slice())
+    }
+
+    pub fn selected_entry(&self) -> Option<&SecretEntry> {
+        self.entries().get(self.selected)
+    }
+
+    pub fn select_next(&mut self) {
+        if self.selected + 1 < self.entries().len() {
+            self.selected += 1;
+        }
+    }
+
+    pub fn select_previous(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    pub fn is_revealed(&self, key: &str) -> bool {
+        self.revealed.contains(key)
+    }
+
+    /// 切换选中键的显示状态，返回切换后是否显示
+    pub fn toggle_reveal(&mut self) -> Option<bool> {
+        let key = self.selected_entry()?.key.clone();
+        if self.revealed.remove(&key) {
+            Some(false)
+        } else {
+            self.revealed.insert(key);
+            Some(true)
+        }
+    }
+
+    /// 审计记录中的目标：`namespace/secret:key`
+    pub fn audit_target(&self, key: &str) -> String {
+        format!("{}/{}:{}", self.namespace, self.name, key)
+    }
+
+    /// 请求在编辑器中修改；需先加载完成
+    pub fn request_edit(&mut self) -> Result<(), String> {
+        if self.lifecycle.detail.is_none() {
+            return Err("Secret not loaded".to_string());
+        }
+        self.lifecycle.pending_edit = Some(());
+        Ok(())
+    }
+
+    /// 交给编辑器的文本：上次未能解析的草稿，否则为解码后的文档
+    pub fn edit_text(&self) -> String {
+        match (&self.draft, &self.lifecycle.detail) {
+            (Some(draft), _) => draft.clone(),
+            (None, Some(detail)) => edit_document(detail),
+            (None, None) => String::new(),
+        }
+    }
+
+    /// 编辑器返回后解析并与原值比较；解析失败时保留草稿
+    pub fn set_edited(&mut self, edited: anyhow::Result<String>) {
+        let Some(detail) = &self.lifecycle.detail else {
+            return;
+        };
+        let text = match edited {
+            Ok(text) => text,
+            Err(e) => {
+                self.message = Some(Err(e.to_string()));
+                return;
+            }
+        };
+        match parse_document(&text) {
+            Ok(values) => {
+                self.draft = None;
+                let changes = secret_changes(detail, &values);
+                if changes.is_empty() {
+                    self.message = Some(Ok("No changes".to_string()));
+                } else {
+                    self.lifecycle.set_edit(changes);
+                    self.message = None;
+                }
+            }
+            Err(e) => {
+                self.draft = Some(text);
+                self.message = Some(Err(format!("{} (press e to fix)", e)));
+            }
+        }
+    }
+
+    pub fn discard_edit(&mut self) {
+        if self.lifecycle.discard_edit().is_some() {
+            self.message = Some(Ok("Edit discarded".to_string()));
+        }
+    }
+}
+
+impl EditableView for SecretView {
+    type Detail = SecretDetail;
+
+    fn load_pending(&self) -> bool {
+        self.lifecycle.pending
+    }
+
+    fn set_result(&mut self, result: anyhow::Result<SecretDetail>) {
+        let Some(detail) = self.lifecycle.set_result(result) else {
+            return;
+        };
+        self.selected = self.selected.min(detail.entries.len().saturating_sub(1));
+        // 已删除的键不再保持显示
+        self.revealed
+            .retain(|key| detail.entries.iter().any(|e| &e.key == key));
+        self.certificates = (detail.type_ == TLS_SECRET_TYPE).then(|| {
+            let pem = detail
+                .entries
+                .iter()
+                .find(|e| e.key == "tls.crt")
+                .and_then(|e| e.text())
+                .ok_or_else(|| "Missing tls.crt".to_string())?;
+            parse_pem_chain(pem).map_err(|e| e.to_string())
+        });
+    }
+
+    fn set_patch_result(&mut self, result: anyhow::Result<String>) {
+        self.message = Some(self.lifecycle.finish_patch(result).map(|changes| {
+            let count = changes.map_or(0, |changes| changes.len());
+            format!("Patched {} key(s)", count)
+        }));
+    }
+}
+
+/// 命名空间内 kubernetes.io/tls 证书的到期概览
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ExpirySummary {
+    pub total: usize,
+    pub expired: usize,
+    /// 剩余不超过 EXPIRY_CRITICAL_DAYS 天
+    pub critical: usize,
+    /// 剩余不超过 EXPIRY_WARNING_DAYS 天
+    pub warning: usize,
+    /// tls.crt 缺失或无法解析
+    pub invalid: usize,
+    /// 最早过期的 Secret 及剩余天数
+    pub soonest: Option<(String, i64)>,
+}
+
+impl ExpirySummary {
+    pub fn of(secrets: &[Secret], now: DateTime<Utc>) -> Self {
+        let mut summary = Self::default();
+        for secret in secrets {
+            let Some(tls) = &secret.tls else {
+                continue;
+            };
+            summary.total += 1;
+            let Ok(certificate) = tls else {
+                summary.invalid += 1;
+                continue;
+            };
+            let days = certificate.days_to_expiry(now);
+            if certificate.not_after <= now {
+                summary.expired += 1;
+            } else if days <= EXPIRY_CRITICAL_DAYS {
+                summary.critical += 1;
+            } else if days <= EXPIRY_WARNING_DAYS {
+                summary.warning += 1;
+            }
+            if summary
+                .soonest
+                .as_ref()
+                .is_none_or(|(_, soonest)| days < *soonest)
+            {
+                summary.soonest = Some((secret.name.clone(), days));
+            }
+        }
+        summary
+    }
+}
+
+/// 显示与复制用的文本：文本值原样，二进制值为 base64
+pub fn display_value(entry: &SecretEntry) -> String {
+    match entry.text() {
+        Some(text) => text.to_string(),
+        None => STANDARD.encode(&entry.value),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn detail(keys: &[&str]) -> SecretDetail {
+        SecretDetail {
+            name: "db".to_string(),
+            namespace: "prod".to_string(),
+            type_: "Opaque".to_string(),
+            entries: keys
+                .iter()
+                .map(|key| SecretEntry {
+                    key: key.to_string(),
+                    value: b"value".to_vec(),
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_reveal_is_per_key_and_survives_reload() {
+        let mut view = SecretView::new("prod", "db");
+        assert_eq!(view.toggle_reveal(), None);
+        view.set_result(Ok(detail(&["password", "user"])));
+
+        assert_eq!(view.toggle_reveal(), Some(true));
+        view.select_next();
+        assert!(view.is_revealed("password"));
+        assert!(!view.is_revealed("user"));
+        assert_eq!(view.audit_target("user"), "prod/db:user");
+
+        view.set_result(Ok(detail(&["user"])));
+        assert!(view.revealed.is_empty());
+        assert_eq!(view.selected, 0);
+        assert_eq!(view.toggle_reveal(), Some(true));
+        assert_eq!(view.toggle_reveal(), Some(false));
+    }
+
+    #[test]
+    fn test_expiry_summary() {
+        use chrono::{Duration, TimeZone};
+        let now = Utc.with_ymd_and_hms(2026, 10, 1, 0, 0, 0).unwrap();
+        let secret = |name: &str, tls: Option<Result<i64, &str>>| Secret {
+            name: name.to_string(),
+            namespace: "prod".to_string(),
+            type_: TLS_SECRET_TYPE.to_string(),
+            data_count: 2,
+            age: "1d".to_string(),
+            tls: tls.map(|tls| {
+                tls.map(|days| Certificate {
+                    subject: format!("CN={}", name),
+                    issuer: "CN=ca".to_string(),
+                    sans: Vec::new(),
+                    not_before: now - Duration::days(365),
+                    not_after: now + Duration::days(days),
+                })
+                .map_err(str::to_string)
+            }),
+        };
+        let secrets = vec![
+            secret("opaque", None),
+            secret("old", Some(Ok(-2))),
+            secret("soon", Some(Ok(3))),
+            secret("month", Some(Ok(20))),
+            secret("fine", Some(Ok(200))),
+            secret("broken", Some(Err("Missing tls.crt"))),
+        ];
+
+        let summary = ExpirySummary::of(&secrets, now);
+        assert_eq!(summary.total, 5);
+        assert_eq!(summary.expired, 1);
+        assert_eq!(summary.critical, 1);
+        assert_eq!(summary.warning, 1);
+        assert_eq!(summary.invalid, 1);
+        assert_eq!(summary.soonest, Some(("old".to_string(), -2)));
+        assert_eq!(
+            ExpirySummary::of(&secrets[..1], now),
+            ExpirySummary::default()
+        );
+    }
+
+    #[test]
+    fn test_tls_secret_parses_chain() {
+        let mut view = SecretView::new("prod", "web-tls");
+        let mut tls = detail(&["tls.crt", "tls.key"]);
+        tls.type_ = TLS_SECRET_TYPE.to_string();
+        view.set_result(Ok(tls.clone()));
+        // 值不是 PEM 证书
+        assert!(matches!(view.certificates, Some(Err(_))));
+
+        tls.entries.retain(|e| e.key == "tls.key");
+        view.set_result(Ok(tls));
+        assert_eq!(view.certificates, Some(Err("Missing tls.crt".to_string())));
+
+        view.set_result(Ok(detail(&["password"])));
+        assert!(view.certificates.is_none());
+    }
+
+    #[test]
+    fn test_edit_keeps_draft_until_parsed() {
+        let mut view = SecretView::new("prod", "db");
+        assert!(view.request_edit().is_err());
+        view.set_result(Ok(detail(&["password"])));
+        view.request_edit().unwrap();
+        assert!(view.lifecycle.pending_edit.is_some());
+        assert!(view.edit_text().contains("password: |-\n  value\n"));
+
+        view.set_edited(Ok("  broken\n".to_string()));
+        assert!(view.lifecycle.edit.is_none());
+        assert_eq!(view.edit_text(), "  broken\n");
+
+        view.set_edited(Ok("password: |-\n  changed\n".to_string()));
+        assert!(view.draft.is_none());
+        assert_eq!(view.lifecycle.edit.as_ref().unwrap().len(), 1);
+
+        view.lifecycle.confirm_edit();
+        view.set_patch_result(Err(anyhow::anyhow!("forbidden")));
+        assert!(view.lifecycle.edit.is_some());
+        view.set_patch_result(Ok("patched".to_string()));
+        assert!(view.lifecycle.edit.is_none());
+        assert!(view.lifecycle.pending);
+    }
+
+    #[test]
+    fn test_display_value() {
+        let binary = SecretEntry {
+            key: "der".to_string(),
+            value: vec![0, 1, 255],
+        };
+        assert_eq!(display_value(&binary), "AAH/");
+        let text = SecretEntry {
+            key: "pw".to_string(),
+            value: b"line1\nline2".to_vec(),
+        };
+        assert_eq!(display_value(&text), "line1\nline2");
+    }
+}
@@ -3,6 +3,29 @@ use std::time::{Duration, Instant};
 
 use anyhow::Result;
 use crossterm::event::MouseEvent;
+use regex::Regex;
+
+use super::ansi::strip_ansi;
+use super::audit::AuditLog;
+use super::config::UserConfig;
+use super::configmap_view::ConfigMapView;
+use super::content_search::ContentSearch;
+use super::describe_view::DescribeOutline;
+use super::file_browser::FileBrowser;
+use super::log_capture::{LogCaptureManager, session_dir};
+use super::log_search::{LogViewLine, build_pattern, grep_view};
+use super::log_stream::LogStream;
+use super::metrics_history::MetricsHistory;
+use super::multi_log::MultiLogView;
+use super::node_detail::NodeDetailView;
+use super::node_drain::{DrainDialog, NodeAction, NodeDrain, is_valid_duration};
+use super::save::{SaveDialog, SaveKind, default_file_name};
+use super::secret_view::SecretView;
+use super::structured_log::{LogLevel, ParsedLine};
+use super::terminal::TerminalSession;
+use super::yaml_neat::neat_lines;
+use super::yaml_tree::YamlTree;
+use crate::kubectl::types::{LogOptions, NodeMetrics, PodMetrics};
 
 #[derive(Debug, Clone)]
 #[allow(dead_code)]
@@ -50,6 +73,135 @@ pub enum AppMode {
     YamlView,
     TopView,
     CommandHistory,
+    Terminal,
+    FileBrowser,
+    MultiLogs,
+    LogCaptures,
+    NodeDrain,
+    NodeDetail,
+    SecretDetail,
+    ConfigMapDetail,
+}
+
+/// 等待主循环在伪终端中启动的 exec/attach 会话
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExecRequest {
+    pub title: String,
+    pub command: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ExecPickerStep {
+    Container,
+    Command,
+    CustomCommand,
+}
+
+/// exec 弹窗状态：先选容器（多容器时），再选 shell 回退链或自定义命令
+#[derive(Debug, Clone)]
+pub struct ExecPicker {
+    pub pod_name: String,
+    pub workload: String,
+    pub containers: Vec<String>,
+    pub container: Option<String>,
+    pub step: ExecPickerStep,
+    pub selected: usize,
+    pub command_input: String,
+}
+
+/// 日志选项弹窗中的行
+pub const LOG_OPTION_ROWS: usize = 5;
+
+/// 日志选项弹窗：编辑 log_options 的副本，Enter 校验通过后才生效
+#[derive(Debug, Clone)]
+pub struct LogOptionsDialog {
+    pub containers: Vec<String>,
+    pub options: LogOptions,
+    pub selected: usize,
+    pub tail_input: String,
+    pub error: Option<String>,
+}
+
+impl LogOptionsDialog {
+    pub fn new(containers: Vec<String>, options: &LogOptions) -> Self {
+        Self {
+            containers,
+            tail_input: options.tail.to_string(),
+            options: options.clone(),
+            selected: 0,
+            error: None,
+        }
+    }
+
+    /// 容器行依次切换：默认容器 → 各个容器 → 全部容器
+    pub fn cycle_container(&mut self, forward: bool) {
+        let count = self.containers.len() + 2;
+        let current = if self.options.all_containers {
+            count - 1
+        } else {
+            self.options
+                .container
+                .as_ref()
+                .and_then(|c| self.containers.iter().position(|n| n == c))
+                .map(|i| i + 1)
+                .unwrap_or(0)
+        };
+        let next = if forward {
+            (current + 1) % count
+        } else {
+            (current + count - 1) % count
+        };
+        self.options.all_containers = next == count - 1;
+        self.options.container = match next {
+            0 => None,
+            i if i == count - 1 => None,
+            i => self.containers.get(i - 1).cloned(),
+        };
+    }
+
+    /// 校验输入并得到最终选项
+    pub fn validated(&self) -> std::result::Result<LogOptions, String> {
+        let mut options = self.options.clone();
+        options.since = options.since.trim().to_string();
+        if !options.since.is_empty() && !is_valid_since(&options.since) {
+            return Err(format!(
+                "Invalid since '{}': use a duration like 10m / 1h30m or an RFC3339 time",
+                options.since
+            ));
+        }
+        options.tail = self
+            .tail_input
+            .trim()
+            .parse()
+            .map_err(|_| format!("Invalid tail '{}': expected a number", self.tail_input))?;
+        Ok(options)
+    }
+}
+
+/// `--since` 接受的时长（如 `30s`、`1h30m`）或 `--since-time` 接受的 RFC3339 时间
+pub fn is_valid_since(value: &str) -> bool {
+    chrono::DateTime::parse_from_rfc3339(value).is_ok() || is_valid_duration(value)
+}
+
+/// 跟随模式下日志缓冲的最大行数
+pub const LOG_BUFFER_LINES: usize = 5000;
+
+/// 节点指标表的排序方式，s 键循环切换
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NodeMetricsSort {
+    Name,
+    Cpu,
+    Memory,
+}
+
+impl NodeMetricsSort {
+    pub fn next(self) -> Self {
+        match self {
+            NodeMetricsSort::Name => NodeMetricsSort::Cpu,
+            NodeMetricsSort::Cpu => NodeMetricsSort::Memory,
+            NodeMetricsSort::Memory => NodeMetricsSort::Name,
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -88,8 +240,12 @@ pub struct AppState {
     pub configmaps: Vec<crate::kubectl::types::ConfigMap>,
     pub secrets: Vec<crate::kubectl::types::Secret>,
     pub logs: Vec<String>,
+    /// 与 logs 逐行对应的解析结果，只通过 set_logs / append_logs / clear_logs 维护
+    pub parsed_logs: Vec<ParsedLine>,
     pub describe_content: String,
     pub describe_lines_cache: Vec<String>,
+    /// Describe 输出的段落与折叠状态
+    pub describe_outline: DescribeOutline,
     pub last_update: Instant,
     pub auto_refresh: bool,
     pub refresh_interval: Duration,
@@ -112,15 +268,31 @@ pub struct AppState {
     pub last_describe_refresh: Instant,
     pub yaml_auto_refresh: bool,
     pub last_yaml_refresh: Instant,
-    pub pending_exec: Option<String>,
+    pub pending_exec: Option<ExecRequest>,
     pub yaml_content: String,
     pub yaml_lines_cache: Vec<String>,
+    /// YAML 视图中光标所在的可见行
     pub yaml_scroll: usize,
+    /// YAML 的结构与折叠状态
+    pub yaml_tree: YamlTree,
+    /// 精简模式：YAML 视图隐藏 managedFields、status 等服务端字段
+    pub yaml_neat: bool,
+    /// YAML / Describe 视图内的搜索
+    pub content_search: ContentSearch,
     pub mouse_capture_enabled: bool,
     pub text_selection_mode: bool,
     pub language_chinese: bool,
     pub pod_metrics: Vec<crate::kubectl::types::PodMetrics>,
     pub metrics_scroll: usize,
+    // 每次刷新追加的 Pod/容器指标样本，用于趋势图
+    pub metrics_history: MetricsHistory,
+    // 从节点列表进入 TopView 时显示 kubectl top nodes
+    pub top_nodes: bool,
+    pub node_metrics: Vec<crate::kubectl::types::NodeMetrics>,
+    pub node_metrics_sort: NodeMetricsSort,
+    pub node_metrics_error: Option<String>,
+    // 进入 TopView 后等待主循环首次拉取指标
+    pub metrics_pending: bool,
 
     // New fields for added features
     #[allow(dead_code)]
@@ -141,18 +313,69 @@ pub struct AppState {
     pub marked_items: HashSet<usize>,
     #[allow(dead_code)]
     pub exec_returning: bool,
-    #[allow(dead_code)]
     pub log_search_query: String,
-    #[allow(dead_code)]
+    // 匹配行在 logs 中的下标
     pub log_search_results: Vec<usize>,
-    #[allow(dead_code)]
     pub current_log_search_index: usize,
-    #[allow(dead_code)]
+    // true 时正在输入搜索内容
     pub log_search_mode: bool,
-    #[allow(dead_code)]
+    pub log_search_regex: bool,
+    pub log_search_case_sensitive: bool,
+    pub log_search_pattern: Option<Regex>,
+    pub log_search_error: Option<String>,
+    // grep 模式：只显示匹配行及上下文
+    pub log_grep_mode: bool,
+    pub log_grep_context: usize,
+    // 把 JSON/logfmt 行渲染为 `time level message key=value`
+    pub log_structured: bool,
+    // 按 ANSI 颜色序列着色；关闭时去掉转义序列显示纯文本
+    pub log_ansi: bool,
+    // 只显示不低于该级别的行
+    pub log_level_filter: Option<LogLevel>,
+    // 展开查看的日志行及弹窗滚动位置
+    pub log_expanded: Option<usize>,
+    pub log_expanded_scroll: usize,
+    // true 时日志由 kubectl logs -f 持续追加，而不是定时拉取快照
     pub streaming_logs: bool,
     #[allow(dead_code)]
     pub command_history: Vec<String>,
+    pub user_config: UserConfig,
+    pub exec_picker: Option<ExecPicker>,
+    pub terminal_sessions: Vec<TerminalSession>,
+    pub active_terminal: usize,
+    // true 时按键直接发送给 shell，Ctrl+] 交还给 TUI
+    pub terminal_focus: bool,
+    pub terminal_return_mode: AppMode,
+    pub file_browser: Option<FileBrowser>,
+    pub log_stream: Option<LogStream>,
+    // 暂停时停止自动滚动，但继续缓冲新日志
+    pub logs_paused: bool,
+    pub logs_new_while_paused: usize,
+    // 会话内保持的日志来源选项
+    pub log_options: LogOptions,
+    pub log_options_dialog: Option<LogOptionsDialog>,
+    pub multi_log: Option<MultiLogView>,
+    // Some 时正在输入聚合日志的标签选择器
+    pub multi_log_selector_input: Option<String>,
+    // 日志/详情/YAML 保存到文件的弹窗
+    pub save_dialog: Option<SaveDialog>,
+    // 后台日志捕获，切换视图后继续写入文件
+    pub log_captures: LogCaptureManager,
+    // 节点 cordon/uncordon/drain：等待执行的操作、上次结果、排空选项弹窗和进行中的排空
+    pub pending_node_action: Option<NodeAction>,
+    pub node_action_result: Option<std::result::Result<String, String>>,
+    pub drain_dialog: Option<DrainDialog>,
+    pub node_drain: Option<NodeDrain>,
+    // 节点容量/分配视图
+    pub node_detail: Option<NodeDetailView>,
+    // 从节点详情跳转后，Pod 列表加载完成时选中该 Pod
+    pub pending_pod_focus: Option<String>,
+    // Secret 详情：解码后的值，按键显示/隐藏
+    pub secret_view: Option<SecretView>,
+    // 显示和复制 Secret 值等敏感操作的审计记录
+    pub audit_log: AuditLog,
+    // ConfigMap 详情：按键查看和编辑
+    pub configmap_view: Option<ConfigMapView>,
 }
 
 impl Default for AppState {
@@ -184,8 +407,10 @@ impl Default for AppState {
             configmaps: Vec::new(),
             secrets: Vec::new(),
             logs: Vec::new(),
+            parsed_logs: Vec::new(),
             describe_content: String::new(),
             describe_lines_cache: Vec::new(),
+            describe_outline: DescribeOutline::default(),
             last_update: Instant::now(),
             auto_refresh: true,
             refresh_interval: Duration::from_secs(5),
@@ -212,11 +437,20 @@ impl Default for AppState {
             yaml_content: String::new(),
             yaml_lines_cache: Vec::new(),
             yaml_scroll: 0,
+            yaml_tree: YamlTree::default(),
+            yaml_neat: false,
+            content_search: ContentSearch::default(),
             mouse_capture_enabled: false,
             text_selection_mode: false,
             language_chinese: true,
             pod_metrics: Vec::new(),
             metrics_scroll: 0,
+            metrics_history: MetricsHistory::default(),
+            top_nodes: false,
+            node_metrics: Vec::new(),
+            node_metrics_sort: NodeMetricsSort::Name,
+            node_metrics_error: None,
+            metrics_pending: false,
 
             // Initialize new fields
             favorite_namespaces: Vec::new(),
@@ -235,8 +469,44 @@ impl Default for AppState {
             log_search_results: Vec::new(),
             current_log_search_index: 0,
             log_search_mode: false,
-            streaming_logs: false,
+            log_search_regex: false,
+            log_search_case_sensitive: false,
+            log_search_pattern: None,
+            log_search_error: None,
+            log_grep_mode: false,
+            log_grep_context: 2,
+            log_structured: true,
+            log_ansi: true,
+            log_level_filter: None,
+            log_expanded: None,
+            log_expanded_scroll: 0,
+            streaming_logs: true,
             command_history: Vec::new(),
+            user_config: UserConfig::default(),
+            exec_picker: None,
+            terminal_sessions: Vec::new(),
+            active_terminal: 0,
+            terminal_focus: false,
+            terminal_return_mode: AppMode::PodList,
+            file_browser: None,
+            log_stream: None,
+            logs_paused: false,
+            logs_new_while_paused: 0,
+            log_options: LogOptions::default(),
+            log_options_dialog: None,
+            multi_log: None,
+            multi_log_selector_input: None,
+            save_dialog: None,
+            log_captures: LogCaptureManager::new(session_dir(chrono::Local::now())),
+            pending_node_action: None,
+            node_action_result: None,
+            drain_dialog: None,
+            node_drain: None,
+            node_detail: None,
+            pending_pod_focus: None,
+            secret_view: None,
+            audit_log: AuditLog::default(),
+            configmap_view: None,
         }
     }
 }
@@ -258,6 +528,7 @@ impl AppState {
     pub fn should_refresh_logs(&self) -> bool {
         self.global_refresh_enabled
             && self.logs_auto_refresh
+            && !self.streaming_logs
             && self.mode == AppMode::Logs
             && self.last_logs_refresh.elapsed() >= self.logs_refresh_interval
     }
@@ -382,6 +653,268 @@ impl AppState {
         }
     }
 
+    /// 追加跟随模式收到的日志，超出 LOG_BUFFER_LINES 时丢弃最旧的行
+    pub fn append_logs(&mut self, lines: Vec<String>) {
+        if lines.is_empty() {
+            return;
+        }
+        let added = lines.len();
+        self.parsed_logs
+            .extend(lines.iter().map(|line| ParsedLine::new(line)));
+        self.logs.extend(lines);
+        let overflow = self.logs.len().saturating_sub(LOG_BUFFER_LINES);
+        if overflow > 0 {
+            self.logs.drain(..overflow);
+            self.parsed_logs.drain(..overflow);
+        }
+        if self.logs_paused {
+            self.logs_new_while_paused += added;
+        }
+        self.refresh_log_search();
+        if self.logs_auto_scroll && !self.logs_paused {
+            self.logs_scroll = self.log_view_len().saturating_sub(1);
+        } else {
+            // 保持视图停留在同一段内容上
+            self.logs_scroll = self.logs_scroll.saturating_sub(overflow);
+        }
+    }
+
+    pub fn toggle_logs_pause(&mut self) {
+        self.logs_paused = !self.logs_paused;
+        if !self.logs_paused {
+            self.logs_new_while_paused = 0;
+            if self.logs_auto_scroll {
+                self.logs_scroll = self.log_view_len().saturating_sub(1);
+            }
+        }
+    }
+
+    pub fn toggle_log_streaming(&mut self) {
+        self.streaming_logs = !self.streaming_logs;
+        self.logs_paused = false;
+        self.logs_new_while_paused = 0;
+        // 切回快照模式时立即拉取一次
+        self.last_logs_refresh = Instant::now() - self.logs_refresh_interval;
+    }
+
+    /// 用快照替换日志内容
+    pub fn set_logs(&mut self, logs: Vec<String>) {
+        self.parsed_logs = logs.iter().map(|line| ParsedLine::new(line)).collect();
+        self.logs = logs;
+        self.refresh_log_search();
+        if self.logs_auto_scroll {
+            self.logs_scroll = self.log_view_len().saturating_sub(1);
+        }
+    }
+
+    pub fn clear_logs(&mut self) {
+        self.logs.clear();
+        self.parsed_logs.clear();
+    }
+
+    /// 根据查询和开关重新编译搜索模式
+    pub fn update_log_search_pattern(&mut self) {
+        match build_pattern(
+            &self.log_search_query,
+            self.log_search_regex,
+            self.log_search_case_sensitive,
+        ) {
+            Ok(pattern) => {
+                self.log_search_pattern = pattern;
+                self.log_search_error = None;
+            }
+            Err(error) => {
+                self.log_search_pattern = None;
+                self.log_search_error = Some(error);
+            }
+        }
+        self.refresh_log_search();
+    }
+
+    /// 日志内容变化后重新计算匹配行
+    pub fn refresh_log_search(&mut self) {
+        self.log_search_results = match &self.log_search_pattern {
+            Some(pattern) => self
+                .logs
+                .iter()
+                .enumerate()
+                .filter(|(i, line)| {
+                    pattern.is_match(&strip_ansi(line)) && self.log_level_passes(*i)
+                })
+                .map(|(i, _)| i)
+                .collect(),
+            None => Vec::new(),
+        };
+        self.current_log_search_index = self
+            .current_log_search_index
+            .min(self.log_search_results.len().saturating_sub(1));
+    }
+
+    pub fn clear_log_search(&mut self) {
+        self.log_search_mode = false;
+        self.log_search_query.clear();
+        self.log_search_pattern = None;
+        self.log_search_error = None;
+        self.log_search_results.clear();
+        self.current_log_search_index = 0;
+        self.log_grep_mode = false;
+    }
+
+    /// 级别过滤：未识别出级别的行在过滤时隐藏
+    pub fn log_level_passes(&self, index: usize) -> bool {
+        match self.log_level_filter {
+            None => true,
+            Some(filter) => self
+                .parsed_logs
+                .get(index)
+                .and_then(|parsed| parsed.level)
+                .is_some_and(|level| level <= filter),
+        }
+    }
+
+    fn log_view_filtered(&self) -> bool {
+        self.log_level_filter.is_some() || (self.log_grep_mode && self.log_search_pattern.is_some())
+    }
+
+    /// 主日志窗格实际显示的行（级别过滤、grep 模式下为过滤后的行）
+    pub fn log_view(&self) -> Vec<LogViewLine> {
+        if !self.log_view_filtered() {
+            return (0..self.logs.len()).map(LogViewLine::Line).collect();
+        }
+        let visible: Vec<usize> = (0..self.logs.len())
+            .filter(|&i| self.log_level_passes(i))
+            .collect();
+        if !(self.log_grep_mode && self.log_search_pattern.is_some()) {
+            return visible.into_iter().map(LogViewLine::Line).collect();
+        }
+        // 在可见行中做 grep，上下文也只取可见行
+        let matches: Vec<usize> = visible
+            .iter()
+            .enumerate()
+            .filter(|(_, i)| self.log_search_results.binary_search(i).is_ok())
+            .map(|(position, _)| position)
+            .collect();
+        grep_view(visible.len(), &matches, self.log_grep_context)
+            .into_iter()
+            .map(|line| match line {
+                LogViewLine::Line(position) => LogViewLine::Line(visible[position]),
+                LogViewLine::Separator => LogViewLine::Separator,
+            })
+            .collect()
+    }
+
+    pub fn log_view_len(&self) -> usize {
+        if self.log_view_filtered() {
+            self.log_view().len()
+        } else {
+            self.logs.len()
+        }
+    }
+
+    pub fn cycle_log_level_filter(&mut self) {
+        self.log_level_filter = LogLevel::next_filter(self.log_level_filter);
+        self.refresh_log_search();
+        self.logs_scroll = self.log_view_len().saturating_sub(1);
+    }
+
+    /// 展开当前视图顶部的日志行
+    pub fn toggle_log_expanded(&mut self) {
+        if self.log_expanded.take().is_some() {
+            return;
+        }
+        let view = self.log_view();
+        self.log_expanded = view
+            .get(self.logs_scroll.min(view.len().saturating_sub(1)))
+            .and_then(|line| match line {
+                LogViewLine::Line(i) => Some(*i),
+                LogViewLine::Separator => None,
+            });
+        self.log_expanded_scroll = 0;
+    }
+
+    /// 选中当前滚动位置之后的第一个匹配（增量搜索）
+    pub fn select_nearest_log_match(&mut self) {
+        let view = self.log_view();
+        let anchor = view[self.logs_scroll.min(view.len().saturating_sub(1))..]
+            .iter()
+            .find_map(|line| match line {
+                LogViewLine::Line(i) => Some(*i),
+                LogViewLine::Separator => None,
+            })
+            .unwrap_or(0);
+        self.current_log_search_index = self
+            .log_search_results
+            .iter()
+            .position(|&line| line >= anchor)
+            .unwrap_or(0);
+        self.jump_to_log_match();
+    }
+
+    /// 滚动到当前匹配行，并关闭自动滚动以免新日志把视图拉走
+    pub fn jump_to_log_match(&mut self) {
+        let Some(&line) = self.log_search_results.get(self.current_log_search_index) else {
+            return;
+        };
+        if let Some(position) = self
+            .log_view()
+            .iter()
+            .position(|l| *l == LogViewLine::Line(line))
+        {
+            self.logs_scroll = position;
+            self.logs_auto_scroll = false;
+        }
+    }
+
+    pub fn log_search_next(&mut self) {
+        if !self.log_search_results.is_empty() {
+            self.current_log_search_index =
+                (self.current_log_search_index + 1) % self.log_search_results.len();
+            self.jump_to_log_match();
+        }
+    }
+
+    pub fn log_search_previous(&mut self) {
+        if !self.log_search_results.is_empty() {
+            let len = self.log_search_results.len();
+            self.current_log_search_index = (self.current_log_search_index + len - 1) % len;
+            self.jump_to_log_match();
+        }
+    }
+
+    pub fn toggle_log_grep_mode(&mut self) {
+        if self.log_search_pattern.is_none() {
+            return;
+        }
+        self.log_grep_mode = !self.log_grep_mode;
+        self.logs_scroll = 0;
+        self.jump_to_log_match();
+    }
+
+    pub fn adjust_log_grep_context(&mut self, increase: bool) {
+        self.log_grep_context = if increase {
+            (self.log_grep_context + 1).min(20)
+        } else {
+            self.log_grep_context.saturating_sub(1)
+        };
+        if self.log_grep_mode {
+            self.jump_to_log_match();
+        }
+    }
+
+    /// 应用新的日志选项：清空当前日志，跟随流会在主循环中按新选项重启
+    pub fn apply_log_options(&mut self, options: LogOptions) {
+        if options == self.log_options {
+            return;
+        }
+        self.log_options = options;
+        self.clear_logs();
+        self.refresh_log_search();
+        self.logs_scroll = 0;
+        self.logs_paused = false;
+        self.logs_new_while_paused = 0;
+        self.last_logs_refresh = Instant::now() - self.logs_refresh_interval;
+    }
+
     pub fn refresh_logs(&mut self) {
         self.last_logs_refresh = Instant::now();
     }
@@ -443,6 +976,16 @@ impl AppState {
         self.nodes.get(self.selected_node_index)
     }
 
+    /// 设置 Pod 列表，并选中等待聚焦的 Pod
+    pub fn set_pods(&mut self, pods: Vec<crate::kubectl::types::Pod>) {
+        self.pods = pods;
+        if let Some(name) = self.pending_pod_focus.take()
+            && let Some(index) = self.pods.iter().position(|pod| pod.name == name)
+        {
+            self.selected_pod_index = index;
+        }
+    }
+
     pub fn get_selected_configmap(&self) -> Option<&crate::kubectl::types::ConfigMap> {
         self.configmaps.get(self.selected_configmap_index)
     }
@@ -459,6 +1002,63 @@ impl AppState {
         self.pvs.get(self.selected_pv_index)
     }
 
+    /// Describe / YAML 视图对应的资源名（来自上一级列表的选中项）
+    pub fn detail_resource_name(&self) -> Option<String> {
+        match self.previous_mode {
+            AppMode::PodList => self.get_selected_pod().map(|p| p.name.clone()),
+            AppMode::ServiceList => self.get_selected_service().map(|s| s.name.clone()),
+            AppMode::DeploymentList => self.get_selected_deployment().map(|d| d.name.clone()),
+            AppMode::JobList => self.get_selected_job().map(|j| j.name.clone()),
+            AppMode::DaemonSetList => self.get_selected_daemonset().map(|d| d.name.clone()),
+            AppMode::NodeList => self.get_selected_node().map(|n| n.name.clone()),
+            AppMode::ConfigMapList => self.get_selected_configmap().map(|c| c.name.clone()),
+            AppMode::SecretList => self.get_selected_secret().map(|s| s.name.clone()),
+            AppMode::PVCList => self.get_selected_pvc().map(|p| p.name.clone()),
+            AppMode::PVList => self.get_selected_pv().map(|p| p.name.clone()),
+            _ => None,
+        }
+    }
+
+    /// 打开保存弹窗；`full` 时在日志视图中不带 --tail 重新获取完整日志，
+    /// 在 YAML 视图中导出精简清单
+    pub fn open_save_dialog(&mut self, full: bool) {
+        let (kind, name) = match self.mode {
+            AppMode::Logs => {
+                let kind = if full {
+                    SaveKind::FullLogs
+                } else {
+                    SaveKind::Logs
+                };
+                (kind, self.get_selected_pod().map(|p| p.name.clone()))
+            }
+            AppMode::Describe if !full => (SaveKind::Describe, self.detail_resource_name()),
+            AppMode::YamlView if full => (SaveKind::NeatYaml, self.detail_resource_name()),
+            AppMode::YamlView => (SaveKind::Yaml, self.detail_resource_name()),
+            _ => return,
+        };
+        let Some(name) = name else {
+            return;
+        };
+        let path = default_file_name(&self.current_namespace, &name, kind, chrono::Local::now());
+        self.save_dialog = Some(SaveDialog::new(kind, path));
+    }
+
+    /// 要保存的完整缓冲区内容（完整日志由主循环另行获取）
+    pub fn save_content(&self, kind: SaveKind) -> String {
+        let mut content = match kind {
+            SaveKind::Logs | SaveKind::FullLogs => self.logs.join("\n"),
+            SaveKind::Describe => self.describe_content.clone(),
+            // 精简模式下保存看到的内容
+            SaveKind::Yaml if self.yaml_neat => self.yaml_lines_cache.join("\n"),
+            SaveKind::Yaml => self.yaml_content.clone(),
+            SaveKind::NeatYaml => neat_lines(&self.yaml_lines_cache).join("\n"),
+        };
+        if !content.is_empty() && !content.ends_with('\n') {
+            content.push('\n');
+        }
+        content
+    }
+
     pub fn set_current_command(&mut self, command: &str) {
         self.current_command = command.to_string();
     }
@@ -469,14 +1069,91 @@ impl AppState {
 
     pub fn set_describe_content(&mut self, content: String) {
         self.describe_lines_cache = content.lines().map(|l| l.to_string()).collect();
+        self.describe_outline.set_lines(&self.describe_lines_cache);
+        self.content_search.refresh(&self.describe_lines_cache);
+        self.describe_scroll = self
+            .describe_scroll
+            .min(self.describe_outline.rows().len().saturating_sub(1));
         self.describe_content = content;
     }
 
     pub fn set_yaml_content(&mut self, content: String) {
         self.yaml_lines_cache = content.lines().map(|l| l.to_string()).collect();
+        if self.yaml_neat {
+            self.yaml_lines_cache = neat_lines(&self.yaml_lines_cache);
+        }
+        self.yaml_tree.set_lines(&self.yaml_lines_cache);
+        self.content_search.refresh(&self.yaml_lines_cache);
+        self.yaml_scroll = self
+            .yaml_scroll
+            .min(self.yaml_tree.rows().len().saturating_sub(1));
         self.yaml_content = content;
     }
 
+    /// 切换精简模式，光标尽量停留在同一路径上
+    pub fn toggle_yaml_neat(&mut self) {
+        let path = self
+            .yaml_cursor_line()
+            .map(|line| self.yaml_tree.lines[line].path.clone());
+        self.yaml_neat = !self.yaml_neat;
+        let content = std::mem::take(&mut self.yaml_content);
+        self.set_yaml_content(content);
+        if let Some(line) =
+            path.and_then(|path| self.yaml_tree.lines.iter().position(|l| l.path == path))
+        {
+            self.yaml_scroll = self.yaml_tree.visible_row(line);
+        }
+    }
+
+    /// YAML 视图光标所在的源行
+    pub fn yaml_cursor_line(&self) -> Option<usize> {
+        self.yaml_tree.rows().get(self.yaml_scroll).copied()
+    }
+
+    /// 修改 YAML / Describe 视图的搜索查询，选中当前位置之后的第一个匹配
+    pub fn update_content_search(&mut self, query: String) {
+        let (lines, anchor) = match self.mode {
+            AppMode::Describe => (
+                &self.describe_lines_cache,
+                self.describe_outline
+                    .rows()
+                    .get(self.describe_scroll)
+                    .copied(),
+            ),
+            _ => (&self.yaml_lines_cache, self.yaml_cursor_line()),
+        };
+        self.content_search.set_query(query, lines);
+        self.content_search.select_from(anchor.unwrap_or(0));
+        self.jump_to_content_match();
+    }
+
+    pub fn content_search_step(&mut self, forward: bool) {
+        if self.content_search.step(forward).is_some() {
+            self.jump_to_content_match();
+        }
+    }
+
+    /// 滚动到当前匹配，所在的节点或段落被折叠时先展开
+    pub fn jump_to_content_match(&mut self) {
+        let Some(line) = self.content_search.current_line() else {
+            return;
+        };
+        match self.mode {
+            AppMode::YamlView => {
+                self.yaml_tree.reveal(line);
+                self.yaml_scroll = self.yaml_tree.visible_row(line);
+            }
+            AppMode::Describe => {
+                self.describe_outline.reveal(line);
+                // Events 固定显示，其中的匹配不需要滚动主面板
+                if let Some(row) = self.describe_outline.rows().iter().position(|&l| l == line) {
+                    self.describe_scroll = row;
+                }
+            }
+            _ => {}
+        }
+    }
+
     pub fn scroll_up(&mut self) {
         match self.mode {
             AppMode::Logs => {
@@ -524,7 +1201,7 @@ impl AppState {
                 if self.split_log_mode {
                     match self.active_pane {
                         ActivePane::Left => {
-                            if self.logs_scroll + 1 < self.logs.len() {
+                            if self.logs_scroll + 1 < self.log_view_len() {
                                 self.logs_scroll += 1;
                             }
                         }
@@ -535,23 +1212,23 @@ impl AppState {
                         }
                     }
                 } else {
-                    if self.logs_scroll + 1 < self.logs.len() {
+                    if self.logs_scroll + 1 < self.log_view_len() {
                         self.logs_scroll += 1;
                     }
                 }
             }
             AppMode::Describe => {
-                if self.describe_scroll + 1 < self.describe_lines_cache.len() {
+                if self.describe_scroll + 1 < self.describe_outline.rows().len() {
                     self.describe_scroll += 1;
                 }
             }
             AppMode::YamlView => {
-                if self.yaml_scroll + 1 < self.yaml_lines_cache.len() {
+                if self.yaml_scroll + 1 < self.yaml_tree.rows().len() {
                     self.yaml_scroll += 1;
                 }
             }
             AppMode::TopView => {
-                if self.metrics_scroll + 1 < self.pod_metrics.len() {
+                if self.metrics_scroll + 1 < self.metrics_len() {
                     self.metrics_scroll += 1;
                 }
             }
@@ -592,7 +1269,7 @@ impl AppState {
                 if self.split_log_mode {
                     match self.active_pane {
                         ActivePane::Left => {
-                            let max = self.logs.len().saturating_sub(1);
+                            let max = self.log_view_len().saturating_sub(1);
                             self.logs_scroll = (self.logs_scroll + 10).min(max);
                         }
                         ActivePane::Right => {
@@ -601,26 +1278,99 @@ impl AppState {
                         }
                     }
                 } else {
-                    let max_scroll = self.logs.len().saturating_sub(1);
+                    let max_scroll = self.log_view_len().saturating_sub(1);
                     self.logs_scroll = (self.logs_scroll + 10).min(max_scroll);
                 }
             }
             AppMode::Describe => {
-                let max_scroll = self.describe_lines_cache.len().saturating_sub(1);
+                let max_scroll = self.describe_outline.rows().len().saturating_sub(1);
                 self.describe_scroll = (self.describe_scroll + 10).min(max_scroll);
             }
             AppMode::YamlView => {
-                let max_scroll = self.yaml_lines_cache.len().saturating_sub(1);
+                let max_scroll = self.yaml_tree.rows().len().saturating_sub(1);
                 self.yaml_scroll = (self.yaml_scroll + 10).min(max_scroll);
             }
             AppMode::TopView => {
-                let max_scroll = self.pod_metrics.len().saturating_sub(1);
+                let max_scroll = self.metrics_len().saturating_sub(1);
                 self.metrics_scroll = (self.metrics_scroll + 10).min(max_scroll);
             }
             _ => {}
         }
     }
 
+    fn metrics_len(&self) -> usize {
+        if self.top_nodes {
+            self.node_metrics.len()
+        } else {
+            self.pod_metrics.len()
+        }
+    }
+
+    /// TopView 是否需要拉取指标：刚进入视图，或到了自动刷新时间
+    pub fn metrics_refresh_due(&self) -> bool {
+        self.mode == AppMode::TopView && (self.metrics_pending || self.should_refresh())
+    }
+
+    /// 记录一次 kubectl top pods 的结果；失败时也重置刷新计时，等下一个周期再试
+    pub fn finish_pod_metrics_refresh(&mut self, result: Result<Vec<PodMetrics>>) {
+        if let Ok(metrics) = result {
+            self.set_pod_metrics(metrics);
+        }
+        self.metrics_pending = false;
+        self.refresh_data();
+    }
+
+    /// 记录一次 kubectl top nodes 的结果；失败或集群为空时同样等下一个周期，
+    /// 不会每次按键都重新执行
+    pub fn finish_node_metrics_refresh(&mut self, result: Result<Vec<NodeMetrics>>) {
+        match result {
+            Ok(metrics) => self.set_node_metrics(metrics),
+            Err(error) => self.node_metrics_error = Some(error.to_string()),
+        }
+        self.metrics_pending = false;
+        self.refresh_data();
+    }
+
+    /// 设置 Pod 指标并记入历史
+    pub fn set_pod_metrics(&mut self, metrics: Vec<PodMetrics>) {
+        self.metrics_history.record(&metrics);
+        self.pod_metrics = metrics;
+        self.metrics_scroll = self
+            .metrics_scroll
+            .min(self.pod_metrics.len().saturating_sub(1));
+    }
+
+    /// 设置节点指标并按当前方式排序（CPU/内存按使用率从高到低）
+    pub fn set_node_metrics(&mut self, metrics: Vec<NodeMetrics>) {
+        self.node_metrics = metrics;
+        self.node_metrics_error = None;
+        self.sort_node_metrics();
+        self.metrics_scroll = self
+            .metrics_scroll
+            .min(self.node_metrics.len().saturating_sub(1));
+    }
+
+    pub fn cycle_node_metrics_sort(&mut self) {
+        self.node_metrics_sort = self.node_metrics_sort.next();
+        self.sort_node_metrics();
+        self.metrics_scroll = 0;
+    }
+
+    fn sort_node_metrics(&mut self) {
+        let percentage: fn(&NodeMetrics) -> Option<f64> = match self.node_metrics_sort {
+            NodeMetricsSort::Name => {
+                self.node_metrics.sort_by(|a, b| a.name.cmp(&b.name));
+                return;
+            }
+            NodeMetricsSort::Cpu => |m| m.cpu_percentage,
+            NodeMetricsSort::Memory => |m| m.memory_percentage,
+        };
+        // 没有指标的节点排在最后
+        let usage = |m: &NodeMetrics| percentage(m).unwrap_or(-1.0);
+        self.node_metrics
+            .sort_by(|a, b| usage(b).total_cmp(&usage(a)));
+    }
+
     pub fn reset_scroll(&mut self) {
         self.logs_scroll = 0;
         self.split_log_scroll = 0;
@@ -884,6 +1634,8 @@ mod tests {
             age: "1d".into(),
             node: None,
             ip: None,
+            containers: Vec::new(),
+            owner: None,
         });
         state.toggle_batch_mode();
         assert!(state.batch_mode);
@@ -912,6 +1664,8 @@ mod tests {
                 age: "1d".into(),
                 node: None,
                 ip: None,
+                containers: Vec::new(),
+                owner: None,
             });
         }
         state.toggle_batch_mode();
@@ -938,10 +1692,143 @@ mod tests {
         state.scroll_up();
         assert_eq!(state.split_log_scroll, 0);
         state.active_pane = ActivePane::Left;
-        state.logs = vec!["x".into(), "y".into()];
+        state.set_logs(vec!["x".into(), "y".into()]);
         state.logs_scroll = 0;
         state.scroll_down();
         assert_eq!(state.logs_scroll, 1);
         assert_eq!(state.split_log_scroll, 0);
     }
+
+    #[test]
+    fn test_append_logs_ring_buffer() {
+        let mut state = AppState::default();
+        state.append_logs((0..LOG_BUFFER_LINES).map(|i| i.to_string()).collect());
+        assert_eq!(state.logs_scroll, LOG_BUFFER_LINES - 1);
+
+        state.append_logs(vec!["a".into(), "ERROR b".into()]);
+        assert_eq!(state.logs.len(), LOG_BUFFER_LINES);
+        assert_eq!(state.logs[0], "2");
+        assert_eq!(state.logs.last().unwrap(), "ERROR b");
+
+        // 解析缓存随缓冲一起丢弃最旧的行，级别过滤按缓存判断
+        assert_eq!(state.parsed_logs.len(), LOG_BUFFER_LINES);
+        state.cycle_log_level_filter();
+        assert_eq!(
+            state.log_view(),
+            vec![LogViewLine::Line(LOG_BUFFER_LINES - 1)]
+        );
+        state.clear_logs();
+        assert!(state.parsed_logs.is_empty());
+    }
+
+    #[test]
+    fn test_logs_pause_keeps_buffering() {
+        let mut state = AppState::default();
+        state.append_logs(vec!["1".into(), "2".into(), "3".into()]);
+        state.logs_scroll = 1;
+        state.toggle_logs_pause();
+
+        state.append_logs(vec!["4".into(), "5".into()]);
+        assert_eq!(state.logs.len(), 5);
+        assert_eq!(state.logs_scroll, 1);
+        assert_eq!(state.logs_new_while_paused, 2);
+
+        state.toggle_logs_pause();
+        assert_eq!(state.logs_new_while_paused, 0);
+        assert_eq!(state.logs_scroll, 4);
+    }
+
+    #[test]
+    fn test_logs_resume_scrolls_filtered_view() {
+        let mut state = AppState::default();
+        state.append_logs(vec!["INFO a".into(), "ERROR b".into(), "INFO c".into()]);
+        state.cycle_log_level_filter();
+        state.toggle_logs_pause();
+        state.append_logs(vec!["INFO d".into(), "ERROR e".into()]);
+
+        // 恢复后停在过滤视图的最后一行，而不是越过所有可见行
+        state.toggle_logs_pause();
+        assert_eq!(state.log_view_len(), 2);
+        assert_eq!(state.logs_scroll, 1);
+    }
+
+    fn pod_metrics(cpu: &str) -> PodMetrics {
+        PodMetrics {
+            name: "web".to_string(),
+            namespace: "default".to_string(),
+            cpu: cpu.to_string(),
+            memory: "10Mi".to_string(),
+            bounds: Default::default(),
+            usage: Default::default(),
+            containers: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_top_view_records_history_every_refresh() {
+        let mut state = AppState {
+            mode: AppMode::PodList,
+            ..Default::default()
+        };
+        state.handle_top_view();
+        // 主循环的一轮：到期时拉取一次指标
+        let cycle = |state: &mut AppState, cpu: &str| {
+            if state.metrics_refresh_due() {
+                state.finish_pod_metrics_refresh(Ok(vec![pod_metrics(cpu)]));
+            }
+        };
+        let samples = |state: &AppState| {
+            state
+                .metrics_history
+                .pod("default", "web")
+                .map_or(0, |series| series.sample_count())
+        };
+
+        cycle(&mut state, "100m");
+        cycle(&mut state, "900m");
+        assert_eq!(samples(&state), 1);
+
+        state.last_update = Instant::now() - state.refresh_interval;
+        cycle(&mut state, "200m");
+        assert_eq!(samples(&state), 2);
+        assert_eq!(state.pod_metrics[0].cpu, "200m");
+
+        // 拉取失败也等到下一个周期再试
+        state.last_update = Instant::now() - state.refresh_interval;
+        if state.metrics_refresh_due() {
+            state.finish_pod_metrics_refresh(Err(anyhow::anyhow!("metrics not available")));
+        }
+        assert!(!state.metrics_refresh_due());
+        assert_eq!(samples(&state), 2);
+    }
+
+    #[test]
+    fn test_node_metrics_error_waits_for_next_refresh() {
+        let mut state = AppState {
+            mode: AppMode::NodeList,
+            ..Default::default()
+        };
+        state.handle_top_view();
+        assert!(state.metrics_refresh_due());
+        state.finish_node_metrics_refresh(Err(anyhow::anyhow!("metrics API not available")));
+        assert!(!state.metrics_refresh_due());
+        assert!(state.node_metrics_error.is_some());
+
+        // 下一个刷新周期重试，成功后清除错误
+        state.last_update = Instant::now() - state.refresh_interval;
+        assert!(state.metrics_refresh_due());
+        state.finish_node_metrics_refresh(Ok(Vec::new()));
+        assert!(state.node_metrics_error.is_none());
+        assert!(!state.metrics_refresh_due());
+    }
+
+    #[test]
+    fn test_is_valid_since() {
+        assert!(is_valid_since("30s"));
+        assert!(is_valid_since("1h30m"));
+        assert!(is_valid_since("2024-01-02T03:04:05Z"));
+        assert!(!is_valid_since("5"));
+        assert!(!is_valid_since("m5"));
+        assert!(!is_valid_since("yesterday"));
+    }
 }
@@ -0,0 +1,286 @@
+//! Detection and rendering of JSON / logfmt structured log lines
+
+use serde_json::{Map, Value};
+
+use super::ansi::strip_ansi;
+
+const TIME_KEYS: [&str; 5] = ["time", "timestamp", "ts", "@timestamp", "t"];
+const LEVEL_KEYS: [&str; 5] = ["level", "lvl", "severity", "loglevel", "@level"];
+const MESSAGE_KEYS: [&str; 4] = ["msg", "message", "@message", "log"];
+
+/// 日志级别，按严重程度从高到低排列
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LogLevel {
+    Error,
+    Warn,
+    Info,
+    Debug,
+}
+
+impl LogLevel {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "error" | "err" | "fatal" | "panic" | "critical" | "crit" | "alert" | "emerg" => {
+                Some(Self::Error)
+            }
+            "warn" | "warning" => Some(Self::Warn),
+            "info" | "notice" | "information" => Some(Self::Info),
+            "debug" | "trace" | "verbose" => Some(Self::Debug),
+            _ => None,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Error => "ERROR",
+            Self::Warn => "WARN",
+            Self::Info => "INFO",
+            Self::Debug => "DEBUG",
+        }
+    }
+
+    /// 级别过滤器的下一档：全部 → error → warn → info → debug → 全部
+    pub fn next_filter(filter: Option<Self>) -> Option<Self> {
+        match filter {
+            None => Some(Self::Error),
+            Some(Self::Error) => Some(Self::Warn),
+            Some(Self::Warn) => Some(Self::Info),
+            Some(Self::Info) => Some(Self::Debug),
+            Some(Self::Debug) => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum LogFormat {
+    Json,
+    Logfmt,
+}
+
+/// 解析后的结构化日志行
+#[derive(Debug, Clone, PartialEq)]
+pub struct StructuredLine {
+    pub format: LogFormat,
+    /// kubectl --prefix / --timestamps 加在行首的部分，原样保留
+    pub prefix: String,
+    pub time: Option<String>,
+    pub level: Option<LogLevel>,
+    pub message: Option<String>,
+    pub fields: Vec<(String, String)>,
+    /// 原始的结构化部分，用于展开显示
+    pub body: String,
+}
+
+// 拆出 `[pod/x/c] ` 前缀和 RFC3339 时间戳
+fn split_prefix(line: &str) -> (&str, &str) {
+    let mut rest = line;
+    if rest.starts_with('[')
+        && let Some(end) = rest.find("] ")
+    {
+        rest = &rest[end + 2..];
+    }
+    if let Some((time, after)) = rest.split_once(' ')
+        && chrono::DateTime::parse_from_rfc3339(time).is_ok()
+    {
+        rest = after;
+    }
+    (&line[..line.len() - rest.len()], rest)
+}
+
+fn value_text(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+fn take_first(map: &mut Vec<(String, String)>, keys: &[&str]) -> Option<String> {
+    let index = map
+        .iter()
+        .position(|(k, _)| keys.iter().any(|key| k.eq_ignore_ascii_case(key)))?;
+    Some(map.remove(index).1)
+}
+
+fn is_logfmt_key(key: &str) -> bool {
+    !key.is_empty()
+        && key
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.' | '@' | '/'))
+}
+
+/// 解析 logfmt：`key=value key="quoted value"`，至少两个键值对且不含其它内容
+fn parse_logfmt(text: &str) -> Option<Vec<(String, String)>> {
+    let mut pairs = Vec::new();
+    let mut chars = text.trim().chars().peekable();
+    while chars.peek().is_some() {
+        let mut key = String::new();
+        while let Some(&c) = chars.peek() {
+            if c == '=' || c == ' ' {
+                break;
+            }
+            key.push(c);
+            chars.next();
+        }
+        if chars.next() != Some('=') || !is_logfmt_key(&key) {
+            return None;
+        }
+        let mut value = String::new();
+        if chars.peek() == Some(&'"') {
+            chars.next();
+            loop {
+                match chars.next()? {
+                    '\\' => value.push(chars.next()?),
+                    '"' => break,
+                    c => value.push(c),
+                }
+            }
+        } else {
+            while let Some(&c) = chars.peek() {
+                if c == ' ' {
+                    break;
+                }
+                value.push(c);
+                chars.next();
+            }
+        }
+        pairs.push((key, value));
+        while chars.peek() == Some(&' ') {
+            chars.next();
+        }
+    }
+    (pairs.len() >= 2).then_some(pairs)
+}
+
+/// 识别 JSON 对象或 logfmt 行，普通文本返回 None；ANSI 颜色先去掉
+pub fn parse_line(line: &str) -> Option<StructuredLine> {
+    let line = strip_ansi(line);
+    let (prefix, body) = split_prefix(&line);
+    let trimmed = body.trim();
+    let (format, mut fields) = if trimmed.starts_with('{') {
+        let object: Map<String, Value> = serde_json::from_str(trimmed).ok()?;
+        let fields = object
+            .iter()
+            .map(|(k, v)| (k.clone(), value_text(v)))
+            .collect();
+        (LogFormat::Json, fields)
+    } else {
+        (LogFormat::Logfmt, parse_logfmt(trimmed)?)
+    };
+    let time = take_first(&mut fields, &TIME_KEYS);
+    let level = take_first(&mut fields, &LEVEL_KEYS).and_then(|l| LogLevel::parse(&l));
+    let message = take_first(&mut fields, &MESSAGE_KEYS);
+    Some(StructuredLine {
+        format,
+        prefix: prefix.to_string(),
+        time,
+        level,
+        message,
+        fields,
+        body: trimmed.to_string(),
+    })
+}
+
+/// 日志缓冲中每行的解析结果，收到日志时计算一次，过滤和渲染时直接使用
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParsedLine {
+    pub structured: Option<StructuredLine>,
+    pub level: Option<LogLevel>,
+}
+
+impl ParsedLine {
+    pub fn new(line: &str) -> Self {
+        let structured = parse_line(line);
+        let level = match &structured {
+            Some(parsed) => parsed.level,
+            None => plain_level(line),
+        };
+        Self { structured, level }
+    }
+}
+
+/// 非结构化行的级别：尝试匹配行首常见的级别单词
+fn plain_level(line: &str) -> Option<LogLevel> {
+    let line = strip_ansi(line);
+    let (_, body) = split_prefix(&line);
+    body.split(|c: char| !c.is_ascii_alphabetic())
+        .filter(|word| !word.is_empty())
+        .take(3)
+        .find_map(|word| {
+            // 只认全大写的单词，避免把正文里的 info 当成级别
+            let upper = word.chars().all(|c| c.is_ascii_uppercase());
+            if upper { LogLevel::parse(word) } else { None }
+        })
+}
+
+/// 展开显示：JSON 格式化输出，logfmt 每个键一行
+pub fn pretty(line: &str) -> Vec<String> {
+    match parse_line(line) {
+        Some(parsed) if parsed.format == LogFormat::Json => {
+            let value: Value = serde_json::from_str(&parsed.body).unwrap_or_default();
+            serde_json::to_string_pretty(&value)
+                .unwrap_or(parsed.body)
+                .lines()
+                .map(String::from)
+                .collect()
+        }
+        Some(_) => {
+            let line = strip_ansi(line);
+            let (_, body) = split_prefix(&line);
+            let pairs = parse_logfmt(body.trim()).unwrap_or_default();
+            let width = pairs.iter().map(|(k, _)| k.len()).max().unwrap_or(0);
+            pairs
+                .iter()
+                .map(|(k, v)| format!("{:width$} = {}", k, v, width = width))
+                .collect()
+        }
+        None => vec![strip_ansi(line).into_owned()],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_json_line() {
+        let line = r#"{"ts":"2024-05-01T10:00:00Z","level":"warn","msg":"slow query","ms":1200,"db":"users"}"#;
+        let parsed = parse_line(line).unwrap();
+        assert_eq!(parsed.format, LogFormat::Json);
+        assert_eq!(parsed.time.as_deref(), Some("2024-05-01T10:00:00Z"));
+        assert_eq!(parsed.level, Some(LogLevel::Warn));
+        assert_eq!(parsed.message.as_deref(), Some("slow query"));
+        assert_eq!(
+            parsed.fields,
+            vec![
+                ("db".to_string(), "users".to_string()),
+                ("ms".to_string(), "1200".to_string())
+            ]
+        );
+        assert_eq!(pretty(line)[0], "{");
+    }
+
+    #[test]
+    fn test_parse_logfmt_with_prefix() {
+        let line = r#"[pod/web-1/app] 2024-05-01T10:00:00.1Z level=error msg="connection refused" retry=3"#;
+        let parsed = parse_line(line).unwrap();
+        assert_eq!(parsed.format, LogFormat::Logfmt);
+        assert_eq!(parsed.prefix, "[pod/web-1/app] 2024-05-01T10:00:00.1Z ");
+        assert_eq!(parsed.level, Some(LogLevel::Error));
+        assert_eq!(parsed.message.as_deref(), Some("connection refused"));
+        assert_eq!(parsed.fields, vec![("retry".to_string(), "3".to_string())]);
+
+        assert!(parse_line("plain text with a=b in it").is_none());
+        assert!(parse_line("{not json").is_none());
+    }
+
+    #[test]
+    fn test_line_level() {
+        let level = |line: &str| ParsedLine::new(line).level;
+        assert_eq!(level("2024 ERROR failed to bind"), Some(LogLevel::Error));
+        assert_eq!(level("I will info you later"), None);
+        assert_eq!(level(r#"{"severity":"DEBUG"}"#), Some(LogLevel::Debug));
+        assert!(ParsedLine::new("plain text").structured.is_none());
+        assert_eq!(LogLevel::next_filter(Some(LogLevel::Debug)), None);
+        assert!(LogLevel::Error < LogLevel::Info);
+    }
+}
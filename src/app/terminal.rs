@@ -0,0 +1,240 @@
+//! Embedded pseudo-terminal sessions hosting `kubectl exec` / `kubectl attach`
+
+use anyhow::{Result, anyhow};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use portable_pty::{Child, CommandBuilder, MasterPty, PtySize, native_pty_system};
+use std::io::{Read, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+
+/// Lines kept above the visible screen of each session
+const SCROLLBACK_LINES: usize = 1000;
+
+struct SessionIo {
+    master: Box<dyn MasterPty + Send>,
+    writer: Box<dyn Write + Send>,
+    child: Box<dyn Child + Send + Sync>,
+}
+
+/// A command running inside a pseudo-terminal; clones share the same session
+#[derive(Clone)]
+pub struct TerminalSession {
+    pub title: String,
+    pub command: String,
+    parser: Arc<Mutex<vt100::Parser>>,
+    io: Arc<Mutex<SessionIo>>,
+    exited: Arc<AtomicBool>,
+}
+
+impl std::fmt::Debug for TerminalSession {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TerminalSession")
+            .field("title", &self.title)
+            .field("command", &self.command)
+            .field("exited", &self.is_exited())
+            .finish()
+    }
+}
+
+impl TerminalSession {
+    /// Spawn `sh -c command` in a new pty of the given size
+    pub fn spawn(title: &str, command: &str, rows: u16, cols: u16) -> Result<Self> {
+        let size = PtySize {
+            rows,
+            cols,
+            pixel_width: 0,
+            pixel_height: 0,
+        };
+        let pair = native_pty_system()
+            .openpty(size)
+            .map_err(|e| anyhow!("Failed to open pty: {}", e))?;
+
+        let mut cmd = CommandBuilder::new("sh");
+        cmd.arg("-c");
+        cmd.arg(command);
+        if let Ok(cwd) = std::env::current_dir() {
+            cmd.cwd(cwd);
+        }
+        let child = pair
+            .slave
+            .spawn_command(cmd)
+            .map_err(|e| anyhow!("Failed to spawn '{}': {}", command, e))?;
+        // 关闭本进程持有的 slave 端，子进程退出后 reader 才能读到 EOF
+        drop(pair.slave);
+
+        let mut reader = pair
+            .master
+            .try_clone_reader()
+            .map_err(|e| anyhow!("Failed to read from pty: {}", e))?;
+        let writer = pair
+            .master
+            .take_writer()
+            .map_err(|e| anyhow!("Failed to write to pty: {}", e))?;
+
+        let parser = Arc::new(Mutex::new(vt100::Parser::new(rows, cols, SCROLLBACK_LINES)));
+        let exited = Arc::new(AtomicBool::new(false));
+
+        // 后台线程持续读取输出并喂给终端模拟器
+        {
+            let parser = Arc::clone(&parser);
+            let exited = Arc::clone(&exited);
+            std::thread::spawn(move || {
+                let mut buf = [0u8; 8192];
+                loop {
+                    match reader.read(&mut buf) {
+                        Ok(0) | Err(_) => break,
+                        Ok(n) => {
+                            if let Ok(mut parser) = parser.lock() {
+                                parser.process(&buf[..n]);
+                            }
+                        }
+                    }
+                }
+                exited.store(true, Ordering::Relaxed);
+            });
+        }
+
+        Ok(Self {
+            title: title.to_string(),
+            command: command.to_string(),
+            parser,
+            io: Arc::new(Mutex::new(SessionIo {
+                master: pair.master,
+                writer,
+                child,
+            })),
+            exited,
+        })
+    }
+
+    pub fn is_exited(&self) -> bool {
+        self.exited.load(Ordering::Relaxed)
+    }
+
+    /// Forward a key press to the session
+    pub fn send_key(&self, key: KeyEvent) {
+        if self.is_exited() {
+            return;
+        }
+        let application_cursor = self
+            .parser
+            .lock()
+            .map(|p| p.screen().application_cursor())
+            .unwrap_or(false);
+        let bytes = key_to_bytes(key, application_cursor);
+        if bytes.is_empty() {
+            return;
+        }
+        if let Ok(mut io) = self.io.lock() {
+            let _ = io.writer.write_all(&bytes);
+            let _ = io.writer.flush();
+        }
+    }
+
+    /// Resize both the emulator screen and the pty, no-op when unchanged
+    pub fn resize(&self, rows: u16, cols: u16) {
+        if rows == 0 || cols == 0 {
+            return;
+        }
+        if let Ok(mut parser) = self.parser.lock() {
+            if parser.screen().size() == (rows, cols) {
+                return;
+            }
+            parser.screen_mut().set_size(rows, cols);
+        }
+        if let Ok(io) = self.io.lock() {
+            let _ = io.master.resize(PtySize {
+                rows,
+                cols,
+                pixel_width: 0,
+                pixel_height: 0,
+            });
+        }
+    }
+
+    /// Run `f` against the current screen contents
+    pub fn with_screen<R>(&self, f: impl FnOnce(&vt100::Screen) -> R) -> Option<R> {
+        self.parser.lock().ok().map(|parser| f(parser.screen()))
+    }
+
+    /// Terminate the session and reap the child process
+    pub fn kill(&self) {
+        if let Ok(mut io) = self.io.lock() {
+            if !self.is_exited() {
+                let _ = io.child.kill();
+            }
+            let _ = io.child.try_wait();
+        }
+    }
+}
+
+/// Encode a key press the way a VT100-compatible terminal would
+pub fn key_to_bytes(key: KeyEvent, application_cursor: bool) -> Vec<u8> {
+    let arrow = |c: u8| {
+        if application_cursor {
+            vec![0x1b, b'O', c]
+        } else {
+            vec![0x1b, b'[', c]
+        }
+    };
+
+    let mut bytes = match key.code {
+        KeyCode::Char(c) if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            match c.to_ascii_lowercase() {
+                c @ 'a'..='z' => vec![c as u8 - b'a' + 1],
+                '@' | ' ' => vec![0],
+                '[' => vec![0x1b],
+                '\\' => vec![0x1c],
+                ']' => vec![0x1d],
+                '^' => vec![0x1e],
+                '_' => vec![0x1f],
+                _ => c.to_string().into_bytes(),
+            }
+        }
+        KeyCode::Char(c) => c.to_string().into_bytes(),
+        KeyCode::Enter => vec![b'\r'],
+        KeyCode::Backspace => vec![0x7f],
+        KeyCode::Tab => vec![b'\t'],
+        KeyCode::BackTab => b"\x1b[Z".to_vec(),
+        KeyCode::Esc => vec![0x1b],
+        KeyCode::Up => arrow(b'A'),
+        KeyCode::Down => arrow(b'B'),
+        KeyCode::Right => arrow(b'C'),
+        KeyCode::Left => arrow(b'D'),
+        KeyCode::Home => arrow(b'H'),
+        KeyCode::End => arrow(b'F'),
+        KeyCode::Insert => b"\x1b[2~".to_vec(),
+        KeyCode::Delete => b"\x1b[3~".to_vec(),
+        KeyCode::PageUp => b"\x1b[5~".to_vec(),
+        KeyCode::PageDown => b"\x1b[6~".to_vec(),
+        KeyCode::F(n) => match n {
+            1 => b"\x1bOP".to_vec(),
+            2 => b"\x1bOQ".to_vec(),
+            3 => b"\x1bOR".to_vec(),
+            4 => b"\x1bOS".to_vec(),
+            5 => b"\x1b[15~".to_vec(),
+            6 => b"\x1b[17~".to_vec(),
+            7 => b"\x1b[18~".to_vec(),
+            8 => b"\x1b[19~".to_vec(),
+            9 => b"\x1b[20~".to_vec(),
+            10 => b"\x1b[21~".to_vec(),
+            11 => b"\x1b[23~".to_vec(),
+            12 => b"\x1b[24~".to_vec(),
+            _ => Vec::new(),
+        },
+        _ => Vec::new(),
+    };
+
+    // Alt 以 ESC 前缀表示
+    if key.modifiers.contains(KeyModifiers::ALT) && !bytes.is_empty() {
+        bytes.insert(0, 0x1b);
+    }
+    bytes
+}
+
+/// Key that hands focus from the shell back to the TUI (Ctrl+])
+pub fn is_focus_toggle_key(key: &KeyEvent) -> bool {
+    // 部分终端把 Ctrl+] 上报为 Ctrl+5
+    key.modifiers.contains(KeyModifiers::CONTROL)
+        && matches!(key.code, KeyCode::Char(']') | KeyCode::Char('5'))
+}
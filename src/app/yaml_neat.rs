@@ -0,0 +1,168 @@
+//! Strip server-managed fields from `kubectl get -o yaml` output ("neat" mode)
+
+use super::yaml_tree::parse_lines;
+
+/// 由 API server 或控制器维护的字段，重新 apply 时不需要。
+/// 有意保留：调度结果 spec.nodeName（去掉后 Pod 会被重新调度，不再对应当前对象）、
+/// Service 的 nodePort 和 headless Service 的 `clusterIP: None`（都是用户可以指定的值）
+const SERVER_FIELDS: &[&str] = &[
+    "metadata.managedFields",
+    "metadata.resourceVersion",
+    "metadata.uid",
+    "metadata.generation",
+    "metadata.creationTimestamp",
+    "metadata.selfLink",
+    "metadata.annotations[\"kubectl.kubernetes.io/last-applied-configuration\"]",
+    "metadata.annotations[\"deployment.kubernetes.io/revision\"]",
+    // Service 分配的地址，apply 到其他集群会冲突
+    "spec.clusterIPs",
+    "status",
+];
+
+fn is_server_field(node: &str, line: &str) -> bool {
+    // Pod 模板等处默认生成的 `creationTimestamp: null`
+    SERVER_FIELDS.contains(&node)
+        || (node.ends_with(".creationTimestamp") && line.trim_end().ends_with(": null"))
+        || (node == "spec.clusterIP" && !line.trim_end().ends_with(": None"))
+}
+
+/// 删除节点覆盖的所有行；remove 的参数为节点路径、节点所在行和节点是否没有子行
+fn remove_nodes(lines: &[String], remove: impl Fn(&str, &str, bool) -> bool) -> Vec<String> {
+    let parsed = parse_lines(lines);
+    let mut result = Vec::with_capacity(lines.len());
+    let mut index = 0;
+    while index < lines.len() {
+        match &parsed[index].node {
+            Some(node) if remove(node, &lines[index], parsed[index].end == index + 1) => {
+                index = parsed[index].end
+            }
+            _ => {
+                result.push(lines[index].clone());
+                index += 1;
+            }
+        }
+    }
+    result
+}
+
+/// 去掉服务端字段后的清单，可直接重新 apply；注解全部删除时一并去掉 `annotations:`
+pub fn neat_lines(lines: &[String]) -> Vec<String> {
+    let lines = remove_nodes(lines, |node, line, _| is_server_field(node, line));
+    remove_nodes(&lines, |node, line, leaf| {
+        node == "metadata.annotations" && leaf && line.trim_end().ends_with(':')
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LIVE: &str = "apiVersion: apps/v1
+kind: Deployment
+metadata:
+  annotations:
+    deployment.kubernetes.io/revision: \"3\"
+    kubectl.kubernetes.io/last-applied-configuration: |
+      {\"apiVersion\":\"apps/v1\",\"kind\":\"Deployment\"}
+  creationTimestamp: \"2024-05-01T09:30:05Z\"
+  generation: 3
+  labels:
+    app: web
+  managedFields:
+  - apiVersion: apps/v1
+    fieldsType: FieldsV1
+    manager: kubectl
+  name: web
+  namespace: prod
+  resourceVersion: \"123456\"
+  uid: 0b4f7c3e-1111-2222-3333-444455556666
+spec:
+  replicas: 2
+  template:
+    metadata:
+      creationTimestamp: null
+      labels:
+        app: web
+    spec:
+      containers:
+      - image: nginx
+        name: web
+status:
+  availableReplicas: 2
+  replicas: 2
+";
+
+    const NEAT: &str = "apiVersion: apps/v1
+kind: Deployment
+metadata:
+  labels:
+    app: web
+  name: web
+  namespace: prod
+spec:
+  replicas: 2
+  template:
+    metadata:
+      labels:
+        app: web
+    spec:
+      containers:
+      - image: nginx
+        name: web
+";
+
+    fn lines(text: &str) -> Vec<String> {
+        text.lines().map(str::to_string).collect()
+    }
+
+    #[test]
+    fn test_neat_lines() {
+        assert_eq!(neat_lines(&lines(LIVE)), lines(NEAT));
+        // 已经精简的清单保持不变
+        assert_eq!(neat_lines(&lines(NEAT)), lines(NEAT));
+    }
+
+    #[test]
+    fn test_neat_keeps_user_annotations() {
+        let live = "metadata:
+  annotations:
+    kubectl.kubernetes.io/last-applied-configuration: '{}'
+    team: payments
+  name: web
+";
+        assert_eq!(
+            neat_lines(&lines(live)),
+            lines("metadata:\n  annotations:\n    team: payments\n  name: web\n")
+        );
+    }
+
+    #[test]
+    fn test_neat_service_drops_allocated_ips() {
+        let live = "kind: Service
+spec:
+  clusterIP: 10.96.12.7
+  clusterIPs:
+  - 10.96.12.7
+  ports:
+  - nodePort: 30080
+    port: 80
+  type: NodePort
+";
+        let neat = "kind: Service
+spec:
+  ports:
+  - nodePort: 30080
+    port: 80
+  type: NodePort
+";
+        assert_eq!(neat_lines(&lines(live)), lines(neat));
+
+        // headless Service 的 None 是用户指定的
+        let headless =
+            "spec:\n  clusterIP: None\n  clusterIPs:\n  - None\n  selector:\n    app: db\n";
+        assert_eq!(
+            neat_lines(&lines(headless)),
+            lines("spec:\n  clusterIP: None\n  selector:\n    app: db\n")
+        );
+    }
+}
@@ -0,0 +1,379 @@
+//! Structure of `kubectl get -o yaml` output: per-line paths and foldable nodes
+
+use std::collections::HashSet;
+
+/// 每一行的结构信息
+#[derive(Debug, Clone, PartialEq)]
+pub struct YamlLine {
+    /// 该行最深的路径，用于面包屑，如 `spec.containers[0].env`
+    pub path: String,
+    /// 该行开始的节点路径（映射键或列表项），折叠状态按它保存；续行和空行为 None
+    pub node: Option<String>,
+    /// 节点覆盖到的行（不含）；叶子为下一行
+    pub end: usize,
+}
+
+/// 解析过程中的祖先节点
+struct Entry {
+    column: usize,
+    segment: String,
+    /// 列表项的序号
+    item: Option<usize>,
+    /// 节点开始的行；`- key:` 行内的键不单独成为节点
+    line: Option<usize>,
+}
+
+fn join_path(stack: &[Entry]) -> String {
+    let mut path = String::new();
+    for entry in stack {
+        if !entry.segment.starts_with('[') && !path.is_empty() {
+            path.push('.');
+        }
+        path.push_str(&entry.segment);
+    }
+    path
+}
+
+/// 路径中的键；含 `.` 或 `/` 的键（如注解）用 `["key"]`
+fn key_segment(key: &str) -> String {
+    if key.contains(['.', '/']) {
+        format!("[\"{}\"]", key)
+    } else {
+        key.to_string()
+    }
+}
+
+/// `key: value` / `key:`，返回键和冒号后的值
+fn split_key(text: &str) -> Option<(&str, &str)> {
+    if text.starts_with(['{', '[', '#']) {
+        return None;
+    }
+    let key_end = match text.chars().next()? {
+        quote @ ('"' | '\'') => text[1..].find(quote)? + 2,
+        _ => text.find(':')?,
+    };
+    let rest = text[key_end..].strip_prefix(':')?;
+    if !rest.is_empty() && !rest.starts_with(' ') {
+        return None;
+    }
+    let key = text[..key_end].trim_matches(['"', '\'']);
+    Some((key, rest.trim()))
+}
+
+/// 按缩进解析；兼容 kubectl 把列表项与父键写在同一列的格式
+pub fn parse_lines(lines: &[String]) -> Vec<YamlLine> {
+    let mut result: Vec<YamlLine> = Vec::with_capacity(lines.len());
+    let mut stack: Vec<Entry> = Vec::new();
+    // 多行标量（`|`、`>` 或折行的字符串）所属键的列
+    let mut scalar_column: Option<usize> = None;
+
+    let close = |entry: Entry, end: usize, result: &mut Vec<YamlLine>| {
+        if let Some(line) = entry.line {
+            result[line].end = end;
+        }
+    };
+
+    for (index, line) in lines.iter().enumerate() {
+        let trimmed = line.trim_start();
+        let indent = line.len() - trimmed.len();
+        let continuation = trimmed.is_empty()
+            || trimmed.starts_with('#')
+            || scalar_column.is_some_and(|column| indent > column);
+        if continuation {
+            result.push(YamlLine {
+                path: join_path(&stack),
+                node: None,
+                end: index + 1,
+            });
+            continue;
+        }
+        scalar_column = None;
+
+        if trimmed == "---" {
+            while let Some(entry) = stack.pop() {
+                close(entry, index, &mut result);
+            }
+            result.push(YamlLine {
+                path: String::new(),
+                node: None,
+                end: index + 1,
+            });
+            continue;
+        }
+
+        let item = trimmed == "-" || trimmed.starts_with("- ");
+        let node = if item {
+            let mut sibling = None;
+            while let Some(top) = stack.last() {
+                if top.column < indent || (top.column == indent && top.item.is_none()) {
+                    break;
+                }
+                let entry = stack.pop().unwrap();
+                if entry.column == indent {
+                    sibling = entry.item;
+                }
+                close(entry, index, &mut result);
+            }
+            let position = sibling.map_or(0, |n| n + 1);
+            stack.push(Entry {
+                column: indent,
+                segment: format!("[{}]", position),
+                item: Some(position),
+                line: Some(index),
+            });
+            let node = join_path(&stack);
+
+            let content = trimmed[1..].trim_start();
+            let column = indent + trimmed.len() - content.len();
+            match split_key(content) {
+                Some((key, value)) => {
+                    stack.push(Entry {
+                        column,
+                        segment: key_segment(key),
+                        item: None,
+                        line: None,
+                    });
+                    if !value.is_empty() {
+                        scalar_column = Some(column);
+                    }
+                }
+                None => scalar_column = Some(indent),
+            }
+            Some(node)
+        } else if let Some((key, value)) = split_key(trimmed) {
+            while stack.last().is_some_and(|top| top.column >= indent) {
+                let entry = stack.pop().unwrap();
+                close(entry, index, &mut result);
+            }
+            stack.push(Entry {
+                column: indent,
+                segment: key_segment(key),
+                item: None,
+                line: Some(index),
+            });
+            if !value.is_empty() {
+                scalar_column = Some(indent);
+            }
+            Some(join_path(&stack))
+        } else {
+            None
+        };
+
+        result.push(YamlLine {
+            path: join_path(&stack),
+            node,
+            end: index + 1,
+        });
+    }
+    while let Some(entry) = stack.pop() {
+        close(entry, lines.len(), &mut result);
+    }
+
+    // 节点末尾的空行不计入
+    for index in 0..result.len() {
+        while result[index].end > index + 1 && lines[result[index].end - 1].trim().is_empty() {
+            result[index].end -= 1;
+        }
+    }
+    result
+}
+
+/// YAML 视图的结构与折叠状态；折叠按节点路径保存，自动刷新后保持
+#[derive(Debug, Clone, Default)]
+pub struct YamlTree {
+    pub lines: Vec<YamlLine>,
+    pub folded: HashSet<String>,
+}
+
+impl YamlTree {
+    pub fn set_lines(&mut self, lines: &[String]) {
+        self.lines = parse_lines(lines);
+    }
+
+    pub fn is_foldable(&self, line: usize) -> bool {
+        self.lines
+            .get(line)
+            .is_some_and(|l| l.node.is_some() && l.end > line + 1)
+    }
+
+    pub fn is_folded(&self, line: usize) -> bool {
+        self.is_foldable(line)
+            && self.lines[line]
+                .node
+                .as_ref()
+                .is_some_and(|node| self.folded.contains(node))
+    }
+
+    /// 可见的行号
+    pub fn rows(&self) -> Vec<usize> {
+        let mut rows = Vec::new();
+        let mut line = 0;
+        while line < self.lines.len() {
+            rows.push(line);
+            line = if self.is_folded(line) {
+                self.lines[line].end
+            } else {
+                line + 1
+            };
+        }
+        rows
+    }
+
+    /// 某行在可见行中的位置；被折叠时为包含它的节点
+    pub fn visible_row(&self, line: usize) -> usize {
+        self.rows()
+            .iter()
+            .rposition(|&row| row <= line)
+            .unwrap_or(0)
+    }
+
+    pub fn toggle(&mut self, line: usize) {
+        if !self.is_foldable(line) {
+            return;
+        }
+        let node = self.lines[line].node.clone().unwrap_or_default();
+        if !self.folded.remove(&node) {
+            self.folded.insert(node);
+        }
+    }
+
+    /// 展开包含 line 的所有节点，使该行可见
+    pub fn reveal(&mut self, line: usize) {
+        for entry in self.lines.iter().take(line) {
+            if line < entry.end
+                && let Some(node) = &entry.node
+            {
+                self.folded.remove(node);
+            }
+        }
+    }
+
+    fn foldable_nodes(&self) -> impl Iterator<Item = &String> {
+        (0..self.lines.len())
+            .filter(|&line| self.is_foldable(line))
+            .filter_map(|line| self.lines[line].node.as_ref())
+    }
+
+    /// 有展开的节点时全部折叠，否则全部展开
+    pub fn toggle_all(&mut self) {
+        if self
+            .foldable_nodes()
+            .any(|node| !self.folded.contains(node))
+        {
+            self.folded = self.foldable_nodes().cloned().collect();
+        } else {
+            self.folded.clear();
+        }
+    }
+
+    /// 只展开 spec：折叠其他顶层节点，spec 内全部展开
+    pub fn focus_spec(&mut self) {
+        self.folded = self
+            .foldable_nodes()
+            .filter(|node| !node.contains(['.', '[']) && node.as_str() != "spec")
+            .cloned()
+            .collect();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DEPLOYMENT: &str = "apiVersion: apps/v1
+kind: Deployment
+metadata:
+  annotations:
+    deployment.kubernetes.io/revision: \"3\"
+  name: web
+spec:
+  template:
+    spec:
+      containers:
+      - env:
+        - name: MODE
+          value: prod
+        image: nginx
+        name: web
+      - args:
+        - --port=80
+        command: |
+          run
+          serve
+        name: sidecar
+status:
+  replicas: 2
+";
+
+    fn tree() -> YamlTree {
+        let lines: Vec<String> = DEPLOYMENT.lines().map(str::to_string).collect();
+        let mut tree = YamlTree::default();
+        tree.set_lines(&lines);
+        tree
+    }
+
+    #[test]
+    fn test_paths() {
+        let tree = tree();
+        let path = |line: usize| tree.lines[line].path.as_str();
+        assert_eq!(path(0), "apiVersion");
+        assert_eq!(
+            path(4),
+            "metadata.annotations[\"deployment.kubernetes.io/revision\"]"
+        );
+        assert_eq!(path(10), "spec.template.spec.containers[0].env");
+        assert_eq!(path(11), "spec.template.spec.containers[0].env[0].name");
+        assert_eq!(path(12), "spec.template.spec.containers[0].env[0].value");
+        assert_eq!(path(13), "spec.template.spec.containers[0].image");
+        assert_eq!(path(16), "spec.template.spec.containers[1].args[0]");
+        // 块标量的内容行属于其键
+        assert_eq!(path(18), "spec.template.spec.containers[1].command");
+        assert_eq!(path(22), "status.replicas");
+        assert_eq!(
+            tree.lines[10].node.as_deref(),
+            Some("spec.template.spec.containers[0]")
+        );
+    }
+
+    #[test]
+    fn test_node_extents() {
+        let tree = tree();
+        let end = |line: usize| tree.lines[line].end;
+        assert_eq!(end(2), 6);
+        assert_eq!(end(6), 21);
+        assert_eq!(end(9), 21);
+        // 列表项到下一个同级项为止
+        assert_eq!(end(10), 15);
+        assert_eq!(end(15), 21);
+        assert_eq!(end(17), 20);
+        assert_eq!(end(0), 1);
+        assert!(!tree.is_foldable(0));
+        assert!(tree.is_foldable(17));
+    }
+
+    #[test]
+    fn test_folding() {
+        let mut tree = tree();
+        let total = tree.rows().len();
+        tree.toggle(10);
+        assert_eq!(tree.rows().len(), total - 4);
+        assert_eq!(tree.visible_row(12), 10);
+        assert_eq!(tree.visible_row(15), 11);
+
+        // 展开包含某行的所有祖先节点
+        tree.toggle_all();
+        tree.reveal(12);
+        assert!(tree.rows().contains(&12));
+        assert!(tree.is_folded(15));
+        tree.folded.clear();
+        tree.toggle(10);
+
+        tree.focus_spec();
+        assert_eq!(tree.rows(), (0..3).chain(6..22).collect::<Vec<_>>());
+
+        tree.toggle_all();
+        assert_eq!(tree.rows(), vec![0, 1, 2, 6, 21]);
+        tree.toggle_all();
+        assert!(tree.folded.is_empty());
+    }
+}
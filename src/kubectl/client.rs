@@ -1,9 +1,13 @@
 use anyhow::{Result, anyhow};
+use base64::{Engine, engine::general_purpose::STANDARD};
 use serde_json::Value;
+use std::collections::HashMap;
 use std::time::Duration;
 
 use super::commands;
+use super::quantity::parse_quantity;
 use super::types::*;
+use super::x509::{Certificate, parse_pem_chain};
 
 pub struct KubectlClient {
     // timeout字段保留以备将来使用
@@ -65,6 +69,20 @@ impl KubectlClient {
         Ok(pods)
     }
 
+    pub async fn get_pods_by_selector(&self, namespace: &str, selector: &str) -> Result<Vec<Pod>> {
+        let json_output = commands::get_pods_by_selector(namespace, selector)?;
+        let parsed: Value = serde_json::from_str(&json_output)?;
+
+        let items = parsed["items"]
+            .as_array()
+            .ok_or_else(|| anyhow!("Invalid JSON response: missing items array"))?;
+
+        Ok(items
+            .iter()
+            .filter_map(|item| self.parse_pod(item).ok())
+            .collect())
+    }
+
     pub async fn get_services(&self, namespace: &str) -> Result<Vec<Service>> {
         let json_output = commands::get_services(namespace)?;
         let parsed: Value = serde_json::from_str(&json_output)?;
@@ -248,12 +266,70 @@ impl KubectlClient {
         &self,
         namespace: &str,
         pod_name: &str,
-        lines: u32,
+        options: &LogOptions,
     ) -> Result<Vec<String>> {
-        let logs = commands::get_pod_logs(namespace, pod_name, lines)?;
+        let logs = commands::get_pod_logs(namespace, pod_name, options)?;
         Ok(logs.lines().map(|line| line.to_string()).collect())
     }
 
+    pub async fn get_node_detail(&self, name: &str) -> Result<NodeDetail> {
+        let node: Value = serde_json::from_str(&commands::get_node(name)?)?;
+        let pods: Value = serde_json::from_str(&commands::get_node_pods(name)?)?;
+        parse_node_detail(&node, &pods)
+    }
+
+    pub async fn get_configmap_detail(
+        &self,
+        namespace: &str,
+        name: &str,
+    ) -> Result<ConfigMapDetail> {
+        let configmap: Value = serde_json::from_str(&commands::get_configmap(namespace, name)?)?;
+        parse_configmap_detail(&configmap)
+    }
+
+    pub async fn patch_configmap_key(
+        &self,
+        namespace: &str,
+        name: &str,
+        key: &str,
+        value: &str,
+    ) -> Result<String> {
+        let patch = serde_json::json!({ "data": { key: value } });
+        commands::patch_configmap(namespace, name, &patch.to_string())
+    }
+
+    /// 只修改给出的键：值为解码后的内容，None 表示删除该键
+    pub async fn patch_secret_data(
+        &self,
+        namespace: &str,
+        name: &str,
+        data: &[(String, Option<Vec<u8>>)],
+    ) -> Result<String> {
+        commands::patch_secret(namespace, name, &secret_data_patch(data).to_string())
+    }
+
+    pub async fn get_secret_detail(&self, namespace: &str, name: &str) -> Result<SecretDetail> {
+        let secret: Value = serde_json::from_str(&commands::get_secret(namespace, name)?)?;
+        parse_secret_detail(&secret)
+    }
+
+    pub async fn cordon_node(&self, name: &str) -> Result<String> {
+        commands::cordon_node(name)
+    }
+
+    pub async fn uncordon_node(&self, name: &str) -> Result<String> {
+        commands::uncordon_node(name)
+    }
+
+    pub async fn get_full_pod_logs(
+        &self,
+        namespace: &str,
+        pod_name: &str,
+        options: &LogOptions,
+    ) -> Result<String> {
+        commands::get_full_pod_logs(namespace, pod_name, options)
+    }
+
     pub async fn describe_pod(&self, namespace: &str, pod_name: &str) -> Result<String> {
         commands::describe_pod(namespace, pod_name)
     }
@@ -306,6 +382,20 @@ impl KubectlClient {
         commands::describe_pv(pv_name)
     }
 
+    pub async fn list_container_dir(
+        &self,
+        namespace: &str,
+        pod_name: &str,
+        container: Option<&str>,
+        path: &str,
+    ) -> Result<Vec<RemoteFile>> {
+        let output = commands::list_container_dir(namespace, pod_name, container, path)?;
+        let mut files: Vec<RemoteFile> = output.lines().filter_map(parse_ls_line).collect();
+        // 目录在前，再按名称排序
+        files.sort_by(|a, b| b.is_dir.cmp(&a.is_dir).then_with(|| a.name.cmp(&b.name)));
+        Ok(files)
+    }
+
     #[allow(dead_code)]
     pub async fn delete_pod(&self, namespace: &str, pod_name: &str) -> Result<String> {
         commands::delete_pod(namespace, pod_name)
@@ -362,6 +452,19 @@ impl KubectlClient {
         let node = spec["nodeName"].as_str().map(|s| s.to_string());
         let ip = status["podIP"].as_str().map(|s| s.to_string());
 
+        let containers = spec["containers"]
+            .as_array()
+            .map(|containers| {
+                containers
+                    .iter()
+                    .filter_map(|c| c["name"].as_str())
+                    .map(|s| s.to_string())
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let owner = self.parse_pod_owner(metadata);
+
         Ok(Pod {
             name,
             namespace,
@@ -375,9 +478,27 @@ impl KubectlClient {
             age,
             node,
             ip,
+            containers,
+            owner,
         })
     }
 
+    // 解析 Pod 的 ownerReferences，ReplicaSet 通过 pod-template-hash 折算回 Deployment
+    fn parse_pod_owner(&self, metadata: &Value) -> Option<String> {
+        let owner_ref = metadata["ownerReferences"].as_array()?.first()?;
+        let kind = owner_ref["kind"].as_str()?;
+        let name = owner_ref["name"].as_str()?;
+
+        if kind == "ReplicaSet"
+            && let Some(hash) = metadata["labels"]["pod-template-hash"].as_str()
+            && let Some(deployment) = name.strip_suffix(&format!("-{}", hash))
+        {
+            return Some(format!("Deployment/{}", deployment));
+        }
+
+        Some(format!("{}/{}", kind, name))
+    }
+
     fn parse_service(&self, item: &Value) -> Result<Service> {
         let metadata = &item["metadata"];
         let spec = &item["spec"];
@@ -460,7 +581,7 @@ impl KubectlClient {
     #[allow(dead_code)]
     fn parse_node(&self, item: &Value) -> Result<Node> {
         let metadata = &item["metadata"];
-        let _spec = &item["spec"]; // 用下划线前缀忽略警告
+        let spec = &item["spec"];
         let status = &item["status"];
 
         let name = metadata["name"]
@@ -552,6 +673,7 @@ impl KubectlClient {
             os_image,
             kernel_version,
             container_runtime,
+            unschedulable: spec["unschedulable"].as_bool().unwrap_or(false),
         })
     }
 
@@ -611,12 +733,19 @@ impl KubectlClient {
 
         let age = self.calculate_age(metadata["creationTimestamp"].as_str());
 
+        let tls = (type_ == TLS_SECRET_TYPE).then(|| {
+            tls_certificates(data)
+                .map(|chain| chain[0].clone())
+                .map_err(|e| e.to_string())
+        });
+
         Ok(Secret {
             name,
             namespace,
             type_,
             data_count,
             age,
+            tls,
         })
     }
 
@@ -652,6 +781,7 @@ impl KubectlClient {
             up_to_date,
             available,
             age,
+            selector: label_selector(&spec["selector"]),
         })
     }
 
@@ -706,13 +836,14 @@ impl KubectlClient {
             age,
             duration,
             status: job_status,
+            selector: label_selector(&spec["selector"]),
         })
     }
 
     #[allow(dead_code)]
     fn parse_daemonset(&self, item: &Value) -> Result<DaemonSet> {
         let metadata = &item["metadata"];
-        let _spec = &item["spec"];
+        let spec = &item["spec"];
         let status = &item["status"];
 
         let name = metadata["name"]
@@ -742,6 +873,7 @@ impl KubectlClient {
             up_to_date,
             available,
             age,
+            selector: label_selector(&spec["selector"]),
         })
     }
 
@@ -924,115 +1056,650 @@ impl KubectlClient {
         }
     }
 
-    // 资源监控相关方法
+    // 资源监控相关方法：kubectl top pods --containers 一次调用，按 Pod 分组
     pub async fn get_pod_metrics(&self, namespace: &str) -> Result<Vec<ResourceMetrics>> {
         let output = commands::get_top_pods(namespace)?;
-        let mut metrics = Vec::new();
+        // 百分比基于 Pod spec 中各容器的请求和限制
+        let bounds = match commands::get_pods(namespace) {
+            Ok(json) => container_bounds(&serde_json::from_str(&json)?),
+            Err(_) => HashMap::new(),
+        };
+        Ok(group_pod_metrics(&output, namespace, &bounds))
+    }
 
-        for line in output.lines() {
-            if line.trim().is_empty() {
-                continue;
+    pub async fn get_node_metrics(&self) -> Result<Vec<NodeMetrics>> {
+        let output = commands::get_top_nodes()?;
+        Ok(output.lines().filter_map(parse_node_metrics_line).collect())
+    }
+}
+
+// kubectl top pods --containers --no-headers：POD NAME CPU(cores) MEMORY(bytes)
+fn parse_container_metrics_line(
+    line: &str,
+    bounds: &PodBounds,
+) -> Option<(String, ContainerMetrics)> {
+    let parts: Vec<&str> = line.split_whitespace().collect();
+    if parts.len() < 4 {
+        return None;
+    }
+
+    let pod = parts[0].to_string();
+    let name = parts[1].to_string();
+    let cpu = parts[2].to_string();
+    let memory = parts[3].to_string();
+    let bounds = bounds
+        .get(&pod)
+        .and_then(|containers| containers.get(&name))
+        .copied()
+        .unwrap_or_default();
+
+    let container = ContainerMetrics {
+        usage: UsagePercentages::compute(&cpu, &memory, &bounds),
+        name,
+        cpu,
+        memory,
+        bounds,
+    };
+    Some((pod, container))
+}
+
+// 容器指标按 Pod 汇总，Pod 的用量为各容器之和（与 kubectl top pods 一致的 m / Mi 单位）
+fn group_pod_metrics(output: &str, namespace: &str, bounds: &PodBounds) -> Vec<ResourceMetrics> {
+    let mut pods: Vec<(String, Vec<ContainerMetrics>)> = Vec::new();
+    for (pod, container) in output
+        .lines()
+        .filter_map(|line| parse_container_metrics_line(line, bounds))
+    {
+        match pods.iter_mut().find(|(name, _)| *name == pod) {
+            Some((_, containers)) => containers.push(container),
+            None => pods.push((pod, vec![container])),
+        }
+    }
+
+    let total = |containers: &[ContainerMetrics], usage: fn(&ContainerMetrics) -> &str| {
+        containers
+            .iter()
+            .filter_map(|c| parse_quantity(usage(c)))
+            .sum::<f64>()
+    };
+    pods.into_iter()
+        .map(|(name, containers)| {
+            let cpu = format!("{:.0}m", total(&containers, |c| &c.cpu) * 1000.0);
+            let memory = format!(
+                "{:.0}Mi",
+                total(&containers, |c| &c.memory) / (1024.0 * 1024.0)
+            );
+            let pod_bounds =
+                ResourceBounds::sum(&containers.iter().map(|c| c.bounds).collect::<Vec<_>>());
+            ResourceMetrics {
+                usage: UsagePercentages::compute(&cpu, &memory, &pod_bounds),
+                name,
+                namespace: namespace.to_string(),
+                cpu,
+                memory,
+                bounds: pod_bounds,
+                containers,
             }
+        })
+        .collect()
+}
 
-            if let Ok(metric) = self.parse_pod_metrics_line(line, namespace).await {
-                metrics.push(metric);
+/// Pod 名 → 容器名 → 请求与限制
+type PodBounds = HashMap<String, HashMap<String, ResourceBounds>>;
+
+// 从 kubectl get pods -o json 中读取每个容器的 resources
+fn container_bounds(pods: &Value) -> PodBounds {
+    let quantity = |value: &Value| value.as_str().and_then(parse_quantity);
+    let mut result = PodBounds::new();
+    for pod in pods["items"].as_array().into_iter().flatten() {
+        let Some(name) = pod["metadata"]["name"].as_str() else {
+            continue;
+        };
+        let containers = pod["spec"]["containers"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|container| {
+                let resources = &container["resources"];
+                let bounds = ResourceBounds {
+                    cpu_request: quantity(&resources["requests"]["cpu"]),
+                    cpu_limit: quantity(&resources["limits"]["cpu"]),
+                    memory_request: quantity(&resources["requests"]["memory"]),
+                    memory_limit: quantity(&resources["limits"]["memory"]),
+                };
+                Some((container["name"].as_str()?.to_string(), bounds))
+            })
+            .collect();
+        result.insert(name.to_string(), containers);
+    }
+    result
+}
+
+// kubectl top nodes --no-headers：NAME CPU(cores) CPU% MEMORY(bytes) MEMORY%
+// 刚加入的节点没有指标时显示为 <unknown>
+fn parse_node_metrics_line(line: &str) -> Option<NodeMetrics> {
+    let parts: Vec<&str> = line.split_whitespace().collect();
+    if parts.len() < 5 {
+        return None;
+    }
+    let percent = |value: &str| value.strip_suffix('%').and_then(|v| v.parse::<f64>().ok());
+    Some(NodeMetrics {
+        name: parts[0].to_string(),
+        cpu: parts[1].to_string(),
+        cpu_percentage: percent(parts[2]),
+        memory: parts[3].to_string(),
+        memory_percentage: percent(parts[4]),
+    })
+}
+
+/// 把 LabelSelector（matchLabels + matchExpressions）转换成 kubectl `-l` 的写法
+fn label_selector(selector: &Value) -> String {
+    let mut parts = Vec::new();
+    if let Some(labels) = selector["matchLabels"].as_object() {
+        for (key, value) in labels {
+            parts.push(format!("{}={}", key, value.as_str().unwrap_or_default()));
+        }
+    }
+    if let Some(expressions) = selector["matchExpressions"].as_array() {
+        for expression in expressions {
+            let key = expression["key"].as_str().unwrap_or_default();
+            let values = expression["values"]
+                .as_array()
+                .map(|v| {
+                    v.iter()
+                        .filter_map(|v| v.as_str())
+                        .collect::<Vec<_>>()
+                        .join(",")
+                })
+                .unwrap_or_default();
+            match expression["operator"].as_str() {
+                Some("In") => parts.push(format!("{} in ({})", key, values)),
+                Some("NotIn") => parts.push(format!("{} notin ({})", key, values)),
+                Some("Exists") => parts.push(key.to_string()),
+                Some("DoesNotExist") => parts.push(format!("!{}", key)),
+                _ => {}
             }
         }
+    }
+    parts.join(",")
+}
 
-        Ok(metrics)
+// 按空白切出前 n 列，返回剩余部分（保留文件名中的空格）
+fn split_fields(line: &str, n: usize) -> Option<(Vec<&str>, &str)> {
+    let mut fields = Vec::with_capacity(n);
+    let mut rest = line.trim_start();
+    while fields.len() < n {
+        let end = rest.find(char::is_whitespace)?;
+        fields.push(&rest[..end]);
+        rest = rest[end..].trim_start();
     }
+    Some((fields, rest))
+}
 
-    async fn parse_pod_metrics_line(&self, line: &str, namespace: &str) -> Result<ResourceMetrics> {
-        let parts: Vec<&str> = line.split_whitespace().collect();
-        if parts.len() < 3 {
-            return Err(anyhow!("Invalid metrics line format"));
+// 解析 ls -la 的一行，兼容 GNU coreutils 与 busybox；跳过 total 行以及 . / ..
+fn parse_ls_line(line: &str) -> Option<RemoteFile> {
+    let (mut fields, mut rest) = split_fields(line, 8)?;
+    // 设备文件的大小列是 "major, minor"
+    if fields[4].ends_with(',') {
+        (fields, rest) = split_fields(line, 9)?;
+        fields.remove(4);
+    }
+    let permissions = fields[0];
+    if permissions.len() < 10 {
+        return None;
+    }
+    let (name, link_target) = match rest.split_once(" -> ") {
+        Some((name, target)) if permissions.starts_with('l') => {
+            (name.to_string(), Some(target.to_string()))
         }
+        _ => (rest.to_string(), None),
+    };
+    if name.is_empty() || name == "." || name == ".." {
+        return None;
+    }
+    Some(RemoteFile {
+        name,
+        permissions: permissions.to_string(),
+        size: fields[4].parse().unwrap_or(0),
+        modified: fields[5..8].join(" "),
+        is_dir: permissions.starts_with('d'),
+        link_target,
+    })
+}
 
-        let name = parts[0].to_string();
-        let cpu = parts[1].to_string();
-        let memory = parts[2].to_string();
+// capacity/allocatable/requests/limits 这类资源表
+fn resource_amounts(value: &Value) -> ResourceAmounts {
+    let get = |key: &str| value[key].as_str().and_then(parse_quantity).unwrap_or(0.0);
+    ResourceAmounts {
+        cpu: get("cpu"),
+        memory: get("memory"),
+        pods: get("pods"),
+        ephemeral_storage: get("ephemeral-storage"),
+    }
+}
 
-        // 尝试解析CPU和内存百分比（如果有的话）
-        let cpu_percentage = self.parse_cpu_percentage(&cpu);
-        let memory_percentage = self.parse_memory_percentage(&memory);
+// Pod 的有效请求/限制：普通容器之和与单个 init 容器取大
+fn pod_resources(pod: &Value) -> (ResourceAmounts, ResourceAmounts) {
+    let spec = &pod["spec"];
+    let sum = |field: &str| {
+        let mut total = ResourceAmounts::default();
+        for container in spec["containers"].as_array().into_iter().flatten() {
+            total.add(&resource_amounts(&container["resources"][field]));
+        }
+        for init in spec["initContainers"].as_array().into_iter().flatten() {
+            total = total.max(&resource_amounts(&init["resources"][field]));
+        }
+        total
+    };
+    (sum("requests"), sum("limits"))
+}
 
-        // 获取容器级别的详细信息
-        let containers = self
-            .get_container_metrics(namespace, &name)
-            .await
-            .unwrap_or_default();
+// data 与 binaryData 合并后按键名排序
+fn parse_configmap_detail(configmap: &Value) -> Result<ConfigMapDetail> {
+    let metadata = &configmap["metadata"];
+    let mut entries = Vec::new();
+    if let Some(data) = configmap["data"].as_object() {
+        for (key, value) in data {
+            let value = value.as_str().unwrap_or_default().to_string();
+            entries.push(ConfigMapEntry {
+                key: key.clone(),
+                size: value.len(),
+                value,
+                binary: false,
+            });
+        }
+    }
+    if let Some(data) = configmap["binaryData"].as_object() {
+        for (key, value) in data {
+            let value = value.as_str().unwrap_or_default().to_string();
+            entries.push(ConfigMapEntry {
+                key: key.clone(),
+                size: STANDARD.decode(&value).map_or(0, |bytes| bytes.len()),
+                value,
+                binary: true,
+            });
+        }
+    }
+    entries.sort_by(|a, b| a.key.cmp(&b.key));
 
-        Ok(ResourceMetrics {
-            name,
-            namespace: namespace.to_string(),
-            cpu,
-            memory,
-            cpu_percentage,
-            memory_percentage,
-            containers,
-        })
+    Ok(ConfigMapDetail {
+        name: metadata["name"]
+            .as_str()
+            .ok_or_else(|| anyhow!("Missing configmap name"))?
+            .to_string(),
+        namespace: metadata["namespace"]
+            .as_str()
+            .unwrap_or_default()
+            .to_string(),
+        entries,
+    })
+}
+
+/// data 中 tls.crt 的证书链
+pub fn tls_certificates(data: &Value) -> Result<Vec<Certificate>> {
+    let encoded = data["tls.crt"]
+        .as_str()
+        .ok_or_else(|| anyhow!("Missing tls.crt"))?;
+    let pem = String::from_utf8(STANDARD.decode(encoded)?)?;
+    parse_pem_chain(&pem)
+}
+
+// data 中的值为 base64，按键名排序
+fn parse_secret_detail(secret: &Value) -> Result<SecretDetail> {
+    let metadata = &secret["metadata"];
+    let mut entries = Vec::new();
+    if let Some(data) = secret["data"].as_object() {
+        for (key, value) in data {
+            let value = STANDARD
+                .decode(value.as_str().unwrap_or_default())
+                .map_err(|e| anyhow!("Invalid base64 in key {}: {}", key, e))?;
+            entries.push(SecretEntry {
+                key: key.clone(),
+                value,
+            });
+        }
     }
+    entries.sort_by(|a, b| a.key.cmp(&b.key));
 
-    async fn get_container_metrics(
-        &self,
-        namespace: &str,
-        pod_name: &str,
-    ) -> Result<Vec<crate::kubectl::types::ContainerMetrics>> {
-        let output = commands::get_top_pod(namespace, pod_name)?;
-        let mut containers = Vec::new();
+    Ok(SecretDetail {
+        name: metadata["name"]
+            .as_str()
+            .ok_or_else(|| anyhow!("Missing secret name"))?
+            .to_string(),
+        namespace: metadata["namespace"]
+            .as_str()
+            .unwrap_or_default()
+            .to_string(),
+        type_: secret["type"].as_str().unwrap_or("Opaque").to_string(),
+        entries,
+    })
+}
 
-        for line in output.lines() {
-            if line.trim().is_empty() {
-                continue;
-            }
+// merge patch：值重新编码为 base64，null 删除键
+fn secret_data_patch(data: &[(String, Option<Vec<u8>>)]) -> Value {
+    let data: serde_json::Map<String, Value> = data
+        .iter()
+        .map(|(key, value)| {
+            let value = value
+                .as_ref()
+                .map_or(Value::Null, |bytes| Value::String(STANDARD.encode(bytes)));
+            (key.clone(), value)
+        })
+        .collect();
+    serde_json::json!({ "data": data })
+}
 
-            if let Ok(container) = self.parse_container_metrics_line(line) {
-                containers.push(container);
+fn parse_node_detail(node: &Value, pods: &Value) -> Result<NodeDetail> {
+    let metadata = &node["metadata"];
+    let status = &node["status"];
+    let name = metadata["name"]
+        .as_str()
+        .ok_or_else(|| anyhow!("Missing node name"))?
+        .to_string();
+
+    let conditions = status["conditions"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .map(|condition| NodeCondition {
+            type_: condition["type"].as_str().unwrap_or("").to_string(),
+            status: condition["status"]
+                .as_str()
+                .unwrap_or("Unknown")
+                .to_string(),
+            reason: condition["reason"].as_str().map(|s| s.to_string()),
+            message: condition["message"].as_str().map(|s| s.to_string()),
+        })
+        .collect();
+
+    let taints = node["spec"]["taints"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .map(|taint| {
+            let key = taint["key"].as_str().unwrap_or("");
+            let effect = taint["effect"].as_str().unwrap_or("");
+            match taint["value"].as_str() {
+                Some(value) => format!("{}={}:{}", key, value, effect),
+                None => format!("{}:{}", key, effect),
             }
-        }
+        })
+        .collect();
+
+    let mut labels: Vec<(String, String)> = metadata["labels"]
+        .as_object()
+        .into_iter()
+        .flatten()
+        .map(|(key, value)| (key.clone(), value.as_str().unwrap_or("").to_string()))
+        .collect();
+    labels.sort();
+
+    let mut requests = ResourceAmounts::default();
+    let mut limits = ResourceAmounts::default();
+    let mut node_pods = Vec::new();
+    for pod in pods["items"].as_array().into_iter().flatten() {
+        let (pod_requests, pod_limits) = pod_resources(pod);
+        requests.add(&pod_requests);
+        limits.add(&pod_limits);
+        node_pods.push(NodePod {
+            namespace: pod["metadata"]["namespace"]
+                .as_str()
+                .unwrap_or("")
+                .to_string(),
+            name: pod["metadata"]["name"].as_str().unwrap_or("").to_string(),
+            phase: pod["status"]["phase"]
+                .as_str()
+                .unwrap_or("Unknown")
+                .to_string(),
+            requests: pod_requests,
+            limits: pod_limits,
+        });
+    }
+    requests.pods = node_pods.len() as f64;
+    limits.pods = node_pods.len() as f64;
+    node_pods.sort_by(|a, b| (&a.namespace, &a.name).cmp(&(&b.namespace, &b.name)));
+
+    Ok(NodeDetail {
+        name,
+        capacity: resource_amounts(&status["capacity"]),
+        allocatable: resource_amounts(&status["allocatable"]),
+        requests,
+        limits,
+        conditions,
+        taints,
+        labels,
+        pods: node_pods,
+    })
+}
 
-        Ok(containers)
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_metrics_against_requests_and_limits() {
+        let pods = serde_json::json!({"items": [{
+            "metadata": {"name": "web"},
+            "spec": {"containers": [
+                {"name": "app", "resources": {
+                    "requests": {"cpu": "200m", "memory": "256Mi"},
+                    "limits": {"cpu": "1", "memory": "512Mi"}
+                }},
+                {"name": "sidecar", "resources": {"requests": {"cpu": "50m"}}}
+            ]}
+        }]});
+        let bounds = container_bounds(&pods);
+        let app = bounds["web"]["app"];
+        assert_eq!(app.cpu_request, Some(0.2));
+        assert_eq!(app.memory_limit, Some(536870912.0));
+
+        let usage = UsagePercentages::compute("100m", "480Mi", &app);
+        assert_eq!(usage.cpu_of_request, Some(50.0));
+        assert_eq!(usage.cpu_of_limit, Some(10.0));
+        assert_eq!(usage.memory_of_limit, Some(93.75));
+        assert!(usage.near_memory_limit());
+
+        // sidecar 没有限制，Pod 级限制不存在；请求相加
+        let pod = ResourceBounds::sum(&[app, bounds["web"]["sidecar"]]);
+        assert!((pod.cpu_request.unwrap() - 0.25).abs() < 1e-9);
+        assert_eq!(pod.memory_request, Some(268435456.0));
+        assert_eq!(pod.cpu_limit, None);
+        let usage = UsagePercentages::compute("100m", "100Mi", &pod);
+        assert_eq!(usage.cpu_of_limit, None);
+        assert!(!usage.near_memory_limit());
+        assert_eq!(ResourceBounds::sum(&[]), ResourceBounds::default());
     }
 
-    fn parse_container_metrics_line(
-        &self,
-        line: &str,
-    ) -> Result<crate::kubectl::types::ContainerMetrics> {
-        let parts: Vec<&str> = line.split_whitespace().collect();
-        if parts.len() < 4 {
-            return Err(anyhow!("Invalid container metrics line format"));
-        }
+    #[test]
+    fn test_group_pod_metrics() {
+        let output = "web-1   app       150m   200Mi\n\
+                      web-1   sidecar   5m     56Mi\n\
+                      db-0    postgres  1      1Gi\n\
+                      \n";
+        let mut bounds = PodBounds::new();
+        bounds.entry("web-1".to_string()).or_default().insert(
+            "app".to_string(),
+            ResourceBounds {
+                cpu_request: Some(0.5),
+                ..Default::default()
+            },
+        );
+
+        let pods = group_pod_metrics(output, "default", &bounds);
+        assert_eq!(pods.len(), 2);
+        assert_eq!(pods[0].name, "web-1");
+        assert_eq!(pods[0].namespace, "default");
+        assert_eq!(pods[0].cpu, "155m");
+        assert_eq!(pods[0].memory, "256Mi");
+        assert_eq!(pods[0].containers.len(), 2);
+        assert_eq!(pods[0].containers[0].usage.cpu_of_request, Some(30.0));
+        assert_eq!(pods[1].name, "db-0");
+        assert_eq!(pods[1].cpu, "1000m");
+        assert_eq!(pods[1].memory, "1024Mi");
+    }
 
-        let name = parts[1].to_string(); // 第一列是pod名，第二列是容器名
-        let cpu = parts[2].to_string();
-        let memory = parts[3].to_string();
+    #[test]
+    fn test_parse_configmap_detail() {
+        let configmap = serde_json::json!({
+            "metadata": {"name": "app", "namespace": "prod"},
+            "data": {"config.yaml": "port: 80\n", "LOG_LEVEL": "debug"},
+            "binaryData": {"logo.png": "iVBORw=="}
+        });
+        let detail = parse_configmap_detail(&configmap).unwrap();
+        assert_eq!(detail.namespace, "prod");
+        let keys: Vec<&str> = detail.entries.iter().map(|e| e.key.as_str()).collect();
+        assert_eq!(keys, ["LOG_LEVEL", "config.yaml", "logo.png"]);
+        assert_eq!(detail.entries[1].value, "port: 80\n");
+        assert_eq!(detail.entries[1].size, 9);
+        assert!(detail.entries[2].binary);
+        assert_eq!(detail.entries[2].size, 4);
+    }
 
-        let cpu_percentage = self.parse_cpu_percentage(&cpu);
-        let memory_percentage = self.parse_memory_percentage(&memory);
+    #[test]
+    fn test_parse_secret_detail() {
+        let secret = serde_json::json!({
+            "metadata": {"name": "db", "namespace": "prod"},
+            "type": "Opaque",
+            "data": {"password": "czNjcjN0", "cert.der": "AAH/"}
+        });
+        let detail = parse_secret_detail(&secret).unwrap();
+        assert_eq!(detail.name, "db");
+        assert_eq!(detail.entries[0].key, "cert.der");
+        assert_eq!(detail.entries[0].value, vec![0, 1, 255]);
+        assert_eq!(detail.entries[0].text(), None);
+        assert_eq!(detail.entries[1].text(), Some("s3cr3t"));
+
+        let invalid = serde_json::json!({"metadata": {"name": "x"}, "data": {"k": "!!"}});
+        assert!(parse_secret_detail(&invalid).is_err());
+        let empty = serde_json::json!({"metadata": {"name": "x"}});
+        assert!(parse_secret_detail(&empty).unwrap().entries.is_empty());
+    }
 
-        Ok(crate::kubectl::types::ContainerMetrics {
-            name,
-            cpu,
-            memory,
-            cpu_percentage,
-            memory_percentage,
-        })
+    #[test]
+    fn test_secret_data_patch() {
+        let patch = secret_data_patch(&[
+            ("password".to_string(), Some(b"s3cr3t".to_vec())),
+            ("old".to_string(), None),
+        ]);
+        assert_eq!(
+            patch,
+            serde_json::json!({"data": {"password": "czNjcjN0", "old": null}})
+        );
     }
 
-    fn parse_cpu_percentage(&self, cpu_str: &str) -> Option<f64> {
-        if cpu_str.ends_with('m') {
-            cpu_str
-                .trim_end_matches('m')
-                .parse::<f64>()
-                .ok()
-                .map(|v| v / 10.0) // 毫核转换为百分比
-        } else {
-            cpu_str.parse::<f64>().ok().map(|v| v * 100.0) // 核转换为百分比
-        }
+    #[test]
+    fn test_parse_node_metrics_line() {
+        let metrics = parse_node_metrics_line("worker-1   250m   6%   2048Mi   27%").unwrap();
+        assert_eq!(metrics.name, "worker-1");
+        assert_eq!(metrics.cpu, "250m");
+        assert_eq!(metrics.cpu_percentage, Some(6.0));
+        assert_eq!(metrics.memory_percentage, Some(27.0));
+
+        let unknown =
+            parse_node_metrics_line("worker-2 <unknown> <unknown> <unknown> <unknown>").unwrap();
+        assert_eq!(unknown.cpu_percentage, None);
+        assert!(parse_node_metrics_line("").is_none());
+    }
+
+    #[test]
+    fn test_parse_node_detail() {
+        let node = serde_json::json!({
+            "metadata": {"name": "worker-1", "labels": {"zone": "a", "arch": "amd64"}},
+            "spec": {"taints": [
+                {"key": "dedicated", "value": "gpu", "effect": "NoSchedule"},
+                {"key": "node.kubernetes.io/unschedulable", "effect": "NoSchedule"}
+            ]},
+            "status": {
+                "capacity": {"cpu": "4", "memory": "16Gi", "pods": "110", "ephemeral-storage": "100Gi"},
+                "allocatable": {"cpu": "3800m", "memory": "15Gi", "pods": "110"},
+                "conditions": [
+                    {"type": "MemoryPressure", "status": "False"},
+                    {"type": "Ready", "status": "True", "reason": "KubeletReady"}
+                ]
+            }
+        });
+        let pods = serde_json::json!({"items": [
+            {
+                "metadata": {"namespace": "prod", "name": "web"},
+                "spec": {
+                    "containers": [
+                        {"resources": {"requests": {"cpu": "250m", "memory": "256Mi"}, "limits": {"memory": "512Mi"}}},
+                        {"resources": {"requests": {"cpu": "250m"}}}
+                    ],
+                    "initContainers": [{"resources": {"requests": {"cpu": "1"}}}]
+                },
+                "status": {"phase": "Running"}
+            },
+            {
+                "metadata": {"namespace": "default", "name": "api"},
+                "spec": {"containers": [{"resources": {}}]},
+                "status": {"phase": "Pending"}
+            }
+        ]});
+
+        let detail = parse_node_detail(&node, &pods).unwrap();
+        assert_eq!(detail.name, "worker-1");
+        assert_eq!(detail.capacity.cpu, 4.0);
+        assert_eq!(detail.allocatable.cpu, 3.8);
+        assert_eq!(detail.allocatable.ephemeral_storage, 0.0);
+        // init 容器的 1 核大于普通容器之和 500m
+        assert_eq!(detail.requests.cpu, 1.0);
+        assert_eq!(detail.requests.memory, 268435456.0);
+        assert_eq!(detail.limits.memory, 536870912.0);
+        assert_eq!(detail.requests.pods, 2.0);
+        assert_eq!(
+            detail.taints,
+            vec![
+                "dedicated=gpu:NoSchedule".to_string(),
+                "node.kubernetes.io/unschedulable:NoSchedule".to_string()
+            ]
+        );
+        assert_eq!(detail.labels[0], ("arch".to_string(), "amd64".to_string()));
+        assert_eq!(detail.pods[0].name, "api");
+        assert!(!detail.conditions[0].is_problem());
+        assert!(!detail.conditions[1].is_problem());
     }
 
-    fn parse_memory_percentage(&self, _memory_str: &str) -> Option<f64> {
-        // 这里需要根据实际的Pod容限来计算百分比，这里暂时返回None
-        None
+    #[test]
+    fn test_parse_ls_line() {
+        // total 行以及 . / .. 不作为条目
+        assert!(parse_ls_line("total 48").is_none());
+        assert!(
+            parse_ls_line("drwxr-xr-x    1 root     root          4096 Jan  1 00:00 .").is_none()
+        );
+        assert!(parse_ls_line("drwxr-xr-x 1 root root 4096 Apr  5 10:00 ..").is_none());
+
+        // GNU coreutils 单空格分隔，权限后带 SELinux 的 "."
+        let file = parse_ls_line("-rw-r--r--. 1 root root 220 Apr  5  2024 .bashrc").unwrap();
+        assert_eq!(file.name, ".bashrc");
+        assert_eq!(file.permissions, "-rw-r--r--.");
+        assert_eq!(file.size, 220);
+        assert_eq!(file.modified, "Apr 5 2024");
+        assert!(!file.is_dir);
+        assert!(file.link_target.is_none());
+
+        // busybox 按列对齐，权限后带 ACL 的 "+"
+        let dir =
+            parse_ls_line("drwxr-xr-x+   2 root     root          4096 Jan  1 00:00 bin").unwrap();
+        assert_eq!(dir.name, "bin");
+        assert_eq!(dir.permissions, "drwxr-xr-x+");
+        assert_eq!(dir.size, 4096);
+        assert_eq!(dir.modified, "Jan 1 00:00");
+        assert!(dir.is_dir);
+
+        // 设备文件的 "major, minor" 不能吞掉日期列
+        let device =
+            parse_ls_line("crw-rw-rw-    1 root     root        1,   3 Jan  1 00:00 null").unwrap();
+        assert_eq!(device.name, "null");
+        assert_eq!(device.modified, "Jan 1 00:00");
+
+        let link = parse_ls_line("lrwxrwxrwx 1 root root 7 Apr  5 10:00 lib -> usr/lib").unwrap();
+        assert_eq!(link.name, "lib");
+        assert_eq!(link.link_target.as_deref(), Some("usr/lib"));
+
+        // 文件名中的空格原样保留
+        let spaced = parse_ls_line("-rw-r--r-- 1 app app 12 Apr  5 10:00 my  report.txt").unwrap();
+        assert_eq!(spaced.name, "my  report.txt");
+        assert!(spaced.link_target.is_none());
     }
 }
@@ -1,7 +1,10 @@
 use anyhow::{Result, anyhow};
-use std::process::Command;
+use std::process::{Child, Command, Stdio};
 use std::sync::OnceLock;
 
+use super::temp_file::PrivateTempFile;
+use super::types::{DrainOptions, LogOptions};
+
 // 全局的 kubectl 命令类型检测器
 static KUBECTL_CMD: OnceLock<KubectlCommand> = OnceLock::new();
 
@@ -52,6 +55,25 @@ fn execute_kubectl(args: &[&str]) -> Result<String> {
     String::from_utf8(output.stdout).map_err(|e| anyhow!("Invalid UTF-8 output: {}", e))
 }
 
+// 启动长时间运行的 kubectl 进程（如 logs -f），stdout/stderr 通过管道读取
+pub fn spawn_kubectl(args: &[&str]) -> Result<Child> {
+    let mut command = match get_kubectl_command() {
+        KubectlCommand::Direct => Command::new("kubectl"),
+        KubectlCommand::Minikube => {
+            let mut command = Command::new("minikube");
+            command.args(["kubectl", "--"]);
+            command
+        }
+    };
+    command
+        .args(args)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| anyhow!("Failed to spawn kubectl: {}", e))
+}
+
 pub fn get_namespaces() -> Result<Vec<String>> {
     let output = execute_kubectl(&["get", "namespaces", "-o", "name"])?;
 
@@ -67,6 +89,10 @@ pub fn get_pods(namespace: &str) -> Result<String> {
     execute_kubectl(&["get", "pods", "-n", namespace, "-o", "json"])
 }
 
+pub fn get_pods_by_selector(namespace: &str, selector: &str) -> Result<String> {
+    execute_kubectl(&["get", "pods", "-n", namespace, "-l", selector, "-o", "json"])
+}
+
 pub fn get_services(namespace: &str) -> Result<String> {
     execute_kubectl(&["get", "services", "-n", namespace, "-o", "json"])
 }
@@ -86,17 +112,143 @@ pub fn get_secrets(namespace: &str) -> Result<String> {
     execute_kubectl(&["get", "secrets", "-n", namespace, "-o", "json"])
 }
 
-pub fn get_pod_logs(namespace: &str, pod_name: &str, lines: u32) -> Result<String> {
+pub fn get_configmap(namespace: &str, name: &str) -> Result<String> {
+    execute_kubectl(&["get", "configmap", name, "-n", namespace, "-o", "json"])
+}
+
+// merge patch 只修改 patch 中出现的键；patch 通过临时文件传入，
+// 不受单个命令行参数 128 KiB 的长度限制，也不会出现在进程参数里
+fn merge_patch(kind: &str, namespace: &str, name: &str, patch: &str) -> Result<String> {
+    let file = PrivateTempFile::new(&format!("{}-patch.json", name), patch)?;
+    let path_arg = file.path().to_string_lossy().to_string();
     execute_kubectl(&[
-        "logs",
+        "patch",
+        kind,
+        name,
         "-n",
         namespace,
-        pod_name,
-        "--tail",
-        &lines.to_string(),
+        "--type",
+        "merge",
+        "--patch-file",
+        &path_arg,
+    ])
+}
+
+// ConfigMap 的值最大 1 MiB
+pub fn patch_configmap(namespace: &str, name: &str, patch: &str) -> Result<String> {
+    merge_patch("configmap", namespace, name, patch)
+}
+
+pub fn get_secret(namespace: &str, name: &str) -> Result<String> {
+    execute_kubectl(&["get", "secret", name, "-n", namespace, "-o", "json"])
+}
+
+// patch 中是 Secret 的值，临时文件仅当前用户可读
+pub fn patch_secret(namespace: &str, name: &str, patch: &str) -> Result<String> {
+    merge_patch("secret", namespace, name, patch)
+}
+
+pub fn get_pod_logs(namespace: &str, pod_name: &str, options: &LogOptions) -> Result<String> {
+    let args = log_args(namespace, pod_name, options, false);
+    execute_kubectl(&args.iter().map(String::as_str).collect::<Vec<_>>())
+}
+
+// 不带 --tail 的完整日志，用于导出
+pub fn get_full_pod_logs(namespace: &str, pod_name: &str, options: &LogOptions) -> Result<String> {
+    let mut args = log_args(namespace, pod_name, options, false);
+    args.truncate(args.len() - 2);
+    execute_kubectl(&args.iter().map(String::as_str).collect::<Vec<_>>())
+}
+
+pub fn get_node(name: &str) -> Result<String> {
+    execute_kubectl(&["get", "node", name, "-o", "json"])
+}
+
+// 节点上未结束的 Pod，与 kubectl describe node 的统计口径一致
+pub fn get_node_pods(name: &str) -> Result<String> {
+    let selector = format!(
+        "spec.nodeName={},status.phase!=Succeeded,status.phase!=Failed",
+        name
+    );
+    execute_kubectl(&[
+        "get",
+        "pods",
+        "--all-namespaces",
+        "--field-selector",
+        &selector,
+        "-o",
+        "json",
     ])
 }
 
+pub fn cordon_node(name: &str) -> Result<String> {
+    execute_kubectl(&["cordon", name])
+}
+
+pub fn uncordon_node(name: &str) -> Result<String> {
+    execute_kubectl(&["uncordon", name])
+}
+
+// kubectl drain 参数，由 NodeDrain 在后台进程中执行
+pub fn drain_args(name: &str, options: &DrainOptions) -> Vec<String> {
+    let mut args = vec!["drain".to_string(), name.to_string()];
+    if options.ignore_daemonsets {
+        args.push("--ignore-daemonsets".to_string());
+    }
+    if options.delete_emptydir_data {
+        args.push("--delete-emptydir-data".to_string());
+    }
+    if let Some(grace) = options.grace_period {
+        args.push(format!("--grace-period={}", grace));
+    }
+    if !options.timeout.is_empty() {
+        args.push(format!("--timeout={}", options.timeout));
+    }
+    args
+}
+
+// kubectl logs 参数；since 可以是相对时长（--since）或 RFC3339 时间（--since-time）
+pub fn log_args(
+    namespace: &str,
+    pod_name: &str,
+    options: &LogOptions,
+    follow: bool,
+) -> Vec<String> {
+    let mut args = vec![
+        "logs".to_string(),
+        "-n".to_string(),
+        namespace.to_string(),
+        pod_name.to_string(),
+    ];
+    if follow {
+        args.push("-f".to_string());
+    }
+    if options.all_containers {
+        args.push("--all-containers".to_string());
+        args.push("--prefix".to_string());
+    } else if let Some(container) = &options.container {
+        args.push("-c".to_string());
+        args.push(container.clone());
+    }
+    if options.previous {
+        args.push("--previous".to_string());
+    }
+    if !options.since.is_empty() {
+        if chrono::DateTime::parse_from_rfc3339(&options.since).is_ok() {
+            args.push(format!("--since-time={}", options.since));
+        } else {
+            args.push(format!("--since={}", options.since));
+        }
+    }
+    if options.timestamps {
+        args.push("--timestamps".to_string());
+    }
+    // --tail 固定放在最后，LogStream 续接时会去掉它
+    args.push("--tail".to_string());
+    args.push(options.tail.to_string());
+    args
+}
+
 #[allow(dead_code)]
 pub fn describe_pod(namespace: &str, pod_name: &str) -> Result<String> {
     execute_kubectl(&["describe", "pod", "-n", namespace, pod_name])
@@ -311,6 +463,100 @@ pub fn exec_pod(namespace: &str, pod_name: &str, command: &str) -> Result<()> {
     Ok(())
 }
 
+// 交互式 exec 命令（交给本地 sh -c 执行）
+// command 为 None 时按 shells 顺序先用 `-c true` 非交互地探测，exec 进入第一个能启动的 shell；
+// 不按交互会话的退出码回退，否则会话中最后一条命令不存在（127）时退出后会再进入下一个 shell。
+// command 原样放在 `--` 之后，由本地 shell 负责拆分参数，因此也适用于没有 shell 的 distroless 镜像
+pub fn build_exec_command(
+    namespace: &str,
+    pod_name: &str,
+    container: Option<&str>,
+    command: Option<&str>,
+    shells: &[String],
+) -> String {
+    let mut target = format!("-n {} {}", namespace, pod_name);
+    if let Some(container) = container {
+        target.push_str(&format!(" -c {}", container));
+    }
+
+    match command {
+        Some(command) => format!("kubectl exec -it {} -- {}", target, command),
+        None => {
+            let shells = shells
+                .iter()
+                .map(|s| shell_quote(s))
+                .collect::<Vec<_>>()
+                .join(" ");
+            format!(
+                "for s in {0}; do kubectl exec {1} -- \"$s\" -c true >/dev/null 2>&1 && exec kubectl exec -it {1} -- \"$s\"; done; echo 'No usable shell found in container' >&2; exit 127",
+                shells, target
+            )
+        }
+    }
+}
+
+// 交互式 attach 命令，连接到容器主进程的 stdin/stdout
+pub fn build_attach_command(namespace: &str, pod_name: &str, container: Option<&str>) -> String {
+    let mut cmd = format!("kubectl attach -it -n {} {}", namespace, pod_name);
+    if let Some(container) = container {
+        cmd.push_str(&format!(" -c {}", container));
+    }
+    cmd
+}
+
+// 单引号转义，用于拼接 sh -c 命令
+pub fn shell_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "'\\''"))
+}
+
+// 列出容器内目录（目录路径以 / 结尾，符号链接目录也能列出内容）
+pub fn list_container_dir(
+    namespace: &str,
+    pod_name: &str,
+    container: Option<&str>,
+    path: &str,
+) -> Result<String> {
+    let dir = format!("{}/", path.trim_end_matches('/'));
+    let mut args = vec!["exec", "-n", namespace, pod_name];
+    if let Some(container) = container {
+        args.extend(["-c", container]);
+    }
+    args.extend(["--", "ls", "-la", dir.as_str()]);
+    execute_kubectl(&args)
+}
+
+// kubectl cp 下载：容器内路径 -> 本地路径
+pub fn copy_from_pod(
+    namespace: &str,
+    pod_name: &str,
+    container: Option<&str>,
+    remote_path: &str,
+    local_path: &str,
+) -> Result<String> {
+    let source = format!("{}/{}:{}", namespace, pod_name, remote_path);
+    let mut args = vec!["cp", source.as_str(), local_path];
+    if let Some(container) = container {
+        args.extend(["-c", container]);
+    }
+    execute_kubectl(&args)
+}
+
+// kubectl cp 上传：本地路径 -> 容器内路径
+pub fn copy_to_pod(
+    namespace: &str,
+    pod_name: &str,
+    container: Option<&str>,
+    local_path: &str,
+    remote_path: &str,
+) -> Result<String> {
+    let target = format!("{}/{}:{}", namespace, pod_name, remote_path);
+    let mut args = vec!["cp", local_path, target.as_str()];
+    if let Some(container) = container {
+        args.extend(["-c", container]);
+    }
+    execute_kubectl(&args)
+}
+
 // YAML配置相关命令
 pub fn get_pod_yaml(namespace: &str, pod_name: &str) -> Result<String> {
     execute_kubectl(&["get", "pod", "-n", namespace, pod_name, "-o", "yaml"])
@@ -384,23 +630,13 @@ pub fn get_pv_yaml(pv_name: &str) -> Result<String> {
     execute_kubectl(&["get", "pv", pv_name, "-o", "yaml"])
 }
 
-// 资源监控相关命令
+// 资源监控相关命令：一次取回命名空间内所有容器的指标，按 Pod 汇总在 client 中完成
 pub fn get_top_pods(namespace: &str) -> Result<String> {
-    execute_kubectl(&["top", "pods", "-n", namespace, "--no-headers"]).map_err(|e| {
-        anyhow!(
-            "kubectl top failed: {}. Note: metrics-server might not be installed.",
-            e
-        )
-    })
-}
-
-pub fn get_top_pod(namespace: &str, pod_name: &str) -> Result<String> {
     execute_kubectl(&[
         "top",
-        "pod",
+        "pods",
         "-n",
         namespace,
-        pod_name,
         "--containers",
         "--no-headers",
     ])
@@ -411,3 +647,12 @@ pub fn get_top_pod(namespace: &str, pod_name: &str) -> Result<String> {
         )
     })
 }
+
+pub fn get_top_nodes() -> Result<String> {
+    execute_kubectl(&["top", "nodes", "--no-headers"]).map_err(|e| {
+        anyhow!(
+            "kubectl top failed: {}. Note: metrics-server might not be installed.",
+            e
+        )
+    })
+}
@@ -1,5 +1,8 @@
 pub mod client;
 pub mod commands;
+pub mod quantity;
+pub mod temp_file;
 pub mod types;
+pub mod x509;
 
 pub use client::KubectlClient;
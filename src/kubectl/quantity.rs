@@ -0,0 +1,109 @@
+//! Kubernetes 资源数量（Quantity）的解析与格式化
+
+const BINARY_SUFFIXES: [(&str, f64); 6] = [
+    ("Ki", 1024.0),
+    ("Mi", 1048576.0),
+    ("Gi", 1073741824.0),
+    ("Ti", 1099511627776.0),
+    ("Pi", 1125899906842624.0),
+    ("Ei", 1152921504606846976.0),
+];
+
+/// 十进制后缀对应的 10 的幂
+const DECIMAL_SUFFIXES: [(&str, i32); 9] = [
+    ("n", -9),
+    ("u", -6),
+    ("m", -3),
+    ("k", 3),
+    ("M", 6),
+    ("G", 9),
+    ("T", 12),
+    ("P", 15),
+    ("E", 18),
+];
+
+/// 解析为基本单位：CPU 为核，内存和存储为字节，其它为个数
+///
+/// 支持 `250m`、`1.5`、`128Mi`、`1G`、`1e3` 以及 metrics 接口的 `12345n`
+pub fn parse_quantity(value: &str) -> Option<f64> {
+    let value = value.trim();
+    for (suffix, factor) in BINARY_SUFFIXES {
+        if let Some(number) = value.strip_suffix(suffix) {
+            return number.parse::<f64>().ok().map(|n| n * factor);
+        }
+    }
+    for (suffix, exponent) in DECIMAL_SUFFIXES {
+        if let Some(number) = value.strip_suffix(suffix) {
+            // 负幂用除法，避免 3800m 变成 3.8000000000000003
+            let scale = 10f64.powi(exponent.abs());
+            return number
+                .parse::<f64>()
+                .ok()
+                .map(|n| if exponent < 0 { n / scale } else { n * scale });
+        }
+    }
+    value.parse::<f64>().ok()
+}
+
+/// CPU 核数显示为 kubectl 风格：整数核显示为 `2`，否则为毫核 `250m`
+pub fn format_cpu(cores: f64) -> String {
+    let millis = (cores * 1000.0).round() as i64;
+    if millis % 1000 == 0 {
+        format!("{}", millis / 1000)
+    } else {
+        format!("{}m", millis)
+    }
+}
+
+/// 字节数显示为二进制单位，如 `512Mi`、`1.5Gi`
+pub fn format_bytes(bytes: f64) -> String {
+    for (suffix, factor) in BINARY_SUFFIXES.iter().rev() {
+        if bytes >= *factor {
+            let value = bytes / factor;
+            return if value.fract().abs() < 0.05 {
+                format!("{:.0}{}", value, suffix)
+            } else {
+                format!("{:.1}{}", value, suffix)
+            };
+        }
+    }
+    format!("{:.0}", bytes)
+}
+
+/// used / total 的百分比，total 为 0 时没有意义
+pub fn percentage(used: f64, total: f64) -> Option<f64> {
+    (total > 0.0).then(|| used / total * 100.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_quantity() {
+        assert_eq!(parse_quantity("250m"), Some(0.25));
+        assert_eq!(parse_quantity("2"), Some(2.0));
+        assert_eq!(parse_quantity("1.5"), Some(1.5));
+        assert_eq!(parse_quantity("128Mi"), Some(134217728.0));
+        assert_eq!(parse_quantity("1Gi"), Some(1073741824.0));
+        assert_eq!(parse_quantity("1G"), Some(1e9));
+        assert_eq!(parse_quantity("100k"), Some(1e5));
+        assert_eq!(parse_quantity("1e3"), Some(1000.0));
+        assert!((parse_quantity("1500000n").unwrap() - 0.0015).abs() < 1e-12);
+        assert_eq!(parse_quantity("110"), Some(110.0));
+        assert_eq!(parse_quantity(""), None);
+        assert_eq!(parse_quantity("abc"), None);
+    }
+
+    #[test]
+    fn test_format_quantity() {
+        assert_eq!(format_cpu(0.25), "250m");
+        assert_eq!(format_cpu(4.0), "4");
+        assert_eq!(format_cpu(1.5), "1500m");
+        assert_eq!(format_bytes(134217728.0), "128Mi");
+        assert_eq!(format_bytes(1610612736.0), "1.5Gi");
+        assert_eq!(format_bytes(512.0), "512");
+        assert_eq!(percentage(1.0, 4.0), Some(25.0));
+        assert_eq!(percentage(1.0, 0.0), None);
+    }
+}
@@ -0,0 +1,117 @@
+//! Private temporary files for decoded Secret values and patches
+
+use std::collections::hash_map::RandomState;
+use std::fs::{self, OpenOptions};
+use std::hash::{BuildHasher, Hasher};
+use std::io::{self, ErrorKind, Write};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// 名称冲突时重试的次数
+const CREATE_ATTEMPTS: usize = 16;
+
+/// 仅当前用户可读写的临时文件；名称带随机后缀并以 O_EXCL 新建，
+/// 不会写入预先放好的同名文件或符号链接。drop 时删除
+#[derive(Debug)]
+pub struct PrivateTempFile {
+    path: PathBuf,
+}
+
+/// 每个进程的 RandomState 使用随机密钥，其他用户无法预测
+fn random_suffix() -> u64 {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_nanos());
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write_u128(nanos);
+    hasher.finish()
+}
+
+/// 文件名只保留安全字符，扩展名留在末尾便于编辑器识别语法
+fn safe_name(file_name: &str) -> String {
+    file_name
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '_') {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+impl PrivateTempFile {
+    pub fn new(file_name: &str, text: &str) -> io::Result<Self> {
+        let name = safe_name(file_name);
+        let mut attempt = 0;
+        loop {
+            let path = std::env::temp_dir().join(format!(
+                "kube-tui-{}-{:016x}-{}",
+                std::process::id(),
+                random_suffix(),
+                name
+            ));
+            let mut options = OpenOptions::new();
+            options.write(true).create_new(true);
+            #[cfg(unix)]
+            std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+            match options.open(&path) {
+                Ok(mut file) => {
+                    // 先接管路径，写入失败时同样删除
+                    let temp = Self { path };
+                    file.write_all(text.as_bytes())?;
+                    return Ok(temp);
+                }
+                Err(e) if e.kind() == ErrorKind::AlreadyExists && attempt < CREATE_ATTEMPTS => {
+                    attempt += 1;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn read(&self) -> io::Result<String> {
+        fs::read_to_string(&self.path)
+    }
+}
+
+impl Drop for PrivateTempFile {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unique_names_keep_extension() {
+        let first = PrivateTempFile::new("app config/settings.yaml", "a").unwrap();
+        let second = PrivateTempFile::new("app config/settings.yaml", "b").unwrap();
+        assert_ne!(first.path(), second.path());
+        let name = first.path().file_name().unwrap().to_str().unwrap();
+        assert!(name.starts_with("kube-tui-"));
+        assert!(name.ends_with("-app_config_settings.yaml"));
+        assert_eq!(second.read().unwrap(), "b");
+
+        let path = first.path().to_path_buf();
+        drop(first);
+        assert!(!path.exists());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_private_mode() {
+        use std::os::unix::fs::PermissionsExt;
+        let file = PrivateTempFile::new("private-test.txt", "secret").unwrap();
+        let mode = fs::metadata(file.path()).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+        assert_eq!(file.read().unwrap(), "secret");
+    }
+}
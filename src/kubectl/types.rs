@@ -1,6 +1,28 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
+use super::x509::Certificate;
+
+/// ConfigMap 中的一个键；binaryData 中的值保持 base64，不可编辑
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConfigMapEntry {
+    pub key: String,
+    pub value: String,
+    pub binary: bool,
+    /// 字节数（binaryData 为解码后的大小）
+    pub size: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConfigMapDetail {
+    pub name: String,
+    pub namespace: String,
+    pub entries: Vec<ConfigMapEntry>,
+}
+
+/// 存放 TLS 证书与私钥的 Secret 类型
+pub const TLS_SECRET_TYPE: &str = "kubernetes.io/tls";
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Pod {
     pub name: String,
@@ -11,6 +33,20 @@ pub struct Pod {
     pub age: String,
     pub node: Option<String>,
     pub ip: Option<String>,
+    pub containers: Vec<String>,
+    /// 所属工作负载，形如 `Deployment/web`（ReplicaSet 已折算为 Deployment）
+    pub owner: Option<String>,
+}
+
+impl Pod {
+    /// 工作负载标识，用于按工作负载记忆用户选择（没有 owner 时退化为 Pod 名）
+    pub fn workload_key(&self) -> String {
+        format!(
+            "{}/{}",
+            self.namespace,
+            self.owner.as_deref().unwrap_or(&self.name)
+        )
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -102,6 +138,81 @@ pub struct Node {
     pub os_image: Option<String>,
     pub kernel_version: Option<String>,
     pub container_runtime: Option<String>,
+    /// 已 cordon（spec.unschedulable）
+    pub unschedulable: bool,
+}
+
+/// 节点资源量：CPU 为核，内存和临时存储为字节
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct ResourceAmounts {
+    pub cpu: f64,
+    pub memory: f64,
+    pub pods: f64,
+    pub ephemeral_storage: f64,
+}
+
+impl ResourceAmounts {
+    pub fn add(&mut self, other: &ResourceAmounts) {
+        self.cpu += other.cpu;
+        self.memory += other.memory;
+        self.pods += other.pods;
+        self.ephemeral_storage += other.ephemeral_storage;
+    }
+
+    /// 逐项取较大值（init 容器与普通容器之和取大）
+    pub fn max(&self, other: &ResourceAmounts) -> ResourceAmounts {
+        ResourceAmounts {
+            cpu: self.cpu.max(other.cpu),
+            memory: self.memory.max(other.memory),
+            pods: self.pods.max(other.pods),
+            ephemeral_storage: self.ephemeral_storage.max(other.ephemeral_storage),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NodeCondition {
+    pub type_: String,
+    pub status: String,
+    pub reason: Option<String>,
+    pub message: Option<String>,
+}
+
+impl NodeCondition {
+    /// Ready 以外的条件（MemoryPressure 等）为 True 时表示异常
+    pub fn is_problem(&self) -> bool {
+        if self.type_ == "Ready" {
+            self.status != "True"
+        } else {
+            self.status == "True"
+        }
+    }
+}
+
+/// 调度在节点上的 Pod 及其资源请求/限制
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NodePod {
+    pub namespace: String,
+    pub name: String,
+    pub phase: String,
+    pub requests: ResourceAmounts,
+    pub limits: ResourceAmounts,
+}
+
+/// 节点容量与分配情况（对应 kubectl describe node 的 Allocated resources）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NodeDetail {
+    pub name: String,
+    pub capacity: ResourceAmounts,
+    pub allocatable: ResourceAmounts,
+    /// 节点上所有未结束 Pod 的请求之和，pods 为 Pod 数
+    pub requests: ResourceAmounts,
+    pub limits: ResourceAmounts,
+    pub conditions: Vec<NodeCondition>,
+    /// `key=value:Effect`
+    pub taints: Vec<String>,
+    pub labels: Vec<(String, String)>,
+    pub pods: Vec<NodePod>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -119,6 +230,34 @@ pub struct Secret {
     pub type_: String,
     pub data_count: usize,
     pub age: String,
+    /// kubernetes.io/tls 类型的叶子证书，解析失败时为错误信息
+    pub tls: Option<std::result::Result<Certificate, String>>,
+}
+
+/// Secret 中的一个键，值为 base64 解码后的原始字节
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SecretEntry {
+    pub key: String,
+    pub value: Vec<u8>,
+}
+
+impl SecretEntry {
+    /// UTF-8 且不含除换行、制表符以外的控制字符时视为文本
+    pub fn text(&self) -> Option<&str> {
+        std::str::from_utf8(&self.value).ok().filter(|text| {
+            !text
+                .chars()
+                .any(|c| c.is_control() && !matches!(c, '\n' | '\r' | '\t'))
+        })
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SecretDetail {
+    pub name: String,
+    pub namespace: String,
+    pub type_: String,
+    pub entries: Vec<SecretEntry>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -129,6 +268,8 @@ pub struct Deployment {
     pub up_to_date: u32,
     pub available: u32,
     pub age: String,
+    /// spec.selector 转换成的 `-l` 标签选择器
+    pub selector: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -140,6 +281,7 @@ pub struct Job {
     pub age: String,
     pub duration: Option<String>,
     pub status: String,
+    pub selector: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -152,6 +294,7 @@ pub struct DaemonSet {
     pub up_to_date: u32,
     pub available: u32,
     pub age: String,
+    pub selector: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -189,8 +332,9 @@ pub struct ResourceMetrics {
     pub namespace: String,
     pub cpu: String,
     pub memory: String,
-    pub cpu_percentage: Option<f64>,
-    pub memory_percentage: Option<f64>,
+    /// 各容器请求之和；只有所有容器都设置了限制时才有 Pod 级限制
+    pub bounds: ResourceBounds,
+    pub usage: UsagePercentages,
     pub containers: Vec<ContainerMetrics>,
 }
 
@@ -199,9 +343,160 @@ pub struct ContainerMetrics {
     pub name: String,
     pub cpu: String,
     pub memory: String,
-    pub cpu_percentage: Option<f64>,
-    pub memory_percentage: Option<f64>,
+    pub bounds: ResourceBounds,
+    pub usage: UsagePercentages,
+}
+
+/// 容器 spec 中的请求与限制：CPU 为核，内存为字节，未设置为 None
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct ResourceBounds {
+    pub cpu_request: Option<f64>,
+    pub cpu_limit: Option<f64>,
+    pub memory_request: Option<f64>,
+    pub memory_limit: Option<f64>,
+}
+
+impl ResourceBounds {
+    /// Pod 级汇总：设置了的请求相加，任一容器没有限制则 Pod 没有限制
+    pub fn sum(bounds: &[ResourceBounds]) -> ResourceBounds {
+        if bounds.is_empty() {
+            return ResourceBounds::default();
+        }
+        type Field = fn(&ResourceBounds) -> Option<f64>;
+        let requests = |field: Field| bounds.iter().filter_map(field).reduce(|a, b| a + b);
+        let limits = |field: Field| bounds.iter().map(field).sum::<Option<f64>>();
+        ResourceBounds {
+            cpu_request: requests(|b| b.cpu_request),
+            cpu_limit: limits(|b| b.cpu_limit),
+            memory_request: requests(|b| b.memory_request),
+            memory_limit: limits(|b| b.memory_limit),
+        }
+    }
+}
+
+/// 近内存限制的阈值，超过后 OOMKill 风险较高
+pub const MEMORY_LIMIT_WARNING: f64 = 90.0;
+
+/// 使用量相对请求/限制的百分比
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct UsagePercentages {
+    pub cpu_of_request: Option<f64>,
+    pub cpu_of_limit: Option<f64>,
+    pub memory_of_request: Option<f64>,
+    pub memory_of_limit: Option<f64>,
+}
+
+impl UsagePercentages {
+    pub fn compute(cpu: &str, memory: &str, bounds: &ResourceBounds) -> Self {
+        use super::quantity::{parse_quantity, percentage};
+        let ratio = |usage: &str, total: Option<f64>| percentage(parse_quantity(usage)?, total?);
+        Self {
+            cpu_of_request: ratio(cpu, bounds.cpu_request),
+            cpu_of_limit: ratio(cpu, bounds.cpu_limit),
+            memory_of_request: ratio(memory, bounds.memory_request),
+            memory_of_limit: ratio(memory, bounds.memory_limit),
+        }
+    }
+
+    pub fn near_memory_limit(&self) -> bool {
+        self.memory_of_limit
+            .is_some_and(|p| p >= MEMORY_LIMIT_WARNING)
+    }
 }
 
 // 类型别名
 pub type PodMetrics = ResourceMetrics;
+
+/// kubectl top nodes 的一行，百分比相对节点的可分配量
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NodeMetrics {
+    pub name: String,
+    pub cpu: String,
+    pub cpu_percentage: Option<f64>,
+    pub memory: String,
+    pub memory_percentage: Option<f64>,
+}
+
+// 容器内文件（ls -la 的一行）
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RemoteFile {
+    pub name: String,
+    pub permissions: String,
+    pub size: u64,
+    pub modified: String,
+    pub is_dir: bool,
+    /// 符号链接的目标
+    pub link_target: Option<String>,
+}
+
+// kubectl drain 的选项
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DrainOptions {
+    pub ignore_daemonsets: bool,
+    pub delete_emptydir_data: bool,
+    /// None 时使用各 Pod 自己的 terminationGracePeriodSeconds
+    pub grace_period: Option<i64>,
+    /// 如 `5m`，空表示一直等待
+    pub timeout: String,
+}
+
+impl Default for DrainOptions {
+    fn default() -> Self {
+        Self {
+            ignore_daemonsets: true,
+            delete_emptydir_data: false,
+            grace_period: None,
+            timeout: "5m".to_string(),
+        }
+    }
+}
+
+// kubectl logs 的来源选项（会话内有效）
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LogOptions {
+    /// None 时使用 Pod 的默认容器
+    pub container: Option<String>,
+    /// 所有容器，并以 `[pod/container]` 作为行前缀
+    pub all_containers: bool,
+    pub previous: bool,
+    /// 相对时长（如 `5m`）或 RFC3339 时间
+    pub since: String,
+    pub timestamps: bool,
+    pub tail: u32,
+}
+
+impl Default for LogOptions {
+    fn default() -> Self {
+        Self {
+            container: None,
+            all_containers: false,
+            previous: false,
+            since: String::new(),
+            timestamps: false,
+            tail: 100,
+        }
+    }
+}
+
+impl LogOptions {
+    /// 标题中显示的非默认选项
+    pub fn summary(&self) -> String {
+        let mut parts = Vec::new();
+        if self.all_containers {
+            parts.push("all containers".to_string());
+        } else if let Some(container) = &self.container {
+            parts.push(format!("-c {}", container));
+        }
+        if self.previous {
+            parts.push("--previous".to_string());
+        }
+        if !self.since.is_empty() {
+            parts.push(format!("since {}", self.since));
+        }
+        if self.timestamps {
+            parts.push("--timestamps".to_string());
+        }
+        parts.push(format!("--tail {}", self.tail));
+        parts.join(" ")
+    }
+}
@@ -0,0 +1,350 @@
+//! Minimal X.509 reader for kubernetes.io/tls secrets (subject, issuer, SANs, validity)
+
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use anyhow::{Result, anyhow, bail};
+use base64::{Engine, engine::general_purpose::STANDARD};
+use chrono::{DateTime, NaiveDateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// 剩余天数不超过该值时标红
+pub const EXPIRY_CRITICAL_DAYS: i64 = 7;
+/// 剩余天数不超过该值时标黄
+pub const EXPIRY_WARNING_DAYS: i64 = 30;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Certificate {
+    /// `CN=web.example.com, O=Example`
+    pub subject: String,
+    pub issuer: String,
+    /// `DNS:…`、`IP:…`、`email:…`
+    pub sans: Vec<String>,
+    pub not_before: DateTime<Utc>,
+    pub not_after: DateTime<Utc>,
+}
+
+impl Certificate {
+    /// 距过期的天数，已过期为负数
+    pub fn days_to_expiry(&self, now: DateTime<Utc>) -> i64 {
+        (self.not_after - now).num_days()
+    }
+}
+
+/// 解析 PEM 中的所有证书（tls.crt 通常为证书链，叶子证书在前）
+pub fn parse_pem_chain(pem: &str) -> Result<Vec<Certificate>> {
+    const BEGIN: &str = "-----BEGIN CERTIFICATE-----";
+    const END: &str = "-----END CERTIFICATE-----";
+
+    let mut certificates = Vec::new();
+    let mut rest = pem;
+    while let Some(start) = rest.find(BEGIN) {
+        let body = &rest[start + BEGIN.len()..];
+        let end = body
+            .find(END)
+            .ok_or_else(|| anyhow!("Unterminated PEM certificate"))?;
+        let encoded: String = body[..end].split_whitespace().collect();
+        let der = STANDARD.decode(encoded)?;
+        certificates.push(parse_der(&der)?);
+        rest = &body[end + END.len()..];
+    }
+    if certificates.is_empty() {
+        bail!("No PEM certificate found");
+    }
+    Ok(certificates)
+}
+
+/// DER 编码的 TLV 读取器
+struct Der<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> Der<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Self { data }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    fn peek_tag(&self) -> Option<u8> {
+        self.data.first().copied()
+    }
+
+    fn next(&mut self) -> Result<(u8, &'a [u8])> {
+        let (&tag, rest) = self
+            .data
+            .split_first()
+            .ok_or_else(|| anyhow!("Unexpected end of DER data"))?;
+        let (&first, rest) = rest
+            .split_first()
+            .ok_or_else(|| anyhow!("Missing DER length"))?;
+        // 长格式：低 7 位为长度字节数
+        let (len, rest) = if first & 0x80 == 0 {
+            (first as usize, rest)
+        } else {
+            let count = (first & 0x7f) as usize;
+            if count == 0 || count > 4 || rest.len() < count {
+                bail!("Invalid DER length");
+            }
+            let len = rest[..count]
+                .iter()
+                .fold(0usize, |len, &b| (len << 8) | b as usize);
+            (len, &rest[count..])
+        };
+        if rest.len() < len {
+            bail!("DER value exceeds input");
+        }
+        self.data = &rest[len..];
+        Ok((tag, &rest[..len]))
+    }
+
+    fn expect(&mut self, tag: u8) -> Result<&'a [u8]> {
+        let (actual, value) = self.next()?;
+        if actual != tag {
+            bail!("Expected DER tag {:#04x}, found {:#04x}", tag, actual);
+        }
+        Ok(value)
+    }
+}
+
+const SEQUENCE: u8 = 0x30;
+const SET: u8 = 0x31;
+const OID: u8 = 0x06;
+const OCTET_STRING: u8 = 0x04;
+const BOOLEAN: u8 = 0x01;
+const UTC_TIME: u8 = 0x17;
+const GENERALIZED_TIME: u8 = 0x18;
+const VERSION: u8 = 0xa0;
+const EXTENSIONS: u8 = 0xa3;
+const SUBJECT_ALT_NAME: &str = "2.5.29.17";
+
+fn parse_der(der: &[u8]) -> Result<Certificate> {
+    let mut certificate = Der::new(Der::new(der).expect(SEQUENCE)?);
+    let mut tbs = Der::new(certificate.expect(SEQUENCE)?);
+
+    if tbs.peek_tag() == Some(VERSION) {
+        tbs.next()?;
+    }
+    tbs.next()?; // serialNumber
+    tbs.expect(SEQUENCE)?; // signature
+    let issuer = parse_name(tbs.expect(SEQUENCE)?)?;
+    let mut validity = Der::new(tbs.expect(SEQUENCE)?);
+    let not_before = parse_time(validity.next()?)?;
+    let not_after = parse_time(validity.next()?)?;
+    let subject = parse_name(tbs.expect(SEQUENCE)?)?;
+    tbs.expect(SEQUENCE)?; // subjectPublicKeyInfo
+
+    // issuerUniqueID [1]、subjectUniqueID [2] 跳过，只读取 extensions [3]
+    let mut sans = Vec::new();
+    while !tbs.is_empty() {
+        let (tag, value) = tbs.next()?;
+        if tag == EXTENSIONS {
+            sans = parse_sans(Der::new(value).expect(SEQUENCE)?)?;
+        }
+    }
+
+    Ok(Certificate {
+        subject,
+        issuer,
+        sans,
+        not_before,
+        not_after,
+    })
+}
+
+fn parse_oid(bytes: &[u8]) -> String {
+    let Some((&first, rest)) = bytes.split_first() else {
+        return String::new();
+    };
+    // 第一个字节编码前两段：40 * X + Y，X 最大为 2
+    let mut arcs = if first < 80 {
+        vec![(first / 40) as u64, (first % 40) as u64]
+    } else {
+        vec![2, (first - 80) as u64]
+    };
+    let mut value = 0u64;
+    for &b in rest {
+        value = (value << 7) | (b & 0x7f) as u64;
+        if b & 0x80 == 0 {
+            arcs.push(value);
+            value = 0;
+        }
+    }
+    arcs.iter()
+        .map(u64::to_string)
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+fn attribute_name(oid: &str) -> &str {
+    match oid {
+        "2.5.4.3" => "CN",
+        "2.5.4.6" => "C",
+        "2.5.4.7" => "L",
+        "2.5.4.8" => "ST",
+        "2.5.4.10" => "O",
+        "2.5.4.11" => "OU",
+        "1.2.840.113549.1.9.1" => "emailAddress",
+        other => other,
+    }
+}
+
+/// Name ::= SEQUENCE OF SET OF AttributeTypeAndValue，格式化为 `CN=…, O=…`
+fn parse_name(name: &[u8]) -> Result<String> {
+    let mut parts = Vec::new();
+    let mut rdns = Der::new(name);
+    while !rdns.is_empty() {
+        let mut attributes = Der::new(rdns.expect(SET)?);
+        while !attributes.is_empty() {
+            let mut attribute = Der::new(attributes.expect(SEQUENCE)?);
+            let oid = parse_oid(attribute.expect(OID)?);
+            let (_, value) = attribute.next()?;
+            parts.push(format!(
+                "{}={}",
+                attribute_name(&oid),
+                String::from_utf8_lossy(value)
+            ));
+        }
+    }
+    Ok(parts.join(", "))
+}
+
+fn parse_time((tag, value): (u8, &[u8])) -> Result<DateTime<Utc>> {
+    let text = std::str::from_utf8(value)?;
+    let text = text.strip_suffix('Z').unwrap_or(text);
+    let full = match tag {
+        // UTCTime 两位年份：50–99 为 19xx，其余为 20xx
+        UTC_TIME => {
+            let year = text.get(..2).ok_or_else(|| anyhow!("Invalid UTCTime"))?;
+            let century = if year.parse::<u32>()? >= 50 {
+                "19"
+            } else {
+                "20"
+            };
+            format!("{}{}", century, text)
+        }
+        GENERALIZED_TIME => text.to_string(),
+        _ => bail!("Unexpected time tag {:#04x}", tag),
+    };
+    Ok(NaiveDateTime::parse_from_str(&full, "%Y%m%d%H%M%S")?.and_utc())
+}
+
+fn parse_sans(extensions: &[u8]) -> Result<Vec<String>> {
+    let mut extensions = Der::new(extensions);
+    while !extensions.is_empty() {
+        let mut extension = Der::new(extensions.expect(SEQUENCE)?);
+        let oid = parse_oid(extension.expect(OID)?);
+        if extension.peek_tag() == Some(BOOLEAN) {
+            extension.next()?; // critical
+        }
+        if oid != SUBJECT_ALT_NAME {
+            continue;
+        }
+
+        let value = extension.expect(OCTET_STRING)?;
+        let mut names = Der::new(Der::new(value).expect(SEQUENCE)?);
+        let mut sans = Vec::new();
+        while !names.is_empty() {
+            let (tag, value) = names.next()?;
+            match tag {
+                0x81 => sans.push(format!("email:{}", String::from_utf8_lossy(value))),
+                0x82 => sans.push(format!("DNS:{}", String::from_utf8_lossy(value))),
+                0x86 => sans.push(format!("URI:{}", String::from_utf8_lossy(value))),
+                0x87 => {
+                    if let Ok(octets) = <[u8; 4]>::try_from(value) {
+                        sans.push(format!("IP:{}", Ipv4Addr::from(octets)));
+                    } else if let Ok(octets) = <[u8; 16]>::try_from(value) {
+                        sans.push(format!("IP:{}", Ipv6Addr::from(octets)));
+                    }
+                }
+                _ => {}
+            }
+        }
+        return Ok(sans);
+    }
+    Ok(Vec::new())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    // openssl 生成的 EC 证书链：web.example.com 由 Example CA 签发
+    const CHAIN: &str = "-----BEGIN CERTIFICATE-----
+MIIByTCCAW6gAwIBAgIUAijSN0/08JCQ1zRHPHUomKeISSIwCgYIKoZIzj0EAwIw
+JzETMBEGA1UEAwwKRXhhbXBsZSBDQTEQMA4GA1UECgwHRXhhbXBsZTAeFw0yNjAx
+MDEwMDAwMDBaFw0yNjEyMDExMjAwMDBaMCwxGDAWBgNVBAMMD3dlYi5leGFtcGxl
+LmNvbTEQMA4GA1UECgwHRXhhbXBsZTBZMBMGByqGSM49AgEGCCqGSM49AwEHA0IA
+BP5BOR1J0QS5bGEjO2LCs+BzSNx7c2C1Oam06s1EM+7EYivHEEGqwRgRESr1ykna
+Mnttf9qnfgghoyN3Oux5NPijczBxMC8GA1UdEQQoMCaCD3dlYi5leGFtcGxlLmNv
+bYINKi5leGFtcGxlLmNvbYcECgAAATAdBgNVHQ4EFgQU3ZLPmG2kMtl3QNNuTft7
+SCdPPnUwHwYDVR0jBBgwFoAUcot+EPDLKj4rvo+ctyI8QBsY5nIwCgYIKoZIzj0E
+AwIDSQAwRgIhAJ1TcUkJnF19Q8wbEjqKgVnj5IsdVSTkAPpThcrtQbwwAiEAr7c2
+7fANCJrV739r9yS43hZxJWKrCOBFpvlMS9DyKDc=
+-----END CERTIFICATE-----
+-----BEGIN CERTIFICATE-----
+MIIBozCCAUmgAwIBAgIUX7jSJk6Tuf1j9cXU42WQCMLtKWwwCgYIKoZIzj0EAwIw
+JzETMBEGA1UEAwwKRXhhbXBsZSBDQTEQMA4GA1UECgwHRXhhbXBsZTAeFw0yNTAx
+MDEwMDAwMDBaFw0zNTAxMDEwMDAwMDBaMCcxEzARBgNVBAMMCkV4YW1wbGUgQ0Ex
+EDAOBgNVBAoMB0V4YW1wbGUwWTATBgcqhkjOPQIBBggqhkjOPQMBBwNCAATq1/T6
+eFzQ9YHTleJK/oLPC9QyFAxm9ah5sEwr9jaLd0d17BsUAn0R/JKcum7JXAi22GQ6
+ctmz/WNIxGYCBDYXo1MwUTAdBgNVHQ4EFgQUcot+EPDLKj4rvo+ctyI8QBsY5nIw
+HwYDVR0jBBgwFoAUcot+EPDLKj4rvo+ctyI8QBsY5nIwDwYDVR0TAQH/BAUwAwEB
+/zAKBggqhkjOPQQDAgNIADBFAiEA0+y3IUCVbP2uCM51bmE6Y9V2PCNYFy9MrR74
+25j2AmQCIHs/z4XeOlYjDmcgRuvF7T9NBWauPp2ymJ86xI9IcFYc
+-----END CERTIFICATE-----
+";
+
+    #[test]
+    fn test_parse_pem_chain() {
+        let chain = parse_pem_chain(CHAIN).unwrap();
+        assert_eq!(chain.len(), 2);
+
+        let leaf = &chain[0];
+        assert_eq!(leaf.subject, "CN=web.example.com, O=Example");
+        assert_eq!(leaf.issuer, "CN=Example CA, O=Example");
+        assert_eq!(
+            leaf.sans,
+            vec!["DNS:web.example.com", "DNS:*.example.com", "IP:10.0.0.1"]
+        );
+        assert_eq!(
+            leaf.not_before,
+            Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap()
+        );
+        assert_eq!(
+            leaf.not_after,
+            Utc.with_ymd_and_hms(2026, 12, 1, 12, 0, 0).unwrap()
+        );
+        let now = Utc.with_ymd_and_hms(2026, 11, 1, 12, 0, 0).unwrap();
+        assert_eq!(leaf.days_to_expiry(now), 30);
+
+        let ca = &chain[1];
+        assert_eq!(ca.subject, ca.issuer);
+        assert!(ca.sans.is_empty());
+        assert_eq!(
+            ca.not_after,
+            Utc.with_ymd_and_hms(2035, 1, 1, 0, 0, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_invalid_pem() {
+        assert!(parse_pem_chain("not a certificate").is_err());
+        assert!(parse_pem_chain("-----BEGIN CERTIFICATE-----\nAAAA").is_err());
+        assert!(
+            parse_pem_chain("-----BEGIN CERTIFICATE-----\nMAA=\n-----END CERTIFICATE-----")
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_parse_oid() {
+        assert_eq!(parse_oid(&[0x55, 0x1d, 0x11]), SUBJECT_ALT_NAME);
+        assert_eq!(
+            parse_oid(&[0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x09, 0x01]),
+            "1.2.840.113549.1.9.1"
+        );
+    }
+}
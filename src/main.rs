@@ -4,6 +4,15 @@ mod kubectl;
 mod ui;
 
 use anyhow::Result;
+use app::audit::{AuditLog, audit_path};
+use app::config::UserConfig;
+use app::detail_lifecycle::EditableView;
+use app::editor;
+use app::file_browser::{FileTransfer, TransferStatus, local_size, parent_remote};
+use app::log_stream::LogStream;
+use app::node_drain::{NodeAction, NodeDrain};
+use app::save::{SaveKind, expand_path, write_file};
+use app::terminal::TerminalSession;
 use app::{AppMode, AppState};
 use crossterm::{
     event::Event,
@@ -12,7 +21,10 @@ use crossterm::{
     terminal::{EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode},
 };
 use kubectl::KubectlClient;
+use kubectl::types::LogOptions;
 use ratatui::{Terminal, backend::CrosstermBackend};
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
 use std::{io, time::Duration};
 
 #[tokio::main]
@@ -55,6 +67,8 @@ async fn main() -> Result<()> {
 
     // Create app state
     let mut app = AppState::new();
+    app.user_config = UserConfig::load().unwrap_or_default();
+    app.audit_log = AuditLog::new(audit_path());
 
     // Load initial data
     if let Err(e) = load_initial_data(&mut app, &client).await {
@@ -64,6 +78,24 @@ async fn main() -> Result<()> {
     // Main loop
     let result = run_app(&mut terminal, &mut app, &client).await;
 
+    // 结束仍在运行的终端会话
+    for session in &app.terminal_sessions {
+        session.kill();
+    }
+    if let Some(stream) = app.log_stream.take() {
+        stream.stop();
+    }
+    if let Some(mut view) = app.multi_log.take() {
+        view.stop();
+    }
+    // 退出时不留下后台运行的 kubectl drain
+    if let Some(drain) = app.node_drain.take() {
+        drain.cancel();
+    }
+    // 写完已收到的日志再停止捕获
+    app.log_captures.poll();
+    app.log_captures.stop_all();
+
     // Restore terminal
     disable_raw_mode()?;
     execute!(
@@ -96,7 +128,7 @@ async fn load_initial_data(app: &mut AppState, client: &KubectlClient) -> Result
 
     // Load pods for default namespace
     if let Ok(pods) = client.get_pods(&app.current_namespace).await {
-        app.pods = pods;
+        app.set_pods(pods);
     }
 
     // Load services for default namespace
@@ -128,6 +160,25 @@ async fn manage_mouse_capture(
     Ok(())
 }
 
+// 暂时退出 TUI，阻塞运行 $EDITOR，返回后恢复终端；外层 Result 为终端操作的错误
+fn run_editor(
+    terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
+    text: &str,
+    file_name: &str,
+) -> Result<Result<String>> {
+    disable_raw_mode()?;
+    execute!(
+        terminal.backend_mut(),
+        LeaveAlternateScreen,
+        DisableMouseCapture
+    )?;
+    let edited = editor::edit(text, file_name);
+    enable_raw_mode()?;
+    execute!(terminal.backend_mut(), EnterAlternateScreen)?;
+    terminal.clear()?;
+    Ok(edited)
+}
+
 async fn run_app(
     terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
     app: &mut AppState,
@@ -135,10 +186,23 @@ async fn run_app(
 ) -> Result<()> {
     loop {
         // Render UI
+        // 终端视图下让伪终端跟随窗口大小
+        if app.mode == AppMode::Terminal
+            && let Some(session) = app.active_terminal_session()
+        {
+            let (rows, cols) = ui::terminal_pane_size(terminal.get_frame().area());
+            session.resize(rows, cols);
+        }
+
         terminal.draw(|f| ui::render_ui(f, app))?;
 
-        // Handle events
-        if let Some(event) = events::poll_events(Duration::from_millis(100))? {
+        // Handle events（终端视图缩短轮询间隔，保证回显及时）
+        let poll_timeout = if app.mode == AppMode::Terminal {
+            Duration::from_millis(20)
+        } else {
+            Duration::from_millis(100)
+        };
+        if let Some(event) = events::poll_events(poll_timeout)? {
             match event {
                 Event::Key(key_event) => {
                     app.handle_key_event(key_event)?;
@@ -146,22 +210,46 @@ async fn run_app(
                     // 管理鼠标捕获状态（在键盘事件处理后检查模式变化或M键切换）
                     manage_mouse_capture(terminal, app).await?;
 
-                    // 处理待执行的exec命令
-                    if let Some(exec_cmd) = app.pending_exec.take() {
-                        execute_external_command(&exec_cmd, terminal).await?;
-                        app.clear_current_command();
-
-                        // exec后保留已有数据，只刷新Pod列表
-                        app.mode = AppMode::PodList;
-                        app.previous_mode = AppMode::PodList;
-
-                        // 只增量刷新Pod列表
-                        if let Ok(pods) = client.get_pods(&app.current_namespace).await {
-                            app.pods = pods;
+                    // 在内嵌伪终端中启动待执行的 exec/attach 会话
+                    if let Some(request) = app.pending_exec.take() {
+                        let (rows, cols) = ui::terminal_pane_size(terminal.get_frame().area());
+                        match TerminalSession::spawn(&request.title, &request.command, rows, cols) {
+                            Ok(session) => {
+                                app.clear_current_command();
+                                app.add_terminal_session(session);
+                            }
+                            Err(e) => {
+                                app.set_current_command(&format!("{} ({})", request.command, e))
+                            }
                         }
+                        // 保存本次 exec 选择（容器/命令）
+                        let _ = app.user_config.save();
+                        manage_mouse_capture(terminal, app).await?;
+                    }
+
+                    // 让出终端，在编辑器中修改 ConfigMap 的一个键
+                    if let Some(view) = app.configmap_view.as_mut()
+                        && let Some(key) = view.lifecycle.pending_edit.take()
+                    {
+                        let value = view
+                            .selected_entry()
+                            .map(|entry| entry.value.clone())
+                            .unwrap_or_default();
+                        let edited = run_editor(terminal, &value, &key)?;
+                        view.set_edited(&key, edited);
+                        app.mouse_capture_enabled = false;
+                        manage_mouse_capture(terminal, app).await?;
+                    }
 
-                        app.refresh_data();
-                        terminal.draw(|f| ui::render_ui(f, app))?;
+                    // Secret 以解码后的文本编辑，保存后重新编码
+                    if let Some(view) = app.secret_view.as_mut()
+                        && view.lifecycle.pending_edit.take().is_some()
+                    {
+                        let file_name = format!("{}-secret.yaml", view.name);
+                        let edited = run_editor(terminal, &view.edit_text(), &file_name)?;
+                        view.set_edited(edited);
+                        app.mouse_capture_enabled = false;
+                        manage_mouse_capture(terminal, app).await?;
                     }
 
                     // Handle mode changes that require data loading
@@ -185,7 +273,7 @@ async fn run_app(
                         AppMode::PodList => {
                             if app.pods.is_empty() || app.should_refresh() {
                                 if let Ok(pods) = client.get_pods(&app.current_namespace).await {
-                                    app.pods = pods;
+                                    app.set_pods(pods);
                                     app.refresh_data();
                                 }
                             }
@@ -269,26 +357,35 @@ async fn run_app(
                                 if app.logs.is_empty() || app.should_refresh() {
                                     let pod_name = pod.name.clone();
                                     let namespace = app.current_namespace.clone();
-                                    app.set_current_command(&format!(
-                                        "kubectl logs -f -n {} {} --tail=100",
-                                        namespace, pod_name
-                                    ));
-                                    if let Ok(logs) =
-                                        client.get_pod_logs(&namespace, &pod_name, 100).await
-                                    {
-                                        app.logs = logs;
-                                        // 如果开启了自动滚动，滚动到最新位置
-                                        if app.logs_auto_scroll {
-                                            app.logs_scroll = app.logs.len().saturating_sub(1);
+                                    // 跟随模式下由 kubectl logs -f 进程追加日志
+                                    if !app.streaming_logs {
+                                        let options = app.log_options.clone();
+                                        app.set_current_command(&format!(
+                                            "kubectl logs -n {} {} {}",
+                                            namespace,
+                                            pod_name,
+                                            options.summary()
+                                        ));
+                                        if let Ok(logs) = client
+                                            .get_pod_logs(&namespace, &pod_name, &options)
+                                            .await
+                                        {
+                                            // 如果开启了自动滚动，滚动到最新位置
+                                            app.set_logs(logs);
                                         }
+                                        app.clear_current_command();
                                     }
-                                    app.clear_current_command();
                                     // Load split pane logs if in split mode
                                     if app.split_log_mode && !app.split_log_pod_name.is_empty() {
                                         let split_ns = app.current_namespace.clone();
                                         let split_name = app.split_log_pod_name.clone();
-                                        if let Ok(split_logs) =
-                                            client.get_pod_logs(&split_ns, &split_name, 100).await
+                                        if let Ok(split_logs) = client
+                                            .get_pod_logs(
+                                                &split_ns,
+                                                &split_name,
+                                                &LogOptions::default(),
+                                            )
+                                            .await
                                         {
                                             app.split_log_content = split_logs;
                                         }
@@ -526,20 +623,7 @@ async fn run_app(
                                 }
                             }
                         }
-                        AppMode::TopView => {
-                            if app.pod_metrics.is_empty() || app.should_refresh() {
-                                let namespace = app.current_namespace.clone();
-                                app.set_current_command(&format!(
-                                    "kubectl top pods -n {}",
-                                    namespace
-                                ));
-                                if let Ok(metrics) = client.get_pod_metrics(&namespace).await {
-                                    app.pod_metrics = metrics;
-                                    app.refresh_data();
-                                }
-                                app.clear_current_command();
-                            }
-                        }
+                        AppMode::TopView => update_top_view(app, client).await,
                         _ => {}
                     }
                 }
@@ -553,6 +637,20 @@ async fn run_app(
             }
         }
 
+        update_log_stream(app);
+        update_multi_log(app, client).await;
+        update_log_captures(app, client).await;
+        run_node_action(app, client).await;
+        update_node_detail(app, client).await;
+        update_secret_view(app, client).await;
+        update_configmap_view(app, client).await;
+        save_to_file(app, client).await;
+
+        // 文件浏览器：加载目录、启动/跟踪 kubectl cp
+        if app.mode == AppMode::FileBrowser {
+            update_file_browser(app, client).await;
+        }
+
         // Auto-refresh data
         if app.should_refresh() {
             match app.mode {
@@ -572,7 +670,7 @@ async fn run_app(
                         app.current_namespace
                     ));
                     if let Ok(pods) = client.get_pods(&app.current_namespace).await {
-                        app.pods = pods;
+                        app.set_pods(pods);
                     }
                     app.clear_current_command();
                 }
@@ -656,21 +754,20 @@ async fn run_app(
                 AppMode::Logs => {
                     // 日志自动刷新
                     if let Some(pod) = app.get_selected_pod() {
-                        if let Ok(logs) = client
-                            .get_pod_logs(&app.current_namespace, &pod.name, 100)
-                            .await
+                        if !app.streaming_logs
+                            && let Ok(logs) = client
+                                .get_pod_logs(&app.current_namespace, &pod.name, &app.log_options)
+                                .await
                         {
-                            app.logs = logs;
-                            if app.logs_auto_scroll {
-                                app.logs_scroll = app.logs.len().saturating_sub(1);
-                            }
+                            app.set_logs(logs);
                         }
                         // Load split pane logs
                         if app.split_log_mode && !app.split_log_pod_name.is_empty() {
                             let split_ns = app.current_namespace.clone();
                             let split_name = app.split_log_pod_name.clone();
-                            if let Ok(split_logs) =
-                                client.get_pod_logs(&split_ns, &split_name, 100).await
+                            if let Ok(split_logs) = client
+                                .get_pod_logs(&split_ns, &split_name, &LogOptions::default())
+                                .await
                             {
                                 app.split_log_content = split_logs;
                             }
@@ -678,6 +775,8 @@ async fn run_app(
                     }
                     app.refresh_logs();
                 }
+                // 每个刷新周期拉取一次指标，趋势图和统计才会累积
+                AppMode::TopView => update_top_view(app, client).await,
                 _ => {}
             }
             app.refresh_data();
@@ -688,15 +787,15 @@ async fn run_app(
             if let Some(pod) = app.get_selected_pod() {
                 let pod_name = pod.name.clone();
                 let namespace = app.current_namespace.clone();
+                let options = app.log_options.clone();
                 app.set_current_command(&format!(
-                    "kubectl logs -f -n {} {} --tail=100 (auto-refresh)",
-                    namespace, pod_name
+                    "kubectl logs -n {} {} {} (auto-refresh)",
+                    namespace,
+                    pod_name,
+                    options.summary()
                 ));
-                if let Ok(logs) = client.get_pod_logs(&namespace, &pod_name, 100).await {
-                    app.logs = logs;
-                    if app.logs_auto_scroll {
-                        app.logs_scroll = app.logs.len().saturating_sub(1);
-                    }
+                if let Ok(logs) = client.get_pod_logs(&namespace, &pod_name, &options).await {
+                    app.set_logs(logs);
                 }
                 app.clear_current_command();
             }
@@ -969,7 +1068,7 @@ async fn run_app(
                         app.current_namespace
                     ));
                     if let Ok(pods) = client.get_pods(&app.current_namespace).await {
-                        app.pods = pods;
+                        app.set_pods(pods);
                     }
                     app.clear_current_command();
                 }
@@ -1070,66 +1169,405 @@ async fn run_app(
     Ok(())
 }
 
-// 执行外部命令（如kubectl exec）
-async fn execute_external_command(
-    command: &str,
-    terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
-) -> Result<()> {
-    // 退出TUI模式
-    disable_raw_mode()?;
-    execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
-    terminal.show_cursor()?;
+// 日志跟随：按当前视图启动/停止 kubectl logs -f，并取走新收到的行
+fn update_log_stream(app: &mut AppState) {
+    let wanted = if app.mode == AppMode::Logs && app.streaming_logs {
+        app.get_selected_pod().map(|pod| pod.name.clone())
+    } else {
+        None
+    };
+    let target = wanted.map(|pod| (app.current_namespace.clone(), pod, app.log_options.clone()));
+    let current = app
+        .log_stream
+        .as_ref()
+        .map(|s| (s.namespace.clone(), s.pod_name.clone(), s.options.clone()));
 
-    // 执行命令
-    println!("Executing: {}", command);
+    if current != target {
+        if let Some(stream) = app.log_stream.take() {
+            stream.stop();
+        }
+        if let Some((namespace, pod_name, options)) = target {
+            app.clear_logs();
+            app.logs_scroll = 0;
+            app.logs_new_while_paused = 0;
+            app.log_stream = Some(LogStream::start(&namespace, &pod_name, options));
+        }
+    }
 
-    let status = std::process::Command::new("sh")
-        .arg("-c")
-        .arg(command)
-        .status();
+    if let Some(stream) = &app.log_stream {
+        let lines = stream.take_lines();
+        app.append_logs(lines);
+    }
+}
 
-    let is_success = match &status {
-        Ok(exit_status) => exit_status.success(),
-        Err(_) => false,
+// 聚合日志：定期按选择器发现 Pod 并收集各跟随进程的输出，离开视图后全部停止
+async fn update_multi_log(app: &mut AppState, client: &KubectlClient) {
+    if app.mode != AppMode::MultiLogs {
+        if let Some(mut view) = app.multi_log.take() {
+            view.stop();
+        }
+        return;
+    }
+    let Some(view) = app.multi_log.as_mut() else {
+        return;
     };
 
-    match status {
-        Ok(exit_status) => {
-            if exit_status.success() {
-                println!("Command executed successfully.");
-            } else {
-                println!("Command failed with exit code: {:?}", exit_status.code());
+    if view.should_discover() {
+        match client
+            .get_pods_by_selector(&view.namespace, &view.selector)
+            .await
+        {
+            Ok(pods) => {
+                let names: Vec<String> = pods.into_iter().map(|pod| pod.name).collect();
+                view.sync_pods(&names);
+            }
+            Err(e) => {
+                view.last_discovery = Some(Instant::now());
+                view.error = Some(e.to_string());
+            }
+        }
+    }
+    view.poll();
+}
+
+// 后台日志捕获：写入新日志，定期检查被捕获的 Pod 是否已被替换
+async fn update_log_captures(app: &mut AppState, client: &KubectlClient) {
+    let captures = &mut app.log_captures;
+    captures.poll();
+    if !captures.should_discover() {
+        return;
+    }
+    captures.last_discovery = Some(Instant::now());
+    for namespace in captures.namespaces() {
+        if let Ok(pods) = client.get_pods(&namespace).await {
+            captures.sync_pods(&namespace, &pods);
+        }
+    }
+}
+
+// 节点 cordon/uncordon 直接执行；drain 在后台进程中运行，结束后刷新节点列表
+async fn run_node_action(app: &mut AppState, client: &KubectlClient) {
+    let mut refresh = false;
+    match app.pending_node_action.take() {
+        Some(NodeAction::Cordon(name)) => {
+            app.set_current_command(&format!("kubectl cordon {}", name));
+            app.node_action_result = Some(
+                client
+                    .cordon_node(&name)
+                    .await
+                    .map(|out| out.trim().to_string())
+                    .map_err(|e| e.to_string()),
+            );
+            app.clear_current_command();
+            refresh = true;
+        }
+        Some(NodeAction::Uncordon(name)) => {
+            app.set_current_command(&format!("kubectl uncordon {}", name));
+            app.node_action_result = Some(
+                client
+                    .uncordon_node(&name)
+                    .await
+                    .map(|out| out.trim().to_string())
+                    .map_err(|e| e.to_string()),
+            );
+            app.clear_current_command();
+            refresh = true;
+        }
+        Some(NodeAction::Drain(name, options)) => {
+            if let Some(previous) = app.node_drain.take() {
+                previous.cancel();
             }
+            app.node_action_result = None;
+            app.node_drain = Some(NodeDrain::start(&name, options));
+            app.previous_mode = AppMode::NodeList;
+            app.mode = AppMode::NodeDrain;
         }
-        Err(e) => {
-            println!("Failed to execute command: {}", e);
+        None => {}
+    }
+    if let Some(drain) = app.node_drain.as_mut()
+        && !drain.refreshed
+        && !drain.is_running()
+    {
+        drain.refreshed = true;
+        refresh = true;
+    }
+    if refresh && let Ok(nodes) = client.get_nodes().await {
+        app.nodes = nodes;
+    }
+}
+
+// TopView：进入视图时和每个自动刷新周期拉取指标
+async fn update_top_view(app: &mut AppState, client: &KubectlClient) {
+    if !app.metrics_refresh_due() {
+        return;
+    }
+    if app.top_nodes {
+        // 节点资源使用；失败时显示错误（通常是缺少 metrics-server）
+        app.set_current_command("kubectl top nodes");
+        let result = client.get_node_metrics().await;
+        app.finish_node_metrics_refresh(result);
+        app.clear_current_command();
+        return;
+    }
+    let namespace = app.current_namespace.clone();
+    app.set_current_command(&format!("kubectl top pods -n {} --containers", namespace));
+    let result = client.get_pod_metrics(&namespace).await;
+    app.finish_pod_metrics_refresh(result);
+    app.clear_current_command();
+}
+
+// 节点详情：打开或按 R 时加载节点与其上的 Pod
+async fn update_node_detail(app: &mut AppState, client: &KubectlClient) {
+    let Some(node) = app
+        .node_detail
+        .as_ref()
+        .filter(|view| view.lifecycle.pending)
+        .map(|view| view.node.clone())
+    else {
+        return;
+    };
+    app.set_current_command(&format!(
+        "kubectl get node {0} -o json && kubectl get pods --all-namespaces --field-selector spec.nodeName={0}",
+        node
+    ));
+    let result = client.get_node_detail(&node).await;
+    app.clear_current_command();
+    if let Some(view) = app.node_detail.as_mut() {
+        view.set_result(result);
+    }
+}
+
+// 可编辑详情视图的公共流程：先执行已确认的修改，再按需重新加载
+async fn sync_editable_view<V: EditableView>(
+    app: &mut AppState,
+    view: fn(&mut AppState) -> Option<&mut V>,
+    patch: Option<(String, impl Future<Output = Result<String>>)>,
+    load_command: String,
+    load: impl Future<Output = Result<V::Detail>>,
+) {
+    if let Some((command, patch)) = patch {
+        app.set_current_command(&command);
+        let result = patch.await;
+        app.clear_current_command();
+        if let Some(view) = view(app) {
+            view.set_patch_result(result);
         }
     }
 
-    // 智能处理：根据命令结果提供不同的用户体验
-    if !is_success {
-        println!("\n=== Command completed with issues ===");
-        println!("The exec session ended. This might be normal if:");
-        println!("- You typed 'exit' to leave the container");
-        println!("- The container doesn't have the requested command");
-        println!("- The shell environment is limited");
-        println!("\nPress Enter to return to Kube TUI...");
-        let mut input = String::new();
-        std::io::stdin().read_line(&mut input)?;
+    if !view(app).is_some_and(|view| view.load_pending()) {
+        return;
+    }
+    app.set_current_command(&load_command);
+    let result = load.await;
+    app.clear_current_command();
+    if let Some(view) = view(app) {
+        view.set_result(result);
+    }
+}
+
+// ConfigMap 详情：修改单个键
+async fn update_configmap_view(app: &mut AppState, client: &KubectlClient) {
+    let Some(view) = app.configmap_view.as_ref() else {
+        return;
+    };
+    let (namespace, name) = (view.namespace.clone(), view.name.clone());
+    let edit = view.lifecycle.confirmed_edit().cloned();
+    let patch = edit.as_ref().map(|edit| {
+        (
+            format!(
+                "kubectl patch configmap {} -n {} --type merge --patch-file <data.{}>",
+                name, namespace, edit.key
+            ),
+            client.patch_configmap_key(&namespace, &name, &edit.key, &edit.value),
+        )
+    });
+    sync_editable_view(
+        app,
+        |app| app.configmap_view.as_mut(),
+        patch,
+        format!("kubectl get configmap {} -n {} -o json", name, namespace),
+        client.get_configmap_detail(&namespace, &name),
+    )
+    .await;
+}
+
+// Secret 详情：加载后解码，修改后重新编码
+async fn update_secret_view(app: &mut AppState, client: &KubectlClient) {
+    let Some(view) = app.secret_view.as_ref() else {
+        return;
+    };
+    let (namespace, name) = (view.namespace.clone(), view.name.clone());
+    let data: Option<Vec<(String, Option<Vec<u8>>)>> =
+        view.lifecycle.confirmed_edit().map(|changes| {
+            changes
+                .iter()
+                .map(|change| (change.key.clone(), change.value.clone()))
+                .collect()
+        });
+    let patch = data.as_ref().map(|data| {
+        (
+            format!(
+                "kubectl patch secret {} -n {} --type merge --patch-file <{} key(s)>",
+                name,
+                namespace,
+                data.len()
+            ),
+            client.patch_secret_data(&namespace, &name, data),
+        )
+    });
+    sync_editable_view(
+        app,
+        |app| app.secret_view.as_mut(),
+        patch,
+        format!("kubectl get secret {} -n {} -o json", name, namespace),
+        client.get_secret_detail(&namespace, &name),
+    )
+    .await;
+}
+
+// 保存弹窗确认后写入文件；完整日志先不带 --tail 重新获取
+async fn save_to_file(app: &mut AppState, client: &KubectlClient) {
+    let Some(dialog) = app.save_dialog.as_ref().filter(|d| d.pending) else {
+        return;
+    };
+    let kind = dialog.kind;
+    let path = expand_path(dialog.input.trim());
+
+    let content = if kind == SaveKind::FullLogs {
+        let Some(pod) = app.get_selected_pod().map(|p| p.name.clone()) else {
+            app.save_dialog = None;
+            return;
+        };
+        let namespace = app.current_namespace.clone();
+        app.set_current_command(&format!("kubectl logs -n {} {} (full)", namespace, pod));
+        let logs = client
+            .get_full_pod_logs(&namespace, &pod, &app.log_options)
+            .await;
+        app.clear_current_command();
+        logs
     } else {
-        // 成功执行后等待1秒，然后自动返回
-        println!("\nReturning to application in 1 second...");
-        std::thread::sleep(std::time::Duration::from_millis(1000));
+        Ok(app.save_content(kind))
+    };
+
+    let chinese = app.language_chinese;
+    let result = content
+        .and_then(|content| {
+            write_file(&path, &content)?;
+            Ok(content.lines().count())
+        })
+        .map(|lines| {
+            if chinese {
+                format!("已保存 {} 行到 {}", lines, path.display())
+            } else {
+                format!("Saved {} lines to {}", lines, path.display())
+            }
+        })
+        .map_err(|e| e.to_string());
+    if let Some(dialog) = app.save_dialog.as_mut() {
+        dialog.pending = false;
+        dialog.result = Some(result);
     }
+}
 
-    // 重新进入TUI模式并强制刷新
-    enable_raw_mode()?;
-    execute!(terminal.backend_mut(), EnterAlternateScreen)?;
-    terminal.hide_cursor()?;
-    terminal.clear()?; // 强制清屏
+async fn update_file_browser(app: &mut AppState, client: &KubectlClient) {
+    let namespace = app.current_namespace.clone();
+    let Some(browser) = app.file_browser.as_mut() else {
+        return;
+    };
+
+    if let Some(path) = browser.pending_list.take() {
+        match client
+            .list_container_dir(
+                &namespace,
+                &browser.pod_name,
+                browser.container.as_deref(),
+                &path,
+            )
+            .await
+        {
+            Ok(files) => browser.set_children(&path, files),
+            Err(e) => {
+                browser.loading = false;
+                browser.error = Some(e.to_string());
+            }
+        }
+    }
 
-    // 确保终端完全恢复
-    std::thread::sleep(std::time::Duration::from_millis(100));
+    // kubectl cp 在后台线程执行，避免阻塞界面
+    if let Some(request) = browser.pending_transfer.take() {
+        let total_bytes = if request.upload {
+            Some(local_size(std::path::Path::new(&request.local_path)))
+        } else {
+            browser
+                .selected_node()
+                .filter(|n| n.path == request.remote_path && !n.file.is_dir)
+                .map(|n| n.file.size)
+        };
+        let status = Arc::new(Mutex::new(TransferStatus::Running));
+        {
+            let status = Arc::clone(&status);
+            let request = request.clone();
+            let namespace = namespace.clone();
+            let pod_name = browser.pod_name.clone();
+            let container = browser.container.clone();
+            std::thread::spawn(move || {
+                let result = if request.upload {
+                    kubectl::commands::copy_to_pod(
+                        &namespace,
+                        &pod_name,
+                        container.as_deref(),
+                        &request.local_path,
+                        &request.remote_path,
+                    )
+                } else {
+                    kubectl::commands::copy_from_pod(
+                        &namespace,
+                        &pod_name,
+                        container.as_deref(),
+                        &request.remote_path,
+                        &request.local_path,
+                    )
+                };
+                if let Ok(mut status) = status.lock() {
+                    *status = match result {
+                        Ok(_) => TransferStatus::Done,
+                        Err(e) => TransferStatus::Failed(e.to_string()),
+                    };
+                }
+            });
+        }
+        browser.error = None;
+        browser.transfer = Some(FileTransfer {
+            request,
+            started: Instant::now(),
+            finished: None,
+            total_bytes,
+            transferred_bytes: 0,
+            status,
+        });
+    }
 
-    Ok(())
+    let mut refresh_dir = None;
+    if let Some(transfer) = browser.transfer.as_mut()
+        && transfer.finished.is_none()
+    {
+        let status = transfer.status();
+        if !transfer.request.upload {
+            transfer.transferred_bytes =
+                local_size(std::path::Path::new(&transfer.request.local_path));
+        }
+        if status != TransferStatus::Running {
+            transfer.finished = Some(Instant::now());
+            // 上传完成后刷新目标目录
+            if status == TransferStatus::Done && transfer.request.upload {
+                refresh_dir = Some(parent_remote(&transfer.request.remote_path));
+            }
+        }
+    }
+    if let Some(dir) = refresh_dir {
+        let known = dir == browser.root || browser.nodes.iter().any(|n| n.path == dir);
+        if known {
+            browser.request_list(&dir);
+        }
+    }
 }
@@ -0,0 +1,343 @@
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState},
+};
+
+use crate::app::configmap_view::{ConfigMapView, KeyEdit, Syntax};
+use crate::app::diff::{DiffLine, diff_stats};
+use crate::app::state::AppState;
+
+fn key_style() -> Style {
+    Style::default()
+        .fg(Color::Yellow)
+        .add_modifier(Modifier::BOLD)
+}
+
+fn scalar_style(value: &str) -> Style {
+    let value = value.trim().trim_end_matches(',');
+    if value.starts_with('"') || value.starts_with('\'') {
+        Style::default().fg(Color::Green)
+    } else if value.parse::<f64>().is_ok() {
+        Style::default().fg(Color::Magenta)
+    } else if matches!(value, "true" | "false" | "null" | "~") {
+        Style::default().fg(Color::Blue)
+    } else {
+        Style::default().fg(Color::White)
+    }
+}
+
+/// `key: value`：注释、列表项、键名和标量分别着色
+fn highlight_yaml(line: &str) -> Vec<Span<'static>> {
+    let trimmed = line.trim_start();
+    let indent = " ".repeat(line.len() - trimmed.len());
+    if trimmed.starts_with('#') {
+        return vec![
+            Span::raw(indent),
+            Span::styled(trimmed.to_string(), Style::default().fg(Color::Green)),
+        ];
+    }
+    let mut spans = vec![Span::raw(indent)];
+    let mut rest = trimmed;
+    if let Some(item) = rest.strip_prefix("- ") {
+        spans.push(Span::styled("- ", Style::default().fg(Color::Blue)));
+        rest = item;
+    }
+    match rest.split_once(':') {
+        Some((key, value)) if !key.contains(' ') || key.starts_with('"') => {
+            spans.push(Span::styled(key.to_string(), key_style()));
+            spans.push(Span::styled(":", Style::default().fg(Color::Cyan)));
+            spans.push(Span::styled(value.to_string(), scalar_style(value)));
+        }
+        _ => spans.push(Span::styled(rest.to_string(), scalar_style(rest))),
+    }
+    spans
+}
+
+/// 逐字符扫描：字符串后紧跟 `:` 的是键名
+fn highlight_json(line: &str) -> Vec<Span<'static>> {
+    let mut spans = Vec::new();
+    let chars: Vec<char> = line.chars().collect();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        if c == '"' {
+            let start = i;
+            i += 1;
+            while i < chars.len() && chars[i] != '"' {
+                if chars[i] == '\\' {
+                    i += 1;
+                }
+                i += 1;
+            }
+            i = (i + 1).min(chars.len());
+            let text: String = chars[start..i].iter().collect();
+            let is_key = chars[i..].iter().find(|c| !c.is_whitespace()) == Some(&':');
+            let style = if is_key {
+                key_style()
+            } else {
+                Style::default().fg(Color::Green)
+            };
+            spans.push(Span::styled(text, style));
+        } else if "{}[]:,".contains(c) {
+            spans.push(Span::styled(
+                c.to_string(),
+                Style::default().fg(Color::Cyan),
+            ));
+            i += 1;
+        } else {
+            let start = i;
+            while i < chars.len() && !"\"{}[]:,".contains(chars[i]) {
+                i += 1;
+            }
+            let text: String = chars[start..i].iter().collect();
+            let style = scalar_style(&text);
+            spans.push(Span::styled(text, style));
+        }
+    }
+    spans
+}
+
+/// properties / ini：注释、`[section]`、`key=value`
+fn highlight_key_value(line: &str, syntax: Syntax) -> Vec<Span<'static>> {
+    let trimmed = line.trim_start();
+    let comment = match syntax {
+        Syntax::Ini => trimmed.starts_with(';') || trimmed.starts_with('#'),
+        _ => trimmed.starts_with('#') || trimmed.starts_with('!'),
+    };
+    if comment {
+        return vec![Span::styled(
+            line.to_string(),
+            Style::default().fg(Color::Green),
+        )];
+    }
+    if syntax == Syntax::Ini && trimmed.starts_with('[') {
+        return vec![Span::styled(
+            line.to_string(),
+            Style::default()
+                .fg(Color::Blue)
+                .add_modifier(Modifier::BOLD),
+        )];
+    }
+    match line.find(['=', ':']) {
+        Some(pos) => vec![
+            Span::styled(line[..pos].to_string(), key_style()),
+            Span::styled(
+                line[pos..pos + 1].to_string(),
+                Style::default().fg(Color::Cyan),
+            ),
+            Span::styled(line[pos + 1..].to_string(), scalar_style(&line[pos + 1..])),
+        ],
+        None => vec![Span::raw(line.to_string())],
+    }
+}
+
+fn highlight_line(line: &str, syntax: Syntax) -> Line<'static> {
+    match syntax {
+        Syntax::Yaml => Line::from(highlight_yaml(line)),
+        Syntax::Json => Line::from(highlight_json(line)),
+        Syntax::Properties | Syntax::Ini => Line::from(highlight_key_value(line, syntax)),
+        Syntax::Plain => Line::from(line.to_string()),
+    }
+}
+
+fn render_keys(f: &mut Frame, area: Rect, view: &ConfigMapView, chinese: bool) {
+    let header = if chinese {
+        ["键", "格式", "大小", "行数"]
+    } else {
+        ["Key", "Format", "Size", "Lines"]
+    };
+    let rows: Vec<Row> = view
+        .entries()
+        .iter()
+        .map(|entry| {
+            let (format, lines) = if entry.binary {
+                ("binary".to_string(), "-".to_string())
+            } else {
+                (
+                    Syntax::from_key(&entry.key).label().to_string(),
+                    entry.value.lines().count().to_string(),
+                )
+            };
+            Row::new(vec![
+                Cell::from(entry.key.clone()),
+                Cell::from(format),
+                Cell::from(format!("{} B", entry.size)),
+                Cell::from(lines),
+            ])
+        })
+        .collect();
+
+    let title = format!("ConfigMap - {}/{}", view.namespace, view.name);
+    let (title, title_style) = match (&view.message, &view.lifecycle.error) {
+        (_, Some(error)) => (
+            format!("{} - {}", title, error),
+            Style::default().fg(Color::Red),
+        ),
+        (Some(Ok(message)), None) => (
+            format!("{} - {}", title, message),
+            Style::default().fg(Color::Green),
+        ),
+        (Some(Err(error)), None) => (
+            format!("{} - {}", title, error),
+            Style::default().fg(Color::Red),
+        ),
+        (None, None) => (title, Style::default()),
+    };
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Percentage(50),
+            Constraint::Length(12),
+            Constraint::Length(12),
+            Constraint::Length(8),
+        ],
+    )
+    .header(
+        Row::new(header).style(
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        ),
+    )
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(title)
+            .title_style(title_style),
+    )
+    .row_highlight_style(
+        Style::default()
+            .fg(Color::Black)
+            .bg(Color::Yellow)
+            .add_modifier(Modifier::BOLD),
+    );
+    let mut state = TableState::default().with_selected(Some(view.selected));
+    f.render_stateful_widget(table, area, &mut state);
+}
+
+fn render_value(f: &mut Frame, area: Rect, view: &ConfigMapView) {
+    let Some(entry) = view.selected_entry() else {
+        return;
+    };
+    let syntax = if entry.binary {
+        Syntax::Plain
+    } else {
+        Syntax::from_key(&entry.key)
+    };
+    let lines: Vec<Line> = entry
+        .value
+        .lines()
+        .map(|line| highlight_line(line, syntax))
+        .collect();
+    let title = format!("{} ({}) - J/K", entry.key, syntax.label());
+    let paragraph = Paragraph::new(lines)
+        .scroll((view.scroll as u16, 0))
+        .block(Block::default().borders(Borders::ALL).title(title));
+    f.render_widget(paragraph, area);
+}
+
+fn render_diff(f: &mut Frame, area: Rect, edit: &KeyEdit, chinese: bool) {
+    let lines: Vec<Line> = edit
+        .diff
+        .iter()
+        .map(|line| match line {
+            DiffLine::Same(text) => Line::from(format!("  {}", text)),
+            DiffLine::Added(text) => {
+                Line::styled(format!("+ {}", text), Style::default().fg(Color::Green))
+            }
+            DiffLine::Removed(text) => {
+                Line::styled(format!("- {}", text), Style::default().fg(Color::Red))
+            }
+        })
+        .collect();
+    let (added, removed) = diff_stats(&edit.diff);
+    let title = if chinese {
+        format!(
+            "确认修改 {} (+{} -{})  y 应用 • n 放弃",
+            edit.key, added, removed
+        )
+    } else {
+        format!(
+            "Apply change to {} (+{} -{})  y apply • n discard",
+            edit.key, added, removed
+        )
+    };
+    let paragraph = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(title)
+            .title_style(Style::default().fg(Color::Yellow)),
+    );
+    f.render_widget(paragraph, area);
+}
+
+pub fn render(f: &mut Frame, area: Rect, app: &AppState) {
+    let Some(view) = &app.configmap_view else {
+        return;
+    };
+    let chinese = app.language_chinese;
+    if view.lifecycle.detail.is_none() {
+        let (text, color) = match &view.lifecycle.error {
+            Some(error) => (error.clone(), Color::Red),
+            None if chinese => ("加载中...".to_string(), Color::Gray),
+            None => ("Loading...".to_string(), Color::Gray),
+        };
+        let paragraph = Paragraph::new(text)
+            .style(Style::default().fg(color))
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(format!("ConfigMap - {}/{}", view.namespace, view.name)),
+            );
+        f.render_widget(paragraph, area);
+        return;
+    }
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length((view.entries().len() as u16 + 3).min(area.height / 3)),
+            Constraint::Min(3),
+        ])
+        .split(area);
+    render_keys(f, chunks[0], view, chinese);
+    match &view.lifecycle.edit {
+        Some(edit) => render_diff(f, chunks[1], edit, chinese),
+        None => render_value(f, chunks[1], view),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(spans: &[Span]) -> Vec<String> {
+        spans.iter().map(|s| s.content.to_string()).collect()
+    }
+
+    #[test]
+    fn test_highlight_json_keys_and_values() {
+        let spans = highlight_json(r#"  "port": 80, "name": "web""#);
+        let contents = text(&spans);
+        assert_eq!(contents.concat(), r#"  "port": 80, "name": "web""#);
+        let port = spans.iter().find(|s| s.content == "\"port\"").unwrap();
+        assert_eq!(port.style, key_style());
+        let web = spans.iter().find(|s| s.content == "\"web\"").unwrap();
+        assert_eq!(web.style.fg, Some(Color::Green));
+    }
+
+    #[test]
+    fn test_highlight_key_value() {
+        let spans = highlight_key_value("db.url=jdbc:x", Syntax::Properties);
+        assert_eq!(text(&spans), ["db.url", "=", "jdbc:x"]);
+        let section = highlight_key_value("[server]", Syntax::Ini);
+        assert_eq!(section[0].style.fg, Some(Color::Blue));
+        let comment = highlight_key_value("; note", Syntax::Ini);
+        assert_eq!(comment[0].style.fg, Some(Color::Green));
+        assert_eq!(text(&highlight_yaml("  port: 80")).concat(), "  port: 80");
+    }
+}
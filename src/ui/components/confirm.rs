@@ -1,16 +1,17 @@
 use ratatui::{
     Frame,
-    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    layout::{Alignment, Rect},
     style::{Color, Style},
     widgets::{Block, Borders, Clear, Paragraph},
 };
 
+use super::centered_percent;
 use crate::app::state::{AppState, ConfirmAction};
 
 pub fn render(f: &mut Frame, area: Rect, app: &AppState) {
     if let Some(ref action) = app.confirm_action {
         // 创建一个居中的对话框
-        let popup_area = centered_rect(60, 20, area);
+        let popup_area = centered_percent(area, 60, 20);
 
         // 清除背景
         f.render_widget(Clear, popup_area);
@@ -56,23 +57,3 @@ pub fn render(f: &mut Frame, area: Rect, app: &AppState) {
         f.render_widget(paragraph, popup_area);
     }
 }
-
-fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
-    let popup_layout = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints([
-            Constraint::Percentage((100 - percent_y) / 2),
-            Constraint::Percentage(percent_y),
-            Constraint::Percentage((100 - percent_y) / 2),
-        ])
-        .split(r);
-
-    Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints([
-            Constraint::Percentage((100 - percent_x) / 2),
-            Constraint::Percentage(percent_x),
-            Constraint::Percentage((100 - percent_x) / 2),
-        ])
-        .split(popup_layout[1])[1]
-}
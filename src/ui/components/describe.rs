@@ -1,14 +1,17 @@
 use ratatui::{
     Frame,
-    layout::Rect,
+    layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
     widgets::{
-        Block, Borders, List, ListItem, ListState, Paragraph, Scrollbar, ScrollbarOrientation,
-        ScrollbarState,
+        Block, Borders, Clear, List, ListItem, ListState, Paragraph, Scrollbar,
+        ScrollbarOrientation, ScrollbarState,
     },
 };
 
+use super::search::{highlight_matches, split_content_search_input};
+use crate::app::content_search::ContentSearch;
+use crate::app::describe_view::{DescribeOutline, DescribeSection, EVENTS_SECTION};
 use crate::app::state::{AppMode, AppState};
 
 pub fn render(f: &mut Frame, area: Rect, app: &AppState) {
@@ -122,6 +125,9 @@ pub fn render(f: &mut Frame, area: Rect, app: &AppState) {
         _ => "Describe".to_string(),
     };
 
+    let title = format!("{}{}", title, app.content_search.status());
+    let area = split_content_search_input(f, area, app);
+
     if app.describe_content.is_empty() {
         let no_content = Paragraph::new("No description available or loading...")
             .block(Block::default().borders(Borders::ALL).title(title))
@@ -131,19 +137,60 @@ pub fn render(f: &mut Frame, area: Rect, app: &AppState) {
         return;
     }
 
-    // 将内容按行分割并应用语法高亮
-    let lines: Vec<&str> = app.describe_content.lines().collect();
-    let visible_height = area.height.saturating_sub(2) as usize;
-    let total_lines = lines.len();
+    // Events 固定在底部，不随主面板滚动
+    let outline = &app.describe_outline;
+    let (main_area, events_area) = match outline.events() {
+        Some(events) => {
+            let height = (events.body_len().max(1) as u16 + 2).min((area.height / 3).max(3));
+            let chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Min(3), Constraint::Length(height)])
+                .split(area);
+            (chunks[0], Some((chunks[1], events)))
+        }
+        None => (area, None),
+    };
+
+    let lines = &app.describe_lines_cache;
+    let search = &app.content_search;
+    let rows = outline.rows();
+    let visible_height = main_area.height.saturating_sub(2) as usize;
+    let total_lines = rows.len();
 
     // 计算显示范围
-    let start_index = app.describe_scroll;
+    let start_index = app.describe_scroll.min(total_lines);
     let end_index = (start_index + visible_height).min(total_lines);
 
-    // 创建带语法高亮的可见内容项
-    let visible_lines: Vec<ListItem> = lines[start_index..end_index]
+    // 段落标题前显示折叠标记，折叠的段落显示隐藏的行数
+    let visible_lines: Vec<ListItem> = rows[start_index..end_index]
         .iter()
-        .map(|line| ListItem::new(highlight_yaml_line(line)))
+        .map(|&index| {
+            let section = outline.sections.iter().find(|s| s.start == index);
+            let mut line = match &search.pattern {
+                Some(pattern) if search.matches.binary_search(&index).is_ok() => {
+                    let current = search.current_line() == Some(index);
+                    highlight_matches(highlight_yaml_line(&lines[index]), pattern, current)
+                }
+                _ => highlight_yaml_line(&lines[index]),
+            };
+            let marker = match section {
+                Some(section) if section.body_len() > 0 && outline.is_folded(section) => "▸ ",
+                Some(section) if section.body_len() > 0 => "▾ ",
+                _ => "  ",
+            };
+            line.spans
+                .insert(0, Span::styled(marker, Style::default().fg(Color::Cyan)));
+            if let Some(section) = section.filter(|s| outline.is_folded(s)) {
+                let hidden = if app.language_chinese {
+                    format!(" … {} 行", section.body_len())
+                } else {
+                    format!(" … {} lines", section.body_len())
+                };
+                line.spans
+                    .push(Span::styled(hidden, Style::default().fg(Color::DarkGray)));
+            }
+            ListItem::new(line)
+        })
         .collect();
 
     let mut list_state = ListState::default();
@@ -163,7 +210,7 @@ pub fn render(f: &mut Frame, area: Rect, app: &AppState) {
         .block(Block::default().borders(Borders::ALL).title(title))
         .style(Style::default().fg(Color::White));
 
-    f.render_stateful_widget(list, area, &mut list_state);
+    f.render_stateful_widget(list, main_area, &mut list_state);
 
     // 渲染滚动条
     if let Some(mut state) = scrollbar_state {
@@ -173,13 +220,132 @@ pub fn render(f: &mut Frame, area: Rect, app: &AppState) {
             .end_symbol(Some("↓"));
         f.render_stateful_widget(
             scrollbar,
-            area.inner(ratatui::layout::Margin {
+            main_area.inner(ratatui::layout::Margin {
                 vertical: 1,
                 horizontal: 0,
             }),
             &mut state,
         );
     }
+
+    if let Some((events_area, events)) = events_area {
+        render_events(f, events_area, lines, events, search, app.language_chinese);
+    }
+    if let Some(selected) = outline.picker {
+        render_section_picker(f, area, outline, selected, app.language_chinese);
+    }
+}
+
+/// 固定的 Events 面板：放不下时显示最新（末尾）的事件，当前搜索匹配在其中时显示到它
+fn render_events(
+    f: &mut Frame,
+    area: Rect,
+    lines: &[String],
+    events: &DescribeSection,
+    search: &ContentSearch,
+    chinese: bool,
+) {
+    let height = area.height.saturating_sub(2) as usize;
+    let body = &lines[events.start + 1..events.end];
+    let first = body.len().saturating_sub(height);
+    let first = match search.current_line() {
+        Some(line) if line > events.start && line < events.end => {
+            first.min(line - events.start - 1)
+        }
+        _ => first,
+    };
+    let items: Vec<ListItem> = if body.is_empty() {
+        // `Events:  <none>` 的值在标题行中
+        let value = lines[events.start]
+            .split_once(':')
+            .map_or("", |(_, value)| value.trim());
+        vec![ListItem::new(Span::styled(
+            value.to_string(),
+            Style::default().fg(Color::Gray),
+        ))]
+    } else {
+        body[first..]
+            .iter()
+            .zip(events.start + 1..)
+            .map(|(text, index)| {
+                let line = highlight_event_line(text);
+                match &search.pattern {
+                    Some(pattern) if search.matches.binary_search(&index).is_ok() => {
+                        let current = search.current_line() == Some(index);
+                        ListItem::new(highlight_matches(line, pattern, current))
+                    }
+                    _ => ListItem::new(line),
+                }
+            })
+            .collect()
+    };
+    let title = if chinese {
+        format!("{} (固定显示)", EVENTS_SECTION)
+    } else {
+        format!("{} (pinned)", EVENTS_SECTION)
+    };
+    let list = List::new(items).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(title)
+            .title_style(Style::default().fg(Color::Blue)),
+    );
+    f.render_widget(list, area);
+}
+
+/// Warning 事件标红
+fn highlight_event_line(line: &str) -> Line<'_> {
+    let style = match line.split_whitespace().next() {
+        Some("Warning") => Style::default().fg(Color::Red),
+        Some("Type") | Some("----") => Style::default().fg(Color::Gray),
+        _ => Style::default().fg(Color::White),
+    };
+    Line::styled(line, style)
+}
+
+fn render_section_picker(
+    f: &mut Frame,
+    area: Rect,
+    outline: &DescribeOutline,
+    selected: usize,
+    chinese: bool,
+) {
+    let width = 40.min(area.width);
+    let height = (outline.sections.len() as u16 + 2).min(area.height);
+    let popup = Rect::new(area.right() - width, area.y, width, height);
+    let items: Vec<ListItem> = outline
+        .sections
+        .iter()
+        .map(|section| {
+            let marker = match (section.body_len() > 0, outline.is_folded(section)) {
+                (false, _) => "  ",
+                (true, true) => "▸ ",
+                (true, false) => "▾ ",
+            };
+            ListItem::new(format!(
+                "{}{} ({})",
+                marker,
+                section.title,
+                section.body_len()
+            ))
+        })
+        .collect();
+    let title = if chinese {
+        "段落 - Enter 跳转 • Space 折叠"
+    } else {
+        "Sections - Enter jump • Space fold"
+    };
+    let list = List::new(items)
+        .block(Block::default().borders(Borders::ALL).title(title))
+        .highlight_style(
+            Style::default()
+                .fg(Color::Black)
+                .bg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        );
+    let mut state = ListState::default().with_selected(Some(selected));
+    f.render_widget(Clear, popup);
+    f.render_stateful_widget(list, popup, &mut state);
 }
 
 // YAML语法高亮函数
@@ -0,0 +1,120 @@
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::Line,
+    widgets::{Block, Borders, Clear, Paragraph},
+};
+
+use super::centered_percent;
+use crate::app::state::{AppState, ExecPickerStep};
+
+fn item_line(label: String, selected: bool) -> Line<'static> {
+    if selected {
+        Line::styled(
+            format!("▶ {}", label),
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        )
+    } else {
+        Line::styled(format!("  {}", label), Style::default().fg(Color::White))
+    }
+}
+
+pub fn render(f: &mut Frame, area: Rect, app: &AppState) {
+    let Some(picker) = &app.exec_picker else {
+        return;
+    };
+
+    let picker_area = centered_percent(area, 60, 50);
+    f.render_widget(Clear, picker_area);
+
+    let target = match &picker.container {
+        Some(container) => format!("{}/{}", picker.pod_name, container),
+        None => picker.pod_name.clone(),
+    };
+
+    let (title, lines) = match picker.step {
+        ExecPickerStep::Container => {
+            let title = if app.language_chinese {
+                format!(
+                    "选择容器 - {} (j/k:导航 Enter:确认 Esc:取消)",
+                    picker.pod_name
+                )
+            } else {
+                format!(
+                    "Select Container - {} (j/k:nav Enter:select Esc:cancel)",
+                    picker.pod_name
+                )
+            };
+            let lines = picker
+                .containers
+                .iter()
+                .enumerate()
+                .map(|(i, c)| item_line(c.clone(), i == picker.selected))
+                .collect::<Vec<_>>();
+            (title, lines)
+        }
+        ExecPickerStep::Command => {
+            let title = if app.language_chinese {
+                format!("Exec - {} (j/k:导航 Enter:确认 Esc:返回)", target)
+            } else {
+                format!("Exec - {} (j/k:nav Enter:select Esc:back)", target)
+            };
+            let shells = app.user_config.exec_shells().join(" → ");
+            let custom = if picker.command_input.is_empty() {
+                if app.language_chinese {
+                    "自定义命令...".to_string()
+                } else {
+                    "Custom command...".to_string()
+                }
+            } else if app.language_chinese {
+                format!("自定义命令: {}", picker.command_input)
+            } else {
+                format!("Custom command: {}", picker.command_input)
+            };
+            let attach = if app.language_chinese {
+                "Attach 到主进程 (kubectl attach)"
+            } else {
+                "Attach to main process (kubectl attach)"
+            };
+            let lines = vec![
+                item_line(format!("Shell: {}", shells), picker.selected == 0),
+                item_line(custom, picker.selected == 1),
+                item_line(attach.to_string(), picker.selected == 2),
+            ];
+            (title, lines)
+        }
+        ExecPickerStep::CustomCommand => {
+            let title = if app.language_chinese {
+                format!("Exec 命令 - {} (Enter:执行 Esc:返回)", target)
+            } else {
+                format!("Exec Command - {} (Enter:run Esc:back)", target)
+            };
+            let hint = if app.language_chinese {
+                "命令直接作为容器进程参数执行；需要管道等 shell 语法时请写成 sh -c '...'"
+            } else {
+                "Runs as the container process; wrap in sh -c '...' for pipes and other shell syntax"
+            };
+            let lines = vec![
+                Line::styled(
+                    format!("> {}█", picker.command_input),
+                    Style::default().fg(Color::White),
+                ),
+                Line::from(""),
+                Line::styled(hint, Style::default().fg(Color::Gray)),
+            ];
+            (title, lines)
+        }
+    };
+
+    let paragraph = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(title)
+            .style(Style::default().fg(Color::Yellow)),
+    );
+
+    f.render_widget(paragraph, picker_area);
+}
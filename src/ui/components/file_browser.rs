@@ -0,0 +1,222 @@
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::Line,
+    widgets::{Block, Borders, Cell, Clear, Paragraph, Row, Table, TableState},
+};
+
+use crate::app::file_browser::{
+    FileBrowser, FileBrowserPrompt, FileTransfer, TransferStatus, format_size,
+};
+use crate::app::state::AppState;
+
+fn transfer_line(transfer: &FileTransfer, chinese: bool) -> Line<'static> {
+    let request = &transfer.request;
+    let (arrow, from, to) = if request.upload {
+        ("⇡", &request.local_path, &request.remote_path)
+    } else {
+        ("⇣", &request.remote_path, &request.local_path)
+    };
+    let elapsed = transfer
+        .finished
+        .unwrap_or_else(std::time::Instant::now)
+        .duration_since(transfer.started)
+        .as_secs();
+
+    match transfer.status() {
+        TransferStatus::Running => {
+            let progress = match transfer.total_bytes {
+                Some(total) if !request.upload && total > 0 => format!(
+                    "{} / {} ({}%)",
+                    format_size(transfer.transferred_bytes),
+                    format_size(total),
+                    (transfer.transferred_bytes * 100 / total).min(100)
+                ),
+                Some(total) if request.upload => format_size(total),
+                _ => format_size(transfer.transferred_bytes),
+            };
+            Line::styled(
+                format!("{} {} → {}  {}  {}s", arrow, from, to, progress, elapsed),
+                Style::default().fg(Color::Yellow),
+            )
+        }
+        TransferStatus::Done => {
+            let done = if chinese { "完成" } else { "done" };
+            Line::styled(
+                format!("✓ {} → {}  {} ({}s)", from, to, done, elapsed),
+                Style::default().fg(Color::Green),
+            )
+        }
+        TransferStatus::Failed(error) => Line::styled(
+            format!("✗ {} → {}: {}", from, to, error.trim()),
+            Style::default().fg(Color::Red),
+        ),
+    }
+}
+
+fn render_prompt(f: &mut Frame, area: Rect, browser: &FileBrowser, chinese: bool) {
+    let Some(prompt) = &browser.prompt else {
+        return;
+    };
+    let width = area.width * 70 / 100;
+    let popup = Rect::new(
+        area.x + (area.width.saturating_sub(width)) / 2,
+        area.y + area.height.saturating_sub(5) / 2,
+        width,
+        5.min(area.height),
+    );
+    f.render_widget(Clear, popup);
+
+    let title = match (prompt, chinese) {
+        (FileBrowserPrompt::Download, true) => "下载到本地路径 (Enter:确认 Esc:取消)".to_string(),
+        (FileBrowserPrompt::Download, false) => {
+            "Download to local path (Enter:ok Esc:cancel)".to_string()
+        }
+        (FileBrowserPrompt::Upload, true) => {
+            format!(
+                "上传本地文件到 {} (Enter:确认 Esc:取消)",
+                browser.upload_dir()
+            )
+        }
+        (FileBrowserPrompt::Upload, false) => {
+            format!(
+                "Upload local path to {} (Enter:ok Esc:cancel)",
+                browser.upload_dir()
+            )
+        }
+    };
+    let paragraph = Paragraph::new(format!("> {}█", browser.input)).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(title)
+            .style(Style::default().fg(Color::Yellow)),
+    );
+    f.render_widget(paragraph, popup);
+}
+
+pub fn render(f: &mut Frame, area: Rect, app: &AppState) {
+    let Some(browser) = &app.file_browser else {
+        return;
+    };
+    let chinese = app.language_chinese;
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(3)])
+        .split(area);
+
+    let target = match &browser.container {
+        Some(container) => format!("{}/{}", browser.pod_name, container),
+        None => browser.pod_name.clone(),
+    };
+    let title = if chinese {
+        format!("文件 - {}:{}", target, browser.root)
+    } else {
+        format!("Files - {}:{}", target, browser.root)
+    };
+    let block = Block::default().borders(Borders::ALL).title(title);
+
+    if browser.nodes.is_empty() {
+        let text = if browser.loading {
+            if chinese {
+                "加载中..."
+            } else {
+                "Loading..."
+            }
+        } else if chinese {
+            "目录为空"
+        } else {
+            "Empty directory"
+        };
+        let widget = Paragraph::new(text)
+            .block(block)
+            .style(Style::default().fg(Color::Gray));
+        f.render_widget(widget, chunks[0]);
+    } else {
+        let rows: Vec<Row> = browser
+            .nodes
+            .iter()
+            .enumerate()
+            .map(|(i, node)| {
+                let icon = if node.file.is_dir {
+                    if node.expanded { "▾ " } else { "▸ " }
+                } else {
+                    "  "
+                };
+                let mut name = format!("{}{}{}", "  ".repeat(node.depth), icon, node.file.name);
+                if node.file.is_dir {
+                    name.push('/');
+                }
+                if let Some(target) = &node.file.link_target {
+                    name.push_str(&format!(" -> {}", target));
+                }
+                let style = if i == browser.selected {
+                    Style::default()
+                        .fg(Color::Yellow)
+                        .add_modifier(Modifier::BOLD)
+                } else if node.file.is_dir {
+                    Style::default().fg(Color::Cyan)
+                } else {
+                    Style::default().fg(Color::White)
+                };
+                let size = if node.file.is_dir {
+                    String::new()
+                } else {
+                    format_size(node.file.size)
+                };
+                Row::new(vec![
+                    Cell::from(name),
+                    Cell::from(node.file.permissions.clone()),
+                    Cell::from(size),
+                    Cell::from(node.file.modified.clone()),
+                ])
+                .style(style)
+            })
+            .collect();
+
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Min(20),
+                Constraint::Length(11),
+                Constraint::Length(10),
+                Constraint::Length(13),
+            ],
+        )
+        .block(block);
+        let mut state = TableState::default().with_selected(Some(browser.selected));
+        f.render_stateful_widget(table, chunks[0], &mut state);
+    }
+
+    let status = if let Some(error) = &browser.error {
+        Line::styled(error.trim().to_string(), Style::default().fg(Color::Red))
+    } else if let Some(transfer) = &browser.transfer {
+        transfer_line(transfer, chinese)
+    } else if browser.loading {
+        Line::styled(
+            if chinese {
+                "加载中..."
+            } else {
+                "Loading..."
+            },
+            Style::default().fg(Color::Gray),
+        )
+    } else {
+        Line::styled(
+            if chinese {
+                "d 下载选中项 • u 上传本地文件到选中目录"
+            } else {
+                "d download selection • u upload a local file into the selected directory"
+            },
+            Style::default().fg(Color::Gray),
+        )
+    };
+    let status_title = if chinese { "传输" } else { "Transfer" };
+    f.render_widget(
+        Paragraph::new(status).block(Block::default().borders(Borders::ALL).title(status_title)),
+        chunks[1],
+    );
+
+    render_prompt(f, area, browser, chinese);
+}
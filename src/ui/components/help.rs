@@ -30,29 +30,102 @@ POD VIEW / Pod 视图:
   Y                View YAML config / 查看 YAML 配置
   T                View resource usage / 查看资源使用情况
   L                View pod logs / 查看 Pod 日志
+  S                Aggregate logs of pods matching a label selector / 按标签选择器聚合日志
+  C                Start/stop background log capture to disk (●) / 开始/停止后台捕获日志到文件
   D                Delete pod / 删除 Pod（需确认）
-  E                Exec into pod / 进入 Pod 容器
+  E                Exec into pod (container picker, bash → sh fallback or custom command)
+                   进入 Pod 容器（多容器可选择，bash → sh 回退或自定义命令）
+                   Sessions run in an embedded terminal; attach is also offered
+                   会话在内嵌终端中运行，也可选择 attach
+  F                Browse container files / 浏览容器文件
   /                Search pods / 搜索 Pod
 
+FILE BROWSER / 文件浏览器:
+  Enter/l, h       Expand / collapse directory / 展开/折叠目录
+  d                Download selection (kubectl cp) / 下载选中项
+  u                Upload local file into directory / 上传本地文件到目录
+  c                Switch container / 切换容器
+  r                Reload from / / 从根目录重新加载
+
 LOGS VIEW / 日志视图:
   J/K              Scroll line by line / 按行滚动
   PgUp/PgDn        Scroll page by page / 按页滚动
+  f                Toggle follow (kubectl logs -f) / snapshot / 切换跟随与快照模式
+  p                Pause scrolling, keep buffering / 暂停滚动（继续缓冲）
+  o                Log options: container, --previous, --since, timestamps, tail / 日志选项
+  /                Search (Ctrl+R regex, Ctrl+T case) / 搜索（Ctrl+R 正则，Ctrl+T 大小写）
+  n/N              Next / previous match / 下一个/上一个匹配
+  g, +/-           Grep mode: only matches with context lines / grep 模式及上下文行数
+  s                Toggle JSON/logfmt rendering / 切换结构化日志渲染
+  v                Cycle level filter (error/warn/info/debug) / 切换级别过滤
+  e                Expand top line as pretty-printed object / 展开顶部日志行
+  c                Toggle ANSI colours / strip escape codes / 切换 ANSI 颜色（关闭时去除转义序列）
+  w                Save log buffer to file / 保存日志缓冲区到文件
+  W                Fetch full logs (no --tail) and save / 获取完整日志并保存
+  A                Toggle auto-scroll / 切换自动滚动
+  R                Toggle auto-refresh (snapshot mode) / 切换自动刷新（快照模式）
+  Esc              Clear search, or return to pod list / 清除搜索或返回 Pod 列表
+
+AGGREGATED LOGS / 聚合日志 (L on Deployment/Job/DaemonSet, S on pods):
+  J/K, PgUp/PgDn   Scroll / 滚动
+  g/G              Top / bottom (bottom resumes auto-scroll) / 顶部/底部
   A                Toggle auto-scroll / 切换自动滚动
-  R                Toggle auto-refresh / 切换自动刷新
-  Esc              Return to pod list / 返回 Pod 列表
+  Esc              Stop tailing and go back / 停止跟随并返回
+
+LOG CAPTURES / 日志捕获 (B from any list view / 在列表视图中按 B):
+  j/k              Select capture / 选择捕获
+  x / X            Stop selected / stop all / 停止选中/全部
+  Esc              Back, captures keep running / 返回，捕获继续
+  Files rotate at 10 MiB (5 kept); replaced pods of the same owner are followed
+  文件超过 10 MiB 轮转（保留 5 个），Pod 被替换后自动跟随同一 owner 的新 Pod
+
+NODE VIEW / 节点视图:
+  Enter            Capacity vs allocatable vs pod requests/limits, conditions, taints, labels, pods
+                   容量/可分配/Pod 请求与限制、状况、污点、标签及节点上的 Pod
+  C                Cordon / uncordon node / 禁止或恢复调度
+  D                Drain node (options dialog) / 排空节点（先设置选项）
+  Drain dialog     Space toggle, type grace period/timeout, Enter start / 空格切换，输入宽限期/超时，Enter 开始
+  Capacity view    j/k select pod, Enter jump to its pod list, R refresh / Enter 跳转到 Pod 列表
+  Drain view       j/k select pod, x cancel, Esc back (drain keeps running)
+  排空视图         j/k 选择 Pod，x 取消，Esc 返回（排空继续）
+
+TERMINAL VIEW / 终端视图:
+  `                Open/close terminal view / 打开/关闭终端视图
+  Ctrl+]           Return focus from shell to TUI / 从 shell 交还焦点给 TUI
+  Enter/i          Focus the shell / 聚焦 shell
+  Tab/[ ]          Switch session tabs / 切换会话标签
+  x                Close session / 关闭会话
+  Esc              Back, sessions keep running / 返回，会话保持运行
 
 YAML/DESCRIBE/TOP VIEW / YAML/描述/监控视图:
   J/K              Scroll content / 滚动内容
   PgUp/PgDn        Scroll page by page / 按页滚动
+  w                Save describe/YAML to file / 保存详情或 YAML 到文件
+  z / Z            Fold section / all (describe) / 折叠当前段落 / 全部（描述）
+  [ / ]            Previous / next section / 上一个 / 下一个段落
+  s                Section list, Events pinned at bottom / 段落列表，Events 固定在底部
+  z / Z / S (yaml)  Fold node / all / only spec, path shown below / 折叠节点 / 全部 / 只展开 spec，底部显示路径
+  / , n / N        Search content, next / previous match / 搜索内容，下一个 / 上一个匹配
+  c / W (yaml)     Neat mode / export clean manifest / 精简模式 / 导出可重新 apply 的清单
   Esc              Return to previous view / 返回上一级视图
 
 SERVICE/NODE/CONFIGMAP/SECRET VIEW / 服务/节点/配置/密钥视图:
   Space            Describe resource / 查看资源详情
   Y                View YAML config / 查看 YAML 配置
   /                Search resources / 搜索资源
+  Enter (secret)   Decoded values, masked / 查看解码后的值（默认隐藏）
+  r / c            Reveal key / copy value, audited / 显示 / 复制值，记入审计日志
+                   TLS secrets: cert chain, SANs, days to expiry / TLS Secret：证书链、SAN、剩余天数
+  e (secret)       Edit decoded values, masked diff before patch / 以明文编辑，确认前显示隐藏值的变更
+  Enter (configmap) Keys with highlighted values / 查看键及高亮的值
+  e / J/K          Edit key in $EDITOR, diff before apply / 在编辑器中修改键，应用前显示 diff；滚动值
 
 RESOURCE MONITORING / 资源监控:
   T (in Pod view)  View CPU/Memory usage / 查看 CPU/内存使用情况
+                   % of each container's requests/limits, ⚠ near memory limit / 按请求和限制计算百分比，⚠ 接近内存限制
+                   Sparklines + min/avg/max over last 60 refreshes / 趋势图及最近 60 次刷新的最小/平均/最大值
+  T (in Node view) kubectl top nodes, % of allocatable / 节点使用量及占可分配量百分比
+  s (node usage)   Cycle sort: name / CPU% / memory% / 切换排序：名称/CPU%/内存%
   Note: Requires metrics-server / 注意: 需要安装 metrics-server
 
 SEARCH / 搜索:
@@ -0,0 +1,133 @@
+use ratatui::{
+    Frame,
+    layout::{Constraint, Rect},
+    style::{Color, Modifier, Style},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState},
+};
+
+use crate::app::file_browser::format_size;
+use crate::app::log_capture::LogCapture;
+use crate::app::state::AppState;
+
+fn elapsed_text(capture: &LogCapture) -> String {
+    let secs = capture.started.elapsed().as_secs();
+    if secs >= 3600 {
+        format!("{}h{}m", secs / 3600, secs % 3600 / 60)
+    } else {
+        format!("{}m{}s", secs / 60, secs % 60)
+    }
+}
+
+// 捕获状态：写入错误 / 重连中 / 跟随中
+fn status_cell(capture: &LogCapture, chinese: bool) -> Cell<'static> {
+    if let Some(error) = &capture.error {
+        return Cell::from(error.clone()).style(Style::default().fg(Color::Red));
+    }
+    if capture.stream.is_connected() {
+        let text = if chinese { "捕获中" } else { "capturing" };
+        return Cell::from(text).style(Style::default().fg(Color::Green));
+    }
+    let reason = capture
+        .stream
+        .last_error()
+        .and_then(|e| e.lines().last().map(String::from))
+        .unwrap_or_default();
+    let text = if chinese {
+        format!("重连中 {}", reason)
+    } else {
+        format!("reconnecting {}", reason)
+    };
+    Cell::from(text).style(Style::default().fg(Color::Yellow))
+}
+
+pub fn render(f: &mut Frame, area: Rect, app: &AppState) {
+    let manager = &app.log_captures;
+    let chinese = app.language_chinese;
+    let title = if chinese {
+        format!(
+            "日志捕获 - {} 个, 共 {} → {}",
+            manager.captures.len(),
+            format_size(manager.total_bytes()),
+            manager.session_dir.display()
+        )
+    } else {
+        format!(
+            "Log Captures - {}, {} total → {}",
+            manager.captures.len(),
+            format_size(manager.total_bytes()),
+            manager.session_dir.display()
+        )
+    };
+    let block = Block::default().borders(Borders::ALL).title(title);
+
+    if manager.captures.is_empty() {
+        let text = if chinese {
+            "没有进行中的捕获，在 Pod 列表中按 C 开始捕获选中的 Pod"
+        } else {
+            "No active captures. Press C in the pod list to capture the selected pod"
+        };
+        let widget = Paragraph::new(text)
+            .block(block)
+            .style(Style::default().fg(Color::Gray));
+        f.render_widget(widget, area);
+        return;
+    }
+
+    let header = if chinese {
+        ["Pod", "所属", "文件", "当前文件", "共写入", "时长", "状态"]
+    } else {
+        [
+            "Pod", "Owner", "File", "Current", "Written", "Elapsed", "Status",
+        ]
+    };
+    let rows: Vec<Row> = manager
+        .captures
+        .iter()
+        .map(|capture| {
+            let pod = if capture.switches > 0 {
+                format!(
+                    "{}/{} (↻{})",
+                    capture.namespace, capture.pod, capture.switches
+                )
+            } else {
+                format!("{}/{}", capture.namespace, capture.pod)
+            };
+            let file = capture
+                .path
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_default();
+            Row::new(vec![
+                Cell::from(pod),
+                Cell::from(capture.owner.clone().unwrap_or_else(|| "-".to_string())),
+                Cell::from(file),
+                Cell::from(format_size(capture.file_bytes)),
+                Cell::from(format_size(capture.total_bytes)),
+                Cell::from(elapsed_text(capture)),
+                status_cell(capture, chinese),
+            ])
+        })
+        .collect();
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Min(24),
+            Constraint::Length(20),
+            Constraint::Min(20),
+            Constraint::Length(10),
+            Constraint::Length(10),
+            Constraint::Length(8),
+            Constraint::Min(12),
+        ],
+    )
+    .header(Row::new(header).style(Style::default().fg(Color::Cyan)))
+    .row_highlight_style(
+        Style::default()
+            .fg(Color::Yellow)
+            .add_modifier(Modifier::BOLD),
+    )
+    .block(block);
+    let mut state = TableState::default().with_selected(Some(manager.selected));
+    f.render_stateful_widget(table, area, &mut state);
+}
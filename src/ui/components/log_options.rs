@@ -0,0 +1,105 @@
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::Line,
+    widgets::{Block, Borders, Clear, Paragraph},
+};
+
+use super::centered_area;
+use crate::app::state::AppState;
+
+fn row_line(label: &str, value: String, selected: bool) -> Line<'static> {
+    let text = format!("{:<12} {}", label, value);
+    if selected {
+        Line::styled(
+            format!("▶ {}", text),
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        )
+    } else {
+        Line::styled(format!("  {}", text), Style::default().fg(Color::White))
+    }
+}
+
+pub fn render(f: &mut Frame, area: Rect, app: &AppState) {
+    let Some(dialog) = &app.log_options_dialog else {
+        return;
+    };
+    let chinese = app.language_chinese;
+    let options = &dialog.options;
+
+    let checkbox = |on: bool| if on { "[x]" } else { "[ ]" }.to_string();
+    let cursor = |row: usize| if dialog.selected == row { "█" } else { "" };
+    let container = if options.all_containers {
+        if chinese {
+            "◀ 全部容器 (带前缀) ▶".to_string()
+        } else {
+            "◀ all containers (prefixed) ▶".to_string()
+        }
+    } else {
+        match &options.container {
+            Some(container) => format!("◀ {} ▶", container),
+            None if chinese => "◀ 默认容器 ▶".to_string(),
+            None => "◀ default container ▶".to_string(),
+        }
+    };
+    let since = if options.since.is_empty() && dialog.selected != 2 {
+        if chinese {
+            "不限".to_string()
+        } else {
+            "any".to_string()
+        }
+    } else {
+        format!("{}{}", options.since, cursor(2))
+    };
+
+    let labels = if chinese {
+        ["容器", "上一个实例", "起始时间", "时间戳", "尾部行数"]
+    } else {
+        ["Container", "Previous", "Since", "Timestamps", "Tail"]
+    };
+    let mut lines = vec![
+        row_line(labels[0], container, dialog.selected == 0),
+        row_line(labels[1], checkbox(options.previous), dialog.selected == 1),
+        row_line(labels[2], since, dialog.selected == 2),
+        row_line(
+            labels[3],
+            checkbox(options.timestamps),
+            dialog.selected == 3,
+        ),
+        row_line(
+            labels[4],
+            format!("{}{}", dialog.tail_input, cursor(4)),
+            dialog.selected == 4,
+        ),
+        Line::from(""),
+    ];
+    match &dialog.error {
+        Some(error) => lines.push(Line::styled(error.clone(), Style::default().fg(Color::Red))),
+        None => lines.push(Line::styled(
+            if chinese {
+                "起始时间: 时长如 10m、1h30m，或 RFC3339 时间"
+            } else {
+                "Since: a duration like 10m / 1h30m, or an RFC3339 time"
+            },
+            Style::default().fg(Color::Gray),
+        )),
+    }
+
+    let title = if chinese {
+        "日志选项 (j/k:导航 Space/←→:切换 Enter:应用 Esc:取消)"
+    } else {
+        "Log Options (j/k:nav Space/←→:toggle Enter:apply Esc:cancel)"
+    };
+    let popup = centered_area(area, 72, lines.len() as u16 + 2);
+    f.render_widget(Clear, popup);
+    let paragraph = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(title)
+            .style(Style::default().fg(Color::Yellow)),
+    );
+    f.render_widget(paragraph, popup);
+}
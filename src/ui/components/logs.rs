@@ -1,10 +1,305 @@
+use super::centered_percent;
+use crate::app::ansi::{parse_ansi, strip_ansi};
+use crate::app::log_search::LogViewLine;
 use crate::app::state::{ActivePane, AppState};
+use crate::app::structured_log::{LogLevel, StructuredLine, pretty};
 use ratatui::{
     Frame,
     layout::{Constraint, Direction, Layout, Rect},
-    style::{Color, Style},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
     widgets::{Block, Borders, Clear, Paragraph, Scrollbar, ScrollbarOrientation, ScrollbarState, Wrap},
 };
+use regex::Regex;
+
+// 跟随模式状态：跟随中 / 已暂停 / 重连中
+fn follow_status(app: &AppState) -> String {
+    if !app.streaming_logs {
+        return String::new();
+    }
+    let chinese = app.language_chinese;
+    if app.logs_paused {
+        return if chinese {
+            format!(" [已暂停 +{}]", app.logs_new_while_paused)
+        } else {
+            format!(" [Paused +{} new]", app.logs_new_while_paused)
+        };
+    }
+    match &app.log_stream {
+        Some(stream) if !stream.is_connected() && stream.reconnects() > 0 => {
+            let reason = stream
+                .last_error()
+                .map(|e| format!(": {}", e.lines().last().unwrap_or_default()))
+                .unwrap_or_default();
+            if chinese {
+                format!(" [重连中{}]", reason)
+            } else {
+                format!(" [Reconnecting{}]", reason)
+            }
+        }
+        _ => {
+            if chinese {
+                " [跟随]".to_string()
+            } else {
+                " [Following]".to_string()
+            }
+        }
+    }
+}
+
+// 搜索状态：/查询 当前/总数，以及正则、大小写和 grep 开关
+fn search_status(app: &AppState) -> String {
+    if app.log_search_query.is_empty() {
+        return String::new();
+    }
+    let position = if app.log_search_results.is_empty() {
+        "0/0".to_string()
+    } else {
+        format!(
+            "{}/{}",
+            app.current_log_search_index + 1,
+            app.log_search_results.len()
+        )
+    };
+    let mut flags = String::new();
+    if app.log_search_regex {
+        flags.push_str(" regex");
+    }
+    if app.log_search_case_sensitive {
+        flags.push_str(" Aa");
+    }
+    if app.log_grep_mode {
+        flags.push_str(&format!(" grep ±{}", app.log_grep_context));
+    }
+    format!(" [/{} {}{}]", app.log_search_query, position, flags)
+}
+
+// 主日志窗格标题后缀：来源选项 + 跟随状态 + 级别过滤 + 搜索状态
+fn log_status(app: &AppState) -> String {
+    let level = app
+        .log_level_filter
+        .map(|level| format!(" [≥{}]", level.label()))
+        .unwrap_or_default();
+    let ansi = if app.log_ansi { "" } else { " [no color]" };
+    format!(
+        " ({}){}{}{}{}",
+        app.log_options.summary(),
+        follow_status(app),
+        level,
+        ansi,
+        search_status(app)
+    )
+}
+
+// 只构建从 scroll 起可见的 rows 行
+fn plain_lines(logs: &[String], ansi: bool, scroll: usize, rows: usize) -> Vec<Line<'static>> {
+    logs.iter()
+        .enumerate()
+        .skip(scroll)
+        .take(rows)
+        .map(|(i, line)| {
+            let mut spans = vec![Span::raw(format!("[{}] ", i + 1))];
+            spans.extend(
+                ansi_segments(line, ansi)
+                    .into_iter()
+                    .map(|(t, s)| Span::styled(t, s)),
+            );
+            Line::from(spans)
+        })
+        .collect()
+}
+
+// 非结构化行：解析 ANSI 颜色，或去掉转义序列
+fn ansi_segments(text: &str, ansi: bool) -> Vec<(String, Style)> {
+    if ansi {
+        parse_ansi(text)
+    } else {
+        vec![(strip_ansi(text).into_owned(), Style::default())]
+    }
+}
+
+fn level_color(level: LogLevel) -> Color {
+    match level {
+        LogLevel::Error => Color::Red,
+        LogLevel::Warn => Color::Yellow,
+        LogLevel::Info => Color::Green,
+        LogLevel::Debug => Color::Blue,
+    }
+}
+
+// 一行日志拆成带样式的片段；结构化行渲染为 `time level message key=value`
+fn line_segments(text: &str, parsed: Option<&StructuredLine>, ansi: bool) -> Vec<(String, Style)> {
+    let Some(parsed) = parsed else {
+        return ansi_segments(text, ansi);
+    };
+    let mut segments = Vec::new();
+    if !parsed.prefix.is_empty() {
+        segments.push((parsed.prefix.clone(), Style::default().fg(Color::DarkGray)));
+    }
+    if let Some(time) = &parsed.time {
+        segments.push((format!("{} ", time), Style::default().fg(Color::DarkGray)));
+    }
+    if let Some(level) = parsed.level {
+        segments.push((
+            format!("{:<5} ", level.label()),
+            Style::default()
+                .fg(level_color(level))
+                .add_modifier(Modifier::BOLD),
+        ));
+    }
+    if let Some(message) = &parsed.message {
+        segments.push((message.clone(), Style::default()));
+    }
+    for (key, value) in &parsed.fields {
+        segments.push((format!(" {}=", key), Style::default().fg(Color::Cyan)));
+        segments.push((value.clone(), Style::default().fg(Color::Gray)));
+    }
+    segments
+}
+
+// 在片段内高亮搜索匹配（跨片段的匹配不高亮）
+fn highlight(segments: Vec<(String, Style)>, pattern: &Regex, style: Style) -> Vec<Span<'static>> {
+    let mut spans = Vec::new();
+    for (text, base) in segments {
+        let mut last = 0;
+        for m in pattern.find_iter(&text) {
+            if m.start() == m.end() {
+                continue;
+            }
+            spans.push(Span::styled(text[last..m.start()].to_string(), base));
+            spans.push(Span::styled(m.as_str().to_string(), style));
+            last = m.end();
+        }
+        spans.push(Span::styled(text[last..].to_string(), base));
+    }
+    spans
+}
+
+// 主日志窗格：结构化渲染、级别过滤、搜索高亮（当前匹配更醒目）和 grep 上下文置灰。
+// 返回视图总行数和从 logs_scroll 起可见的 rows 行
+fn main_lines(app: &AppState, rows: usize) -> (usize, Vec<Line<'static>>) {
+    let view = app.log_view();
+    if view.is_empty() && !app.logs.is_empty() {
+        let text = if app.language_chinese {
+            "没有匹配的行"
+        } else {
+            "No matching lines"
+        };
+        let line = Line::styled(text, Style::default().fg(Color::Gray));
+        return (1, vec![line]);
+    }
+    let current = app
+        .log_search_results
+        .get(app.current_log_search_index)
+        .copied();
+    let match_style = Style::default().fg(Color::Black).bg(Color::Yellow);
+    let current_style = Style::default()
+        .fg(Color::Black)
+        .bg(Color::LightRed)
+        .add_modifier(Modifier::BOLD);
+
+    let lines = view
+        .iter()
+        .skip(app.logs_scroll)
+        .take(rows)
+        .filter_map(|&view_line| {
+            let index = match view_line {
+                LogViewLine::Line(index) => index,
+                LogViewLine::Separator => {
+                    return Some(Line::styled("--", Style::default().fg(Color::DarkGray)));
+                }
+            };
+            let text = app.logs.get(index)?;
+            let parsed = app
+                .parsed_logs
+                .get(index)
+                .filter(|_| app.log_structured)
+                .and_then(|parsed| parsed.structured.as_ref());
+            let segments = line_segments(text, parsed, app.log_ansi);
+            let mut spans = vec![Span::raw(format!("[{}] ", index + 1))];
+            let mut line_style = Style::default();
+            match &app.log_search_pattern {
+                Some(pattern) => {
+                    let style = if Some(index) == current {
+                        current_style
+                    } else {
+                        match_style
+                    };
+                    spans.extend(highlight(segments, pattern, style));
+                    if app.log_grep_mode && app.log_search_results.binary_search(&index).is_err() {
+                        line_style = Style::default().fg(Color::Gray);
+                    }
+                }
+                None => spans.extend(segments.into_iter().map(|(t, s)| Span::styled(t, s))),
+            }
+            Some(Line::from(spans).style(line_style))
+        })
+        .collect();
+    (view.len(), lines)
+}
+
+// 展开的日志行：JSON 格式化显示，logfmt 每个键一行
+fn render_expanded(f: &mut Frame, area: Rect, app: &AppState) {
+    let Some(index) = app.log_expanded else {
+        return;
+    };
+    let Some(line) = app.logs.get(index) else {
+        return;
+    };
+    let popup = centered_percent(area, 80, 70);
+    f.render_widget(Clear, popup);
+    let title = if app.language_chinese {
+        format!("日志行 {} (j/k:滚动 e/Esc:关闭)", index + 1)
+    } else {
+        format!("Log line {} (j/k:scroll e/Esc:close)", index + 1)
+    };
+    let text: Vec<Line> = pretty(line).into_iter().map(Line::raw).collect();
+    let paragraph = Paragraph::new(text)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(title)
+                .style(Style::default().fg(Color::Yellow)),
+        )
+        .style(Style::default().fg(Color::White))
+        .wrap(Wrap { trim: false })
+        .scroll((app.log_expanded_scroll as u16, 0));
+    f.render_widget(paragraph, popup);
+}
+
+// 输入搜索时在日志下方显示的输入框
+fn render_search_input(f: &mut Frame, area: Rect, app: &AppState) {
+    let on_off = |on: bool| if on { "on" } else { "off" };
+    let (title, style) = match &app.log_search_error {
+        Some(error) => (
+            error.lines().last().unwrap_or_default().to_string(),
+            Style::default().fg(Color::Red),
+        ),
+        None if app.language_chinese => (
+            format!(
+                "搜索日志 (Enter:确认 Esc:清除 Ctrl+R 正则:{} Ctrl+T 区分大小写:{})",
+                on_off(app.log_search_regex),
+                on_off(app.log_search_case_sensitive)
+            ),
+            Style::default().fg(Color::Yellow),
+        ),
+        None => (
+            format!(
+                "Search logs (Enter:done Esc:clear Ctrl+R regex:{} Ctrl+T case:{})",
+                on_off(app.log_search_regex),
+                on_off(app.log_search_case_sensitive)
+            ),
+            Style::default().fg(Color::Yellow),
+        ),
+    };
+    let paragraph = Paragraph::new(format!("/{}█", app.log_search_query)).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(title)
+            .style(style),
+    );
+    f.render_widget(paragraph, area);
+}
 
 fn log_pane_title(app: &AppState, pod_name: &str, pane: &ActivePane) -> String {
     let marker = match pane {
@@ -12,35 +307,51 @@ fn log_pane_title(app: &AppState, pod_name: &str, pane: &ActivePane) -> String {
         ActivePane::Right if app.active_pane == ActivePane::Right => "◉",
         _ => " ",
     };
+    // 分屏右侧是默认选项的快照，只在主日志窗格显示选项和跟随状态
+    let status = match pane {
+        ActivePane::Left => log_status(app),
+        ActivePane::Right => String::new(),
+    };
     if app.language_chinese {
-        format!("{} 日志 - {}/{}", marker, app.current_namespace, pod_name)
+        format!(
+            "{} 日志 - {}/{}{}",
+            marker, app.current_namespace, pod_name, status
+        )
     } else {
-        format!("{} Logs - {}/{}", marker, app.current_namespace, pod_name)
+        format!(
+            "{} Logs - {}/{}{}",
+            marker, app.current_namespace, pod_name, status
+        )
     }
 }
 
-fn render_log_pane(f: &mut Frame, area: Rect, logs: &[String], scroll: usize, title: &str) {
-    if logs.is_empty() {
+// 日志窗格边框内的行数
+fn pane_rows(area: Rect) -> usize {
+    area.height.saturating_sub(2) as usize
+}
+
+// lines 为从 scroll 起可见的行，total 为全部行数（用于滚动条）
+fn render_log_pane(
+    f: &mut Frame,
+    area: Rect,
+    total: usize,
+    lines: Vec<Line<'static>>,
+    scroll: usize,
+    title: &str,
+) {
+    if total == 0 {
         let widget = Paragraph::new("Loading logs...")
             .block(Block::default().borders(Borders::ALL).title(title))
             .style(Style::default().fg(Color::Gray));
         f.render_widget(widget, area);
         return;
     }
-    let content = logs
-        .iter()
-        .enumerate()
-        .map(|(i, line)| format!("[{}] {}", i + 1, line))
-        .collect::<Vec<_>>()
-        .join("\n");
-    let paragraph = Paragraph::new(content)
+    let paragraph = Paragraph::new(lines)
         .block(Block::default().borders(Borders::ALL).title(title))
         .style(Style::default().fg(Color::White))
-        .wrap(Wrap { trim: true })
-        .scroll((scroll as u16, 0));
+        .wrap(Wrap { trim: true });
     f.render_widget(paragraph, area);
-    let total = logs.len();
-    let visible = area.height.saturating_sub(2) as usize;
+    let visible = pane_rows(area);
     if total > visible {
         let mut state = ScrollbarState::default()
             .content_length(total)
@@ -61,16 +372,8 @@ fn render_log_pane(f: &mut Frame, area: Rect, logs: &[String], scroll: usize, ti
     }
 }
 
-fn popup_area(area: Rect, percent_x: u16, percent_y: u16) -> Rect {
-    let popup_width = area.width * percent_x / 100;
-    let popup_height = area.height * percent_y / 100;
-    let x = area.x + (area.width.saturating_sub(popup_width)) / 2;
-    let y = area.y + (area.height.saturating_sub(popup_height)) / 2;
-    Rect::new(x, y, popup_width, popup_height)
-}
-
 fn render_pod_picker(f: &mut Frame, area: Rect, app: &AppState) {
-    let picker_area = popup_area(area, 60, 70);
+    let picker_area = centered_percent(area, 60, 70);
     f.render_widget(Clear, picker_area);
 
     let pod_names: Vec<String> = app
@@ -107,6 +410,16 @@ fn render_pod_picker(f: &mut Frame, area: Rect, app: &AppState) {
 }
 
 pub fn render(f: &mut Frame, area: Rect, app: &AppState) {
+    let area = if app.log_search_mode {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(0), Constraint::Length(3)])
+            .split(area);
+        render_search_input(f, chunks[1], app);
+        chunks[0]
+    } else {
+        area
+    };
     if app.split_pod_selection_mode {
         // 先渲染当前日志作为背景
         let name = app
@@ -114,12 +427,14 @@ pub fn render(f: &mut Frame, area: Rect, app: &AppState) {
             .get(app.selected_pod_index)
             .map(|p| p.name.as_str())
             .unwrap_or("?");
+        let status = log_status(app);
         let title = if app.language_chinese {
-            format!("日志 - {}/{}", app.current_namespace, name)
+            format!("日志 - {}/{}{}", app.current_namespace, name, status)
         } else {
-            format!("Logs - {}/{}", app.current_namespace, name)
+            format!("Logs - {}/{}{}", app.current_namespace, name, status)
         };
-        render_log_pane(f, area, &app.logs, app.logs_scroll, &title);
+        let (total, lines) = main_lines(app, pane_rows(area));
+        render_log_pane(f, area, total, lines, app.logs_scroll, &title);
         // 叠加 Pod 选择弹窗
         render_pod_picker(f, area, app);
     } else if app.split_log_mode {
@@ -132,17 +447,26 @@ pub fn render(f: &mut Frame, area: Rect, app: &AppState) {
             .get(app.selected_pod_index)
             .map(|p| p.name.as_str())
             .unwrap_or("?");
+        let (total, lines) = main_lines(app, pane_rows(panes[0]));
         render_log_pane(
             f,
             panes[0],
-            &app.logs,
+            total,
+            lines,
             app.logs_scroll,
             &log_pane_title(app, left_name, &ActivePane::Left),
         );
+        let lines = plain_lines(
+            &app.split_log_content,
+            app.log_ansi,
+            app.split_log_scroll,
+            pane_rows(panes[1]),
+        );
         render_log_pane(
             f,
             panes[1],
-            &app.split_log_content,
+            app.split_log_content.len(),
+            lines,
             app.split_log_scroll,
             &log_pane_title(app, &app.split_log_pod_name, &ActivePane::Right),
         );
@@ -152,11 +476,14 @@ pub fn render(f: &mut Frame, area: Rect, app: &AppState) {
             .get(app.selected_pod_index)
             .map(|p| p.name.as_str())
             .unwrap_or("?");
+        let status = log_status(app);
         let title = if app.language_chinese {
-            format!("日志 - {}/{}", app.current_namespace, name)
+            format!("日志 - {}/{}{}", app.current_namespace, name, status)
         } else {
-            format!("Logs - {}/{}", app.current_namespace, name)
+            format!("Logs - {}/{}{}", app.current_namespace, name, status)
         };
-        render_log_pane(f, area, &app.logs, app.logs_scroll, &title);
+        let (total, lines) = main_lines(app, pane_rows(area));
+        render_log_pane(f, area, total, lines, app.logs_scroll, &title);
     }
+    render_expanded(f, area, app);
 }
@@ -1,18 +1,50 @@
+pub mod configmap_detail;
 pub mod configmap_list;
 pub mod confirm;
 pub mod daemonset_list;
 pub mod deployment_list;
 pub mod describe;
+pub mod exec_picker;
+pub mod file_browser;
 pub mod help;
 pub mod job_list;
+pub mod log_captures;
+pub mod log_options;
 pub mod logs;
+pub mod multi_logs;
 pub mod namespace_list;
+pub mod node_detail;
+pub mod node_drain;
 pub mod node_list;
 pub mod pod_list;
 pub mod pv_list;
 pub mod pvc_list;
+pub mod save_dialog;
 pub mod search;
+pub mod secret_detail;
 pub mod secret_list;
 pub mod service_list;
+pub mod terminal;
 pub mod top_view;
 pub mod yaml_view;
+
+use ratatui::layout::Rect;
+
+/// 在 area 中居中的弹窗区域，超出时收缩到 area 内
+pub fn centered_area(area: Rect, width: u16, height: u16) -> Rect {
+    let width = width.min(area.width);
+    let height = height.min(area.height);
+    let x = area.x + (area.width - width) / 2;
+    let y = area.y + (area.height - height) / 2;
+    Rect::new(x, y, width, height)
+}
+
+/// 按 area 宽高的百分比居中的弹窗区域
+pub fn centered_percent(area: Rect, percent_x: u16, percent_y: u16) -> Rect {
+    let scale = |length: u16, percent: u16| (u32::from(length) * u32::from(percent) / 100) as u16;
+    centered_area(
+        area,
+        scale(area.width, percent_x),
+        scale(area.height, percent_y),
+    )
+}
@@ -0,0 +1,150 @@
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Scrollbar, ScrollbarOrientation, ScrollbarState},
+};
+
+use crate::app::ansi::{parse_ansi, strip_ansi};
+use crate::app::multi_log::AggregatedLine;
+use crate::app::state::AppState;
+
+const PALETTE: [Color; 8] = [
+    Color::Cyan,
+    Color::Green,
+    Color::Yellow,
+    Color::Magenta,
+    Color::Blue,
+    Color::LightRed,
+    Color::LightGreen,
+    Color::LightCyan,
+];
+
+// 同一 pod/container 始终使用同一颜色
+fn source_color(source: &str) -> Color {
+    let hash = source.bytes().fold(0usize, |acc, b| {
+        acc.wrapping_mul(31).wrapping_add(b as usize)
+    });
+    PALETTE[hash % PALETTE.len()]
+}
+
+fn line_spans(line: &AggregatedLine, ansi: bool) -> Line<'static> {
+    let source = line.source();
+    // 只显示到秒的时间，完整时间用于排序
+    let time = line.timestamp.get(11..19).unwrap_or_default();
+    let mut spans = vec![
+        Span::styled(format!("{} ", time), Style::default().fg(Color::DarkGray)),
+        Span::styled(
+            format!("{} ", source),
+            Style::default().fg(source_color(&source)),
+        ),
+    ];
+    if ansi {
+        spans.extend(
+            parse_ansi(&line.text)
+                .into_iter()
+                .map(|(t, s)| Span::styled(t, s)),
+        );
+    } else {
+        spans.push(Span::raw(strip_ansi(&line.text).into_owned()));
+    }
+    Line::from(spans)
+}
+
+pub fn render(f: &mut Frame, area: Rect, app: &AppState) {
+    let Some(view) = &app.multi_log else {
+        return;
+    };
+    let chinese = app.language_chinese;
+
+    let auto = if view.auto_scroll {
+        ""
+    } else if chinese {
+        " [自动滚动关闭]"
+    } else {
+        " [auto-scroll off]"
+    };
+    let title = if chinese {
+        format!(
+            "聚合日志 - {}/{} ({} 个 Pod){}",
+            view.namespace,
+            view.label,
+            view.streams.len(),
+            auto
+        )
+    } else {
+        format!(
+            "Aggregated Logs - {}/{} ({} pods){}",
+            view.namespace,
+            view.label,
+            view.streams.len(),
+            auto
+        )
+    };
+    let block = Block::default().borders(Borders::ALL).title(title);
+
+    if view.lines.is_empty() {
+        let text = match &view.error {
+            Some(error) => error.clone(),
+            None if view.last_discovery.is_some() && view.streams.is_empty() => {
+                if chinese {
+                    format!("没有匹配 {} 的 Pod", view.selector)
+                } else {
+                    format!("No pods match {}", view.selector)
+                }
+            }
+            None if chinese => "等待日志...".to_string(),
+            None => "Waiting for logs...".to_string(),
+        };
+        let widget = Paragraph::new(text)
+            .block(block)
+            .style(Style::default().fg(Color::Gray));
+        f.render_widget(widget, area);
+        return;
+    }
+
+    // 让 scroll 指向的行落在窗口底部
+    let height = area.height.saturating_sub(2) as usize;
+    let end = (view.scroll + 1).min(view.lines.len());
+    let start = end.saturating_sub(height);
+    let lines: Vec<Line> = view.lines[start..end]
+        .iter()
+        .map(|line| line_spans(line, app.log_ansi))
+        .collect();
+    f.render_widget(Paragraph::new(lines).block(block), area);
+
+    let mut scrollbar_state = ScrollbarState::new(view.lines.len()).position(view.scroll);
+    f.render_stateful_widget(
+        Scrollbar::new(ScrollbarOrientation::VerticalRight),
+        area,
+        &mut scrollbar_state,
+    );
+}
+
+pub fn render_selector_prompt(f: &mut Frame, area: Rect, app: &AppState) {
+    let Some(input) = &app.multi_log_selector_input else {
+        return;
+    };
+    let width = area.width * 70 / 100;
+    let popup = Rect::new(
+        area.x + (area.width.saturating_sub(width)) / 2,
+        area.y + area.height.saturating_sub(3) / 2,
+        width,
+        3.min(area.height),
+    );
+    f.render_widget(Clear, popup);
+
+    let title = if app.language_chinese {
+        "聚合日志 - 标签选择器，如 app=web (Enter:确认 Esc:取消)"
+    } else {
+        "Aggregated Logs - label selector, e.g. app=web (Enter:ok Esc:cancel)"
+    };
+    let paragraph = Paragraph::new(format!("-l {}█", input)).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(title)
+            .style(Style::default().fg(Color::Yellow)),
+    );
+    f.render_widget(paragraph, popup);
+}
@@ -0,0 +1,311 @@
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState},
+};
+
+use crate::app::state::AppState;
+use crate::kubectl::quantity::{format_bytes, format_cpu, percentage};
+use crate::kubectl::types::{NodeDetail, ResourceAmounts};
+
+/// 占可分配量的比例着色：≥100% 红，≥80% 黄
+fn usage_color(percent: Option<f64>) -> Color {
+    match percent {
+        Some(p) if p >= 100.0 => Color::Red,
+        Some(p) if p >= 80.0 => Color::Yellow,
+        Some(_) => Color::Green,
+        None => Color::Gray,
+    }
+}
+
+fn with_percent(value: String, percent: Option<f64>) -> String {
+    match percent {
+        Some(p) => format!("{} ({:.0}%)", value, p),
+        None => value,
+    }
+}
+
+/// 中文名、英文名、取值、格式化
+type ResourceRow = (
+    &'static str,
+    &'static str,
+    fn(&ResourceAmounts) -> f64,
+    fn(f64) -> String,
+);
+
+fn resource_rows(detail: &NodeDetail, chinese: bool) -> Vec<Row<'static>> {
+    let resources: [ResourceRow; 4] = [
+        ("CPU", "CPU", |r| r.cpu, format_cpu),
+        ("内存", "Memory", |r| r.memory, format_bytes),
+        ("Pod 数", "Pods", |r| r.pods, |v| format!("{:.0}", v)),
+        (
+            "临时存储",
+            "Ephemeral storage",
+            |r| r.ephemeral_storage,
+            format_bytes,
+        ),
+    ];
+    resources
+        .iter()
+        .map(|(zh, en, get, format)| {
+            let allocatable = get(&detail.allocatable);
+            let requests = get(&detail.requests);
+            let limits = get(&detail.limits);
+            let request_percent = percentage(requests, allocatable);
+            let limit_percent = percentage(limits, allocatable);
+            Row::new(vec![
+                Cell::from(if chinese { *zh } else { *en }),
+                Cell::from(format(get(&detail.capacity))),
+                Cell::from(format(allocatable)),
+                Cell::from(with_percent(format(requests), request_percent))
+                    .style(Style::default().fg(usage_color(request_percent))),
+                Cell::from(with_percent(format(limits), limit_percent))
+                    .style(Style::default().fg(usage_color(limit_percent))),
+            ])
+        })
+        .collect()
+}
+
+fn render_resources(
+    f: &mut Frame,
+    area: Rect,
+    detail: &NodeDetail,
+    error: Option<&str>,
+    chinese: bool,
+) {
+    let header = if chinese {
+        ["资源", "容量", "可分配", "请求", "限制"]
+    } else {
+        ["Resource", "Capacity", "Allocatable", "Requests", "Limits"]
+    };
+    let mut title = if chinese {
+        format!("节点 {} - 容量与分配（百分比相对可分配量）", detail.name)
+    } else {
+        format!(
+            "Node {} - capacity & allocation (% of allocatable)",
+            detail.name
+        )
+    };
+    // 刷新失败时保留上次结果并提示错误
+    if let Some(error) = error {
+        title = format!("{} - {}", title, error);
+    }
+    let table = Table::new(
+        resource_rows(detail, chinese),
+        [
+            Constraint::Length(18),
+            Constraint::Length(12),
+            Constraint::Length(12),
+            Constraint::Length(18),
+            Constraint::Length(18),
+        ],
+    )
+    .header(Row::new(header).style(Style::default().fg(Color::Cyan)))
+    .block(Block::default().borders(Borders::ALL).title(title));
+    f.render_widget(table, area);
+}
+
+fn render_conditions(f: &mut Frame, area: Rect, detail: &NodeDetail, chinese: bool) {
+    let mut lines: Vec<Line> = detail
+        .conditions
+        .iter()
+        .map(|condition| {
+            let color = if condition.is_problem() {
+                Color::Red
+            } else {
+                Color::Green
+            };
+            let mut spans = vec![
+                Span::raw(format!("{:<20}", condition.type_)),
+                Span::styled(condition.status.clone(), Style::default().fg(color)),
+            ];
+            if let Some(reason) = &condition.reason {
+                spans.push(Span::styled(
+                    format!("  {}", reason),
+                    Style::default().fg(Color::Gray),
+                ));
+            }
+            Line::from(spans)
+        })
+        .collect();
+    lines.push(Line::from(""));
+    let taints_label = if chinese { "污点:" } else { "Taints:" };
+    if detail.taints.is_empty() {
+        lines.push(Line::raw(format!("{} <none>", taints_label)));
+    } else {
+        lines.push(Line::raw(taints_label));
+        lines.extend(
+            detail.taints.iter().map(|taint| {
+                Line::styled(format!("  {}", taint), Style::default().fg(Color::Yellow))
+            }),
+        );
+    }
+    let title = if chinese {
+        "状况与污点"
+    } else {
+        "Conditions & taints"
+    };
+    let paragraph =
+        Paragraph::new(lines).block(Block::default().borders(Borders::ALL).title(title));
+    f.render_widget(paragraph, area);
+}
+
+fn render_labels(f: &mut Frame, area: Rect, detail: &NodeDetail, chinese: bool) {
+    let lines: Vec<Line> = detail
+        .labels
+        .iter()
+        .map(|(key, value)| {
+            Line::from(vec![
+                Span::styled(key.clone(), Style::default().fg(Color::Cyan)),
+                Span::raw(format!("={}", value)),
+            ])
+        })
+        .collect();
+    let title = if chinese { "标签" } else { "Labels" };
+    let paragraph =
+        Paragraph::new(lines).block(Block::default().borders(Borders::ALL).title(title));
+    f.render_widget(paragraph, area);
+}
+
+fn render_pods(f: &mut Frame, area: Rect, detail: &NodeDetail, selected: usize, chinese: bool) {
+    let header = if chinese {
+        [
+            "命名空间",
+            "Pod",
+            "状态",
+            "CPU 请求",
+            "CPU 限制",
+            "内存请求",
+            "内存限制",
+        ]
+    } else {
+        [
+            "Namespace",
+            "Pod",
+            "Phase",
+            "CPU Req",
+            "CPU Lim",
+            "Mem Req",
+            "Mem Lim",
+        ]
+    };
+    // 未设置的请求/限制显示为 -
+    let cpu = |v: f64| {
+        if v > 0.0 {
+            format_cpu(v)
+        } else {
+            "-".to_string()
+        }
+    };
+    let memory = |v: f64| {
+        if v > 0.0 {
+            format_bytes(v)
+        } else {
+            "-".to_string()
+        }
+    };
+    let rows: Vec<Row> = detail
+        .pods
+        .iter()
+        .map(|pod| {
+            let phase_color = match pod.phase.as_str() {
+                "Running" => Color::Green,
+                "Pending" => Color::Yellow,
+                _ => Color::Gray,
+            };
+            Row::new(vec![
+                Cell::from(pod.namespace.clone()),
+                Cell::from(pod.name.clone()),
+                Cell::from(pod.phase.clone()).style(Style::default().fg(phase_color)),
+                Cell::from(cpu(pod.requests.cpu)),
+                Cell::from(cpu(pod.limits.cpu)),
+                Cell::from(memory(pod.requests.memory)),
+                Cell::from(memory(pod.limits.memory)),
+            ])
+        })
+        .collect();
+    let title = if chinese {
+        format!(
+            "节点上的 Pod ({}) - Enter 跳转到 Pod 列表",
+            detail.pods.len()
+        )
+    } else {
+        format!(
+            "Pods on node ({}) - Enter to jump to pod list",
+            detail.pods.len()
+        )
+    };
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Percentage(18),
+            Constraint::Percentage(34),
+            Constraint::Percentage(10),
+            Constraint::Percentage(9),
+            Constraint::Percentage(9),
+            Constraint::Percentage(10),
+            Constraint::Percentage(10),
+        ],
+    )
+    .header(Row::new(header).style(Style::default().fg(Color::Cyan)))
+    .row_highlight_style(
+        Style::default()
+            .fg(Color::Black)
+            .bg(Color::Yellow)
+            .add_modifier(Modifier::BOLD),
+    )
+    .block(Block::default().borders(Borders::ALL).title(title));
+    let mut state = TableState::default().with_selected(Some(selected));
+    f.render_stateful_widget(table, area, &mut state);
+}
+
+pub fn render(f: &mut Frame, area: Rect, app: &AppState) {
+    let Some(view) = &app.node_detail else {
+        return;
+    };
+    let chinese = app.language_chinese;
+    let Some(detail) = &view.lifecycle.detail else {
+        let (text, color) = match &view.lifecycle.error {
+            Some(error) => (error.clone(), Color::Red),
+            None if chinese => ("加载中...".to_string(), Color::Gray),
+            None => ("Loading...".to_string(), Color::Gray),
+        };
+        let paragraph = Paragraph::new(text)
+            .style(Style::default().fg(color))
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(format!("Node - {}", view.node)),
+            );
+        f.render_widget(paragraph, area);
+        return;
+    };
+
+    let condition_height = (detail.conditions.len() + detail.taints.len().max(1) + 3) as u16;
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(7),
+            Constraint::Length(condition_height.max(detail.labels.len() as u16 + 2).min(14)),
+            Constraint::Min(5),
+        ])
+        .split(area);
+    render_resources(
+        f,
+        chunks[0],
+        detail,
+        view.lifecycle.error.as_deref(),
+        chinese,
+    );
+
+    let middle = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(chunks[1]);
+    render_conditions(f, middle[0], detail, chinese);
+    render_labels(f, middle[1], detail, chinese);
+
+    render_pods(f, chunks[2], detail, view.selected, chinese);
+}
@@ -0,0 +1,290 @@
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::Line,
+    widgets::{Block, Borders, Cell, Clear, Paragraph, Row, Table, TableState, Wrap},
+};
+
+use super::centered_area;
+use crate::app::node_drain::{DrainStatus, EvictionState, NodeDrain};
+use crate::app::state::AppState;
+use crate::kubectl::commands::drain_args;
+
+fn row_line(label: &str, value: String, selected: bool) -> Line<'static> {
+    let text = format!("{:<22} {}", label, value);
+    if selected {
+        Line::styled(
+            format!("▶ {}", text),
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        )
+    } else {
+        Line::styled(format!("  {}", text), Style::default().fg(Color::White))
+    }
+}
+
+pub fn render_dialog(f: &mut Frame, area: Rect, app: &AppState) {
+    let Some(dialog) = &app.drain_dialog else {
+        return;
+    };
+    let chinese = app.language_chinese;
+    let options = &dialog.options;
+    let checkbox = |on: bool| if on { "[x]" } else { "[ ]" }.to_string();
+    let cursor = |row: usize| if dialog.selected == row { "█" } else { "" };
+    let grace = if dialog.grace_input.is_empty() && dialog.selected != 2 {
+        if chinese { "Pod 默认" } else { "pod default" }.to_string()
+    } else {
+        format!("{}{}", dialog.grace_input, cursor(2))
+    };
+    let timeout = if options.timeout.is_empty() && dialog.selected != 3 {
+        if chinese {
+            "一直等待"
+        } else {
+            "wait forever"
+        }
+        .to_string()
+    } else {
+        format!("{}{}", options.timeout, cursor(3))
+    };
+
+    let labels = if chinese {
+        [
+            "忽略 DaemonSet Pod",
+            "删除 emptyDir 数据",
+            "宽限期 (秒)",
+            "超时",
+        ]
+    } else {
+        [
+            "Ignore DaemonSets",
+            "Delete emptyDir data",
+            "Grace period (s)",
+            "Timeout",
+        ]
+    };
+    let mut lines = vec![
+        row_line(
+            labels[0],
+            checkbox(options.ignore_daemonsets),
+            dialog.selected == 0,
+        ),
+        row_line(
+            labels[1],
+            checkbox(options.delete_emptydir_data),
+            dialog.selected == 1,
+        ),
+        row_line(labels[2], grace, dialog.selected == 2),
+        row_line(labels[3], timeout, dialog.selected == 3),
+        Line::from(""),
+    ];
+    match &dialog.error {
+        Some(error) => lines.push(Line::styled(error.clone(), Style::default().fg(Color::Red))),
+        None => lines.push(Line::styled(
+            if chinese {
+                "节点会先被 cordon，再逐个驱逐 Pod（遵守 PodDisruptionBudget）"
+            } else {
+                "The node is cordoned first, then pods are evicted (PodDisruptionBudgets apply)"
+            },
+            Style::default().fg(Color::Gray),
+        )),
+    }
+
+    let title = if chinese {
+        format!(
+            "排空节点 {} (j/k:导航 Space:切换 Enter:开始 Esc:取消)",
+            dialog.node
+        )
+    } else {
+        format!(
+            "Drain node {} (j/k:nav Space:toggle Enter:start Esc:cancel)",
+            dialog.node
+        )
+    };
+    let popup = centered_area(area, 84, lines.len() as u16 + 2);
+    f.render_widget(Clear, popup);
+    let paragraph = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(title)
+            .style(Style::default().fg(Color::Red)),
+    );
+    f.render_widget(paragraph, popup);
+}
+
+fn summary_lines(drain: &NodeDrain, chinese: bool) -> Vec<Line<'static>> {
+    let progress = drain.progress();
+    let elapsed = progress
+        .finished
+        .unwrap_or_else(std::time::Instant::now)
+        .duration_since(drain.started)
+        .as_secs();
+    let (status, color) = match &progress.status {
+        DrainStatus::Running if chinese => ("排空中".to_string(), Color::Yellow),
+        DrainStatus::Running => ("draining".to_string(), Color::Yellow),
+        DrainStatus::Succeeded if chinese => ("已完成".to_string(), Color::Green),
+        DrainStatus::Succeeded => ("drained".to_string(), Color::Green),
+        DrainStatus::Cancelled if chinese => ("已取消".to_string(), Color::Gray),
+        DrainStatus::Cancelled => ("cancelled".to_string(), Color::Gray),
+        DrainStatus::Failed(error) => (error.clone(), Color::Red),
+    };
+    let evicted = progress.count(|s| *s == EvictionState::Evicted);
+    let retrying = progress.count(|s| matches!(s, EvictionState::Retrying(_)));
+    let evicting = progress.count(|s| *s == EvictionState::Evicting);
+    let counts = if chinese {
+        format!(
+            "已驱逐 {} • 驱逐中 {} • 重试中 {} • 被 PDB 阻止 {} • 忽略 {} • {}s",
+            evicted,
+            evicting,
+            retrying,
+            progress.pdb_blocked(),
+            progress.ignored.len(),
+            elapsed
+        )
+    } else {
+        format!(
+            "evicted {} • evicting {} • retrying {} • blocked by PDB {} • ignored {} • {}s",
+            evicted,
+            evicting,
+            retrying,
+            progress.pdb_blocked(),
+            progress.ignored.len(),
+            elapsed
+        )
+    };
+    let cordon = match (progress.cordoned, chinese) {
+        (true, true) => " • 已 cordon",
+        (true, false) => " • cordoned",
+        _ => "",
+    };
+    vec![
+        Line::from(vec![
+            ratatui::text::Span::styled(
+                status,
+                Style::default().fg(color).add_modifier(Modifier::BOLD),
+            ),
+            ratatui::text::Span::raw(cordon),
+        ]),
+        Line::raw(counts),
+        Line::styled(
+            format!(
+                "kubectl {}",
+                drain_args(&drain.node, &drain.options).join(" ")
+            ),
+            Style::default().fg(Color::DarkGray),
+        ),
+    ]
+}
+
+pub fn render(f: &mut Frame, area: Rect, app: &AppState) {
+    let Some(drain) = &app.node_drain else {
+        return;
+    };
+    let chinese = app.language_chinese;
+    let progress = drain.progress();
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(5),
+            Constraint::Min(5),
+            Constraint::Length(6),
+        ])
+        .split(area);
+
+    let title = if chinese {
+        format!("排空节点 - {}", drain.node)
+    } else {
+        format!("Drain - {}", drain.node)
+    };
+    let summary = Paragraph::new(summary_lines(drain, chinese))
+        .block(Block::default().borders(Borders::ALL).title(title));
+    f.render_widget(summary, chunks[0]);
+
+    let header = if chinese {
+        ["Pod", "状态", "重试", "原因"]
+    } else {
+        ["Pod", "State", "Retries", "Reason"]
+    };
+    let rows: Vec<Row> = progress
+        .pods
+        .iter()
+        .map(|pod| {
+            let (state, reason, color) = match &pod.state {
+                EvictionState::Evicting => (
+                    if chinese { "驱逐中" } else { "evicting" },
+                    String::new(),
+                    Color::Yellow,
+                ),
+                EvictionState::Retrying(reason) if pod.pdb_blocked => (
+                    if chinese { "PDB 阻止" } else { "PDB blocked" },
+                    reason.clone(),
+                    Color::Red,
+                ),
+                EvictionState::Retrying(reason) => (
+                    if chinese { "重试中" } else { "retrying" },
+                    reason.clone(),
+                    Color::LightRed,
+                ),
+                EvictionState::Evicted => (
+                    if chinese { "已驱逐" } else { "evicted" },
+                    String::new(),
+                    Color::Green,
+                ),
+            };
+            Row::new(vec![
+                Cell::from(format!("{}/{}", pod.namespace, pod.name)),
+                Cell::from(state).style(Style::default().fg(color)),
+                Cell::from(pod.attempts.to_string()),
+                Cell::from(reason),
+            ])
+        })
+        .collect();
+    let pods_title = if chinese {
+        "Pod 驱逐进度"
+    } else {
+        "Evictions"
+    };
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Percentage(35),
+            Constraint::Length(12),
+            Constraint::Length(8),
+            Constraint::Min(20),
+        ],
+    )
+    .header(Row::new(header).style(Style::default().fg(Color::Cyan)))
+    .row_highlight_style(
+        Style::default()
+            .fg(Color::Yellow)
+            .add_modifier(Modifier::BOLD),
+    )
+    .block(Block::default().borders(Borders::ALL).title(pods_title));
+    let mut state = TableState::default().with_selected(Some(drain.selected));
+    f.render_stateful_widget(table, chunks[1], &mut state);
+
+    // 错误和被忽略的 DaemonSet Pod
+    let mut notes: Vec<Line> = progress
+        .errors
+        .iter()
+        .map(|error| Line::styled(error.clone(), Style::default().fg(Color::Red)))
+        .collect();
+    if !progress.ignored.is_empty() {
+        let label = if chinese {
+            "忽略的 DaemonSet Pod: "
+        } else {
+            "Ignored DaemonSet pods: "
+        };
+        notes.push(Line::styled(
+            format!("{}{}", label, progress.ignored.join(", ")),
+            Style::default().fg(Color::Gray),
+        ));
+    }
+    let notes_title = if chinese { "信息" } else { "Notes" };
+    let notes = Paragraph::new(notes)
+        .block(Block::default().borders(Borders::ALL).title(notes_title))
+        .wrap(Wrap { trim: true });
+    f.render_widget(notes, chunks[2]);
+}
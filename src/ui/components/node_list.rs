@@ -31,16 +31,23 @@ pub fn render(f: &mut Frame, area: Rect, app: &AppState) {
             };
 
             let status_color = match node.status.as_str() {
+                "Ready" if node.unschedulable => Color::Yellow,
                 "Ready" => Color::Green,
                 "NotReady" => Color::Red,
                 _ => Color::Gray,
             };
+            // 与 kubectl get nodes 一致
+            let status = if node.unschedulable {
+                format!("{},SchedulingDisabled", node.status)
+            } else {
+                node.status.clone()
+            };
 
             let roles_str = node.roles.join(",");
 
             Row::new(vec![
                 Cell::from(node.name.clone()),
-                Cell::from(node.status.clone()).style(Style::default().fg(status_color)),
+                Cell::from(status).style(Style::default().fg(status_color)),
                 Cell::from(roles_str),
                 Cell::from(node.age.clone()),
                 Cell::from(node.version.clone()),
@@ -54,12 +61,25 @@ pub fn render(f: &mut Frame, area: Rect, app: &AppState) {
         })
         .collect();
 
+    // 最近一次 cordon/uncordon 的结果
+    let (title, title_style) = match &app.node_action_result {
+        Some(Ok(message)) => (
+            format!("Nodes ({}) - {}", app.nodes.len(), message),
+            Style::default().fg(Color::Green),
+        ),
+        Some(Err(error)) => (
+            format!("Nodes ({}) - {}", app.nodes.len(), error),
+            Style::default().fg(Color::Red),
+        ),
+        None => (format!("Nodes ({})", app.nodes.len()), Style::default()),
+    };
+
     let table = Table::new(
         rows,
         [
             Constraint::Percentage(25),
-            Constraint::Percentage(15),
-            Constraint::Percentage(20),
+            Constraint::Percentage(22),
+            Constraint::Percentage(13),
             Constraint::Percentage(10),
             Constraint::Percentage(15),
             Constraint::Percentage(15),
@@ -83,7 +103,8 @@ pub fn render(f: &mut Frame, area: Rect, app: &AppState) {
     .block(
         Block::default()
             .borders(Borders::ALL)
-            .title(format!("Nodes ({})", app.nodes.len())),
+            .title(title)
+            .title_style(title_style),
     )
     .row_highlight_style(
         Style::default()
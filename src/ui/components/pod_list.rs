@@ -60,7 +60,17 @@ pub fn render(f: &mut Frame, area: Rect, app: &AppState) {
                 _ => Color::Gray,
             };
 
-            let name_prefix = if is_marked { "✓ " } else { "  " };
+            // ● 表示正在后台捕获日志
+            let name_prefix = if is_marked {
+                "✓ "
+            } else if app
+                .log_captures
+                .is_capturing(&app.current_namespace, &pod.name)
+            {
+                "● "
+            } else {
+                "  "
+            };
 
             Row::new(vec![
                 Cell::from(format!("{}{}", name_prefix, pod.name)),
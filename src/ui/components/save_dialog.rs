@@ -0,0 +1,87 @@
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Color, Style},
+    text::Line,
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+};
+
+use super::centered_area;
+use crate::app::save::SaveKind;
+use crate::app::state::AppState;
+
+pub fn render(f: &mut Frame, area: Rect, app: &AppState) {
+    let Some(dialog) = &app.save_dialog else {
+        return;
+    };
+    let chinese = app.language_chinese;
+
+    let what = match (dialog.kind, chinese) {
+        (SaveKind::Logs, true) => "保存日志缓冲区",
+        (SaveKind::Logs, false) => "Save log buffer",
+        (SaveKind::FullLogs, true) => "保存完整日志 (不带 --tail)",
+        (SaveKind::FullLogs, false) => "Save full logs (no --tail)",
+        (SaveKind::Describe, true) => "保存详情",
+        (SaveKind::Describe, false) => "Save describe output",
+        (SaveKind::Yaml, true) => "保存 YAML",
+        (SaveKind::Yaml, false) => "Save YAML",
+        (SaveKind::NeatYaml, true) => "导出精简清单 (可重新 apply)",
+        (SaveKind::NeatYaml, false) => "Export clean manifest (ready to apply)",
+    };
+
+    let mut lines = vec![Line::styled(
+        format!("{}█", dialog.input),
+        Style::default().fg(Color::White),
+    )];
+    let (status, color) = match &dialog.result {
+        Some(Ok(message)) => (message.clone(), Color::Green),
+        Some(Err(error)) => (error.clone(), Color::Red),
+        None if dialog.pending => (
+            if chinese { "保存中..." } else { "Saving..." }.to_string(),
+            Color::Gray,
+        ),
+        None if dialog.confirm_overwrite => (
+            if chinese {
+                "文件已存在，再按 Enter 覆盖"
+            } else {
+                "File exists, press Enter again to overwrite"
+            }
+            .to_string(),
+            Color::Yellow,
+        ),
+        None => (
+            if chinese {
+                "相对路径基于当前目录，支持 ~/"
+            } else {
+                "Relative to the current directory, ~/ is expanded"
+            }
+            .to_string(),
+            Color::Gray,
+        ),
+    };
+    lines.push(Line::styled(status, Style::default().fg(color)));
+
+    let hint = if dialog.result.is_some() {
+        if chinese {
+            "任意键关闭"
+        } else {
+            "any key to close"
+        }
+    } else if chinese {
+        "Enter:保存 Esc:取消"
+    } else {
+        "Enter:save Esc:cancel"
+    };
+    let title = format!("{} ({})", what, hint);
+    let popup = centered_area(area, 80, 6);
+    f.render_widget(Clear, popup);
+    let paragraph = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(title)
+                .style(Style::default().fg(Color::Yellow)),
+        )
+        .wrap(Wrap { trim: false });
+    f.render_widget(paragraph, popup);
+}
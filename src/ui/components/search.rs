@@ -2,8 +2,10 @@ use ratatui::{
     Frame,
     layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
+    text::{Line, Span},
     widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
 };
+use regex::Regex;
 
 use crate::app::state::{AppMode, AppState};
 
@@ -300,3 +302,51 @@ pub fn render(f: &mut Frame, area: Rect, app: &AppState) {
         f.render_stateful_widget(list, chunks[1], &mut list_state);
     }
 }
+
+/// YAML / Describe 视图：输入搜索时在内容下方显示输入框，返回剩余的内容区域
+pub fn split_content_search_input(f: &mut Frame, area: Rect, app: &AppState) -> Rect {
+    if !app.content_search.input {
+        return area;
+    }
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(3), Constraint::Length(3)])
+        .split(area);
+    let title = if app.language_chinese {
+        "搜索 (Enter:确认 Esc:清除 n/N:下一个/上一个)"
+    } else {
+        "Search (Enter:done Esc:clear n/N:next/previous)"
+    };
+    let paragraph = Paragraph::new(format!("/{}█", app.content_search.query)).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(title)
+            .style(Style::default().fg(Color::Yellow)),
+    );
+    f.render_widget(paragraph, chunks[1]);
+    chunks[0]
+}
+
+/// 在已高亮的行内标出搜索匹配（跨片段的匹配不标出），当前匹配更醒目
+pub fn highlight_matches(line: Line<'_>, pattern: &Regex, current: bool) -> Line<'static> {
+    let style = if current {
+        Style::default()
+            .fg(Color::Black)
+            .bg(Color::LightRed)
+            .add_modifier(Modifier::BOLD)
+    } else {
+        Style::default().fg(Color::Black).bg(Color::Yellow)
+    };
+    let mut spans = Vec::new();
+    for span in line.spans {
+        let text = span.content.as_ref();
+        let mut last = 0;
+        for m in pattern.find_iter(text) {
+            spans.push(Span::styled(text[last..m.start()].to_string(), span.style));
+            spans.push(Span::styled(m.as_str().to_string(), style));
+            last = m.end();
+        }
+        spans.push(Span::styled(text[last..].to_string(), span.style));
+    }
+    Line::from(spans).style(line.style)
+}
@@ -0,0 +1,295 @@
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState, Wrap},
+};
+
+use super::secret_list::expiry_color;
+use crate::app::secret_edit::{ChangeKind, SecretChange};
+use crate::app::secret_view::{MASK, SecretView, display_value};
+use crate::app::state::AppState;
+use crate::kubectl::types::SecretEntry;
+use crate::kubectl::x509::Certificate;
+
+/// 每个证书占用的行数
+const CERTIFICATE_LINES: usize = 4;
+
+/// 表格中只显示值的第一行
+fn preview(entry: &SecretEntry) -> String {
+    let value = display_value(entry);
+    let first = value.lines().next().unwrap_or_default();
+    if value.lines().nth(1).is_some() {
+        format!("{} …", first)
+    } else {
+        first.to_string()
+    }
+}
+
+fn render_entries(f: &mut Frame, area: Rect, view: &SecretView, chinese: bool) {
+    let header = if chinese {
+        ["键", "类型", "大小", "值"]
+    } else {
+        ["Key", "Kind", "Size", "Value"]
+    };
+    let rows: Vec<Row> = view
+        .entries()
+        .iter()
+        .map(|entry| {
+            let kind = match (entry.text().is_some(), chinese) {
+                (true, true) => "文本",
+                (true, false) => "text",
+                (false, true) => "二进制",
+                (false, false) => "binary",
+            };
+            let (value, color) = if view.is_revealed(&entry.key) {
+                (preview(entry), Color::Yellow)
+            } else {
+                (MASK.to_string(), Color::DarkGray)
+            };
+            Row::new(vec![
+                Cell::from(entry.key.clone()),
+                Cell::from(kind),
+                Cell::from(format!("{} B", entry.value.len())),
+                Cell::from(value).style(Style::default().fg(color)),
+            ])
+        })
+        .collect();
+
+    let type_ = view
+        .lifecycle
+        .detail
+        .as_ref()
+        .map_or("", |d| d.type_.as_str());
+    let title = format!("Secret - {}/{} ({})", view.namespace, view.name, type_);
+    let (title, title_style) = match (&view.message, &view.lifecycle.error) {
+        (_, Some(error)) => (
+            format!("{} - {}", title, error),
+            Style::default().fg(Color::Red),
+        ),
+        (Some(Ok(message)), None) => (
+            format!("{} - {}", title, message),
+            Style::default().fg(Color::Green),
+        ),
+        (Some(Err(error)), None) => (
+            format!("{} - {}", title, error),
+            Style::default().fg(Color::Red),
+        ),
+        (None, None) => (title, Style::default()),
+    };
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Percentage(30),
+            Constraint::Length(8),
+            Constraint::Length(10),
+            Constraint::Min(20),
+        ],
+    )
+    .header(
+        Row::new(header).style(
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        ),
+    )
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(title)
+            .title_style(title_style),
+    )
+    .row_highlight_style(
+        Style::default()
+            .fg(Color::Black)
+            .bg(Color::Yellow)
+            .add_modifier(Modifier::BOLD),
+    );
+    let mut state = TableState::default().with_selected(Some(view.selected));
+    f.render_stateful_widget(table, area, &mut state);
+}
+
+fn render_value(f: &mut Frame, area: Rect, view: &SecretView, chinese: bool) {
+    let Some(entry) = view.selected_entry() else {
+        return;
+    };
+    let (text, color) = if !view.is_revealed(&entry.key) {
+        let hint = if chinese {
+            "值已隐藏，按 r 显示（会记录到审计日志）"
+        } else {
+            "Value hidden, press r to reveal (recorded in the audit log)"
+        };
+        (hint.to_string(), Color::DarkGray)
+    } else {
+        (display_value(entry), Color::White)
+    };
+    let title = match (entry.text().is_some(), chinese) {
+        (true, _) => entry.key.clone(),
+        (false, true) => format!("{} (二进制，以 base64 显示)", entry.key),
+        (false, false) => format!("{} (binary, shown as base64)", entry.key),
+    };
+    let paragraph = Paragraph::new(text)
+        .style(Style::default().fg(color))
+        .wrap(Wrap { trim: false })
+        .block(Block::default().borders(Borders::ALL).title(title));
+    f.render_widget(paragraph, area);
+}
+
+/// 待确认的修改：只显示键和变化的行数，不显示值
+fn render_changes(f: &mut Frame, area: Rect, changes: &[SecretChange], chinese: bool) {
+    let lines: Vec<Line> = changes
+        .iter()
+        .map(|change| {
+            let (sign, color, kind) = match (change.kind, chinese) {
+                (ChangeKind::Added, true) => ("+", Color::Green, "新增"),
+                (ChangeKind::Added, false) => ("+", Color::Green, "added"),
+                (ChangeKind::Changed, true) => ("~", Color::Yellow, "修改"),
+                (ChangeKind::Changed, false) => ("~", Color::Yellow, "changed"),
+                (ChangeKind::Removed, true) => ("-", Color::Red, "删除"),
+                (ChangeKind::Removed, false) => ("-", Color::Red, "removed"),
+            };
+            let (added, removed) = change.lines;
+            Line::from(vec![
+                Span::styled(
+                    format!("{} {} ", sign, change.key),
+                    Style::default().fg(color).add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    format!("{} (+{} -{}) {}", kind, added, removed, MASK),
+                    Style::default().fg(Color::DarkGray),
+                ),
+            ])
+        })
+        .collect();
+    let title = if chinese {
+        format!("确认修改 {} 个键  y 应用 • n 放弃", changes.len())
+    } else {
+        format!(
+            "Apply {} changed key(s)  y apply • n discard",
+            changes.len()
+        )
+    };
+    let paragraph = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(title)
+            .title_style(Style::default().fg(Color::Yellow)),
+    );
+    f.render_widget(paragraph, area);
+}
+
+fn certificate_lines(index: usize, certificate: &Certificate, chinese: bool) -> Vec<Line<'static>> {
+    let now = chrono::Utc::now();
+    let days = certificate.days_to_expiry(now);
+    let remaining = match (certificate.not_after <= now, chinese) {
+        (true, true) => "已过期".to_string(),
+        (true, false) => "expired".to_string(),
+        (false, true) => format!("剩余 {} 天", days),
+        (false, false) => format!("{} days left", days),
+    };
+    let (issuer, sans, validity) = if chinese {
+        ("签发者", "SAN", "有效期")
+    } else {
+        ("Issuer", "SANs", "Validity")
+    };
+    let sans_text = if certificate.sans.is_empty() {
+        "-".to_string()
+    } else {
+        certificate.sans.join(", ")
+    };
+    vec![
+        Line::from(Span::styled(
+            format!("#{} {}", index + 1, certificate.subject),
+            Style::default().add_modifier(Modifier::BOLD),
+        )),
+        Line::from(format!("  {}: {}", issuer, certificate.issuer)),
+        Line::from(format!("  {}: {}", sans, sans_text)),
+        Line::from(vec![
+            Span::raw(format!(
+                "  {}: {} → {} ",
+                validity,
+                certificate.not_before.format("%Y-%m-%d %H:%M UTC"),
+                certificate.not_after.format("%Y-%m-%d %H:%M UTC")
+            )),
+            Span::styled(
+                format!("({})", remaining),
+                Style::default().fg(expiry_color(days)),
+            ),
+        ]),
+    ]
+}
+
+fn render_certificates(
+    f: &mut Frame,
+    area: Rect,
+    certificates: &Result<Vec<Certificate>, String>,
+    chinese: bool,
+) {
+    let title = if chinese {
+        "TLS 证书链"
+    } else {
+        "TLS certificate chain"
+    };
+    let paragraph = match certificates {
+        Ok(chain) => Paragraph::new(
+            chain
+                .iter()
+                .enumerate()
+                .flat_map(|(i, certificate)| certificate_lines(i, certificate, chinese))
+                .collect::<Vec<_>>(),
+        ),
+        Err(error) => Paragraph::new(error.clone()).style(Style::default().fg(Color::Red)),
+    };
+    f.render_widget(
+        paragraph.block(Block::default().borders(Borders::ALL).title(title)),
+        area,
+    );
+}
+
+pub fn render(f: &mut Frame, area: Rect, app: &AppState) {
+    let Some(view) = &app.secret_view else {
+        return;
+    };
+    let chinese = app.language_chinese;
+    if view.lifecycle.detail.is_none() {
+        let (text, color) = match &view.lifecycle.error {
+            Some(error) => (error.clone(), Color::Red),
+            None if chinese => ("加载中...".to_string(), Color::Gray),
+            None => ("Loading...".to_string(), Color::Gray),
+        };
+        let paragraph = Paragraph::new(text)
+            .style(Style::default().fg(color))
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(format!("Secret - {}/{}", view.namespace, view.name)),
+            );
+        f.render_widget(paragraph, area);
+        return;
+    }
+
+    // TLS Secret 在键列表和值之间显示证书链
+    let certificate_height = match &view.certificates {
+        Some(Ok(chain)) => (chain.len() * CERTIFICATE_LINES) as u16 + 2,
+        Some(Err(_)) => 3,
+        None => 0,
+    };
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length((view.entries().len() as u16 + 3).min(area.height / 3)),
+            Constraint::Length(certificate_height.min(area.height / 2)),
+            Constraint::Min(3),
+        ])
+        .split(area);
+    render_entries(f, chunks[0], view, chinese);
+    if let Some(certificates) = &view.certificates {
+        render_certificates(f, chunks[1], certificates, chinese);
+    }
+    match &view.lifecycle.edit {
+        Some(changes) => render_changes(f, chunks[2], changes, chinese),
+        None => render_value(f, chunks[2], view, chinese),
+    }
+}
@@ -1,11 +1,79 @@
+use chrono::{DateTime, Utc};
 use ratatui::{
     Frame,
-    layout::{Constraint, Rect},
+    layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
-    widgets::{Block, Borders, Cell, Row, Table},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table},
 };
 
+use crate::app::secret_view::ExpirySummary;
 use crate::app::state::AppState;
+use crate::kubectl::types::Secret;
+use crate::kubectl::x509::{EXPIRY_CRITICAL_DAYS, EXPIRY_WARNING_DAYS};
+
+/// 证书剩余天数着色：已过期或不超过 7 天红，不超过 30 天黄
+pub fn expiry_color(days: i64) -> Color {
+    if days <= EXPIRY_CRITICAL_DAYS {
+        Color::Red
+    } else if days <= EXPIRY_WARNING_DAYS {
+        Color::Yellow
+    } else {
+        Color::Green
+    }
+}
+
+fn expiry_cell(secret: &Secret, now: DateTime<Utc>) -> Cell<'static> {
+    match &secret.tls {
+        None => Cell::from("-"),
+        Some(Err(_)) => Cell::from("invalid").style(Style::default().fg(Color::Red)),
+        Some(Ok(certificate)) if certificate.not_after <= now => {
+            Cell::from("expired").style(Style::default().fg(Color::Red))
+        }
+        Some(Ok(certificate)) => {
+            let days = certificate.days_to_expiry(now);
+            Cell::from(format!("{}d", days)).style(Style::default().fg(expiry_color(days)))
+        }
+    }
+}
+
+/// 命名空间内 TLS 证书的到期概览，颜色取最严重的一项
+fn render_expiry_summary(f: &mut Frame, area: Rect, summary: &ExpirySummary, chinese: bool) {
+    let mut parts = if chinese {
+        vec![format!("TLS 证书: {} 个", summary.total)]
+    } else {
+        vec![format!("TLS certificates: {}", summary.total)]
+    };
+    let counts = [
+        (summary.expired, "已过期", "expired"),
+        (summary.critical, "7 天内到期", "expiring in 7d"),
+        (summary.warning, "30 天内到期", "expiring in 30d"),
+        (summary.invalid, "无法解析", "invalid"),
+    ];
+    for (count, zh, en) in counts {
+        if count > 0 {
+            parts.push(format!("{} {}", if chinese { zh } else { en }, count));
+        }
+    }
+    if let Some((name, days)) = &summary.soonest {
+        parts.push(if chinese {
+            format!("最早到期: {} ({} 天)", name, days)
+        } else {
+            format!("soonest: {} ({}d)", name, days)
+        });
+    }
+
+    let color = if summary.expired + summary.critical + summary.invalid > 0 {
+        Color::Red
+    } else if summary.warning > 0 {
+        Color::Yellow
+    } else {
+        Color::Green
+    };
+    let paragraph = Paragraph::new(parts.join(" • "))
+        .style(Style::default().fg(color))
+        .block(Block::default().borders(Borders::ALL));
+    f.render_widget(paragraph, area);
+}
 
 pub fn render(f: &mut Frame, area: Rect, app: &AppState) {
     if app.secrets.is_empty() {
@@ -21,6 +89,19 @@ pub fn render(f: &mut Frame, area: Rect, app: &AppState) {
         return;
     }
 
+    let now = Utc::now();
+    let summary = ExpirySummary::of(&app.secrets, now);
+    let area = if summary.total > 0 {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(3), Constraint::Min(0)])
+            .split(area);
+        render_expiry_summary(f, chunks[0], &summary, app.language_chinese);
+        chunks[1]
+    } else {
+        area
+    };
+
     let rows: Vec<Row> = app
         .secrets
         .iter()
@@ -38,6 +119,7 @@ pub fn render(f: &mut Frame, area: Rect, app: &AppState) {
                 Cell::from(secret.name.clone()),
                 Cell::from(secret.type_.clone()),
                 Cell::from(secret.data_count.to_string()),
+                expiry_cell(secret, now),
                 Cell::from(secret.age.clone()),
             ])
             .style(style)
@@ -49,12 +131,13 @@ pub fn render(f: &mut Frame, area: Rect, app: &AppState) {
         [
             Constraint::Percentage(40),
             Constraint::Percentage(30),
-            Constraint::Percentage(15),
-            Constraint::Percentage(15),
+            Constraint::Percentage(10),
+            Constraint::Percentage(10),
+            Constraint::Percentage(10),
         ],
     )
     .header(
-        Row::new(vec!["Name", "Type", "Data", "Age"]).style(
+        Row::new(vec!["Name", "Type", "Data", "Expires", "Age"]).style(
             Style::default()
                 .fg(Color::Yellow)
                 .add_modifier(Modifier::BOLD),
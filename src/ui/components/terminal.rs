@@ -0,0 +1,173 @@
+use crate::app::state::AppState;
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+};
+
+fn split(area: Rect) -> (Rect, Rect) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(1), Constraint::Min(0)])
+        .split(area);
+    (chunks[0], chunks[1])
+}
+
+/// 主内容区内伪终端的行列数（去掉标签栏和边框）
+pub fn pty_size(area: Rect) -> (u16, u16) {
+    let (_, body) = split(area);
+    (body.height.saturating_sub(2), body.width.saturating_sub(2))
+}
+
+fn convert_color(color: vt100::Color) -> Option<Color> {
+    match color {
+        vt100::Color::Default => None,
+        vt100::Color::Idx(i) => Some(Color::Indexed(i)),
+        vt100::Color::Rgb(r, g, b) => Some(Color::Rgb(r, g, b)),
+    }
+}
+
+fn cell_style(cell: &vt100::Cell) -> Style {
+    let mut style = Style::default();
+    if let Some(fg) = convert_color(cell.fgcolor()) {
+        style = style.fg(fg);
+    }
+    if let Some(bg) = convert_color(cell.bgcolor()) {
+        style = style.bg(bg);
+    }
+    if cell.bold() {
+        style = style.add_modifier(Modifier::BOLD);
+    }
+    if cell.italic() {
+        style = style.add_modifier(Modifier::ITALIC);
+    }
+    if cell.underline() {
+        style = style.add_modifier(Modifier::UNDERLINED);
+    }
+    if cell.inverse() {
+        style = style.add_modifier(Modifier::REVERSED);
+    }
+    style
+}
+
+// 把模拟器屏幕转换成 ratatui 行，相邻同样式的单元格合并为一个 Span
+fn screen_lines(screen: &vt100::Screen) -> Vec<Line<'static>> {
+    let (rows, cols) = screen.size();
+    (0..rows)
+        .map(|row| {
+            let mut spans: Vec<Span<'static>> = Vec::new();
+            let mut text = String::new();
+            let mut style = Style::default();
+            for col in 0..cols {
+                let Some(cell) = screen.cell(row, col) else {
+                    continue;
+                };
+                if cell.is_wide_continuation() {
+                    continue;
+                }
+                let cell_style = cell_style(cell);
+                if cell_style != style && !text.is_empty() {
+                    spans.push(Span::styled(std::mem::take(&mut text), style));
+                }
+                style = cell_style;
+                if cell.has_contents() {
+                    text.push_str(cell.contents());
+                } else {
+                    text.push(' ');
+                }
+            }
+            if !text.is_empty() {
+                spans.push(Span::styled(text, style));
+            }
+            Line::from(spans)
+        })
+        .collect()
+}
+
+fn render_tabs(f: &mut Frame, area: Rect, app: &AppState) {
+    let mut spans = Vec::new();
+    for (i, session) in app.terminal_sessions.iter().enumerate() {
+        let exited = if session.is_exited() {
+            if app.language_chinese {
+                " (已退出)"
+            } else {
+                " (exited)"
+            }
+        } else {
+            ""
+        };
+        let label = format!(" {}:{}{} ", i + 1, session.title, exited);
+        let style = if i == app.active_terminal {
+            Style::default()
+                .fg(Color::Black)
+                .bg(Color::Yellow)
+                .add_modifier(Modifier::BOLD)
+        } else if session.is_exited() {
+            Style::default().fg(Color::DarkGray)
+        } else {
+            Style::default().fg(Color::White)
+        };
+        spans.push(Span::styled(label, style));
+        spans.push(Span::raw(" "));
+    }
+    f.render_widget(Paragraph::new(Line::from(spans)), area);
+}
+
+pub fn render(f: &mut Frame, area: Rect, app: &AppState) {
+    let (tabs_area, body) = split(area);
+    render_tabs(f, tabs_area, app);
+
+    let Some(session) = app.active_terminal_session() else {
+        let text = if app.language_chinese {
+            "没有终端会话，在 Pod 列表按 E 启动"
+        } else {
+            "No terminal sessions, press E in the Pod list to start one"
+        };
+        let widget = Paragraph::new(text)
+            .block(Block::default().borders(Borders::ALL).title("Terminal"))
+            .style(Style::default().fg(Color::Gray));
+        f.render_widget(widget, body);
+        return;
+    };
+
+    let focus = if app.terminal_focus {
+        if app.language_chinese {
+            "[shell 焦点] Ctrl+] 返回 TUI"
+        } else {
+            "[shell focus] Ctrl+] back to TUI"
+        }
+    } else if app.language_chinese {
+        "[TUI 焦点] Enter 聚焦 shell"
+    } else {
+        "[TUI focus] Enter to focus shell"
+    };
+    let border_color = if app.terminal_focus {
+        Color::Green
+    } else {
+        Color::Gray
+    };
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(format!("{} - {}", session.title, focus))
+        .border_style(Style::default().fg(border_color));
+    let inner = block.inner(body);
+
+    let (lines, cursor) = session
+        .with_screen(|screen| {
+            let cursor = (!screen.hide_cursor()).then(|| screen.cursor_position());
+            (screen_lines(screen), cursor)
+        })
+        .unwrap_or_default();
+    f.render_widget(Paragraph::new(lines).block(block), body);
+
+    if app.terminal_focus
+        && !session.is_exited()
+        && let Some((row, col)) = cursor
+        && row < inner.height
+        && col < inner.width
+    {
+        f.set_cursor_position((inner.x + col, inner.y + row));
+    }
+}
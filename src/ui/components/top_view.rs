@@ -1,14 +1,24 @@
+use std::collections::VecDeque;
+
 use ratatui::{
     Frame,
     layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::Span,
-    widgets::{Block, Borders, Cell, Row, Table},
+    widgets::{Block, Borders, Cell, Row, Sparkline, Table},
 };
 
-use crate::app::state::AppState;
+use crate::app::metrics_history::{MetricsSeries, SeriesStats, sparkline};
+use crate::app::state::{AppState, NodeMetricsSort};
+use crate::kubectl::quantity;
+use crate::kubectl::types::MEMORY_LIMIT_WARNING;
 
 pub fn render(f: &mut Frame, area: Rect, app: &AppState) {
+    if app.top_nodes {
+        render_node_metrics(f, area, app);
+        return;
+    }
+
     let title = format!(
         "资源使用情况 - {} (j/k:滚动, PgUp/PgDn:翻页)",
         app.current_namespace
@@ -25,11 +35,12 @@ pub fn render(f: &mut Frame, area: Rect, app: &AppState) {
         return;
     }
 
-    // 分割区域：上半部分显示Pod级别的指标，下半部分显示Container级别的指标
+    // 分割区域：上半部分显示Pod级别的指标，中间为选中Pod的趋势，下半部分显示Container级别的指标
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
             Constraint::Length(app.pod_metrics.len() as u16 + 3), // Pod指标表格
+            Constraint::Length(6),                                // 选中Pod的趋势图
             Constraint::Min(0),                                   // Container指标表格
         ])
         .split(area);
@@ -37,8 +48,188 @@ pub fn render(f: &mut Frame, area: Rect, app: &AppState) {
     // 渲染Pod级别的资源使用表格
     render_pod_metrics_table(f, chunks[0], app);
 
+    // 渲染选中Pod在保留窗口内的CPU/内存趋势
+    render_pod_history(f, chunks[1], app);
+
     // 渲染选中Pod的Container级别详细信息
-    render_container_metrics_detail(f, chunks[1], app);
+    render_container_metrics_detail(f, chunks[2], app);
+}
+
+/// 表格中趋势列的宽度（样本数）
+const TREND_WIDTH: usize = 20;
+
+fn trend_cell(
+    series: Option<&MetricsSeries>,
+    samples: fn(&MetricsSeries) -> &VecDeque<u64>,
+) -> Cell<'static> {
+    Cell::from(series.map_or_else(String::new, |s| sparkline(samples(s), TREND_WIDTH)))
+        .style(Style::default().fg(Color::Cyan))
+}
+
+/// 趋势图：标题、样本、数值格式化、颜色
+type Chart<'a> = (&'a str, &'a VecDeque<u64>, fn(f64) -> String, Color);
+
+fn render_pod_history(f: &mut Frame, area: Rect, app: &AppState) {
+    let Some(metrics) = app.pod_metrics.get(app.metrics_scroll) else {
+        return;
+    };
+    let series = app
+        .metrics_history
+        .pod(&metrics.namespace, &metrics.name)
+        .cloned()
+        .unwrap_or_default();
+
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(area);
+
+    let charts: [Chart; 2] = [
+        ("CPU", &series.cpu, format_millicores, Color::Green),
+        (
+            "内存",
+            &series.memory,
+            quantity::format_bytes,
+            Color::Magenta,
+        ),
+    ];
+    for ((label, samples, format, color), area) in charts.into_iter().zip(chunks.iter()) {
+        let title = match SeriesStats::of(samples) {
+            Some(stats) => format!(
+                "{} 最小 {} / 平均 {} / 最大 {} ({} 个样本)",
+                label,
+                format(stats.min as f64),
+                format(stats.avg),
+                format(stats.max as f64),
+                series.sample_count()
+            ),
+            None => format!("{} (暂无样本)", label),
+        };
+        // 只取最近能画下的样本，最新的在最右侧
+        let width = area.width.saturating_sub(2) as usize;
+        let data: Vec<u64> = samples
+            .iter()
+            .skip(samples.len().saturating_sub(width))
+            .copied()
+            .collect();
+        let chart = Sparkline::default()
+            .block(Block::default().borders(Borders::ALL).title(title))
+            .data(&data)
+            .style(Style::default().fg(color));
+        f.render_widget(chart, *area);
+    }
+}
+
+/// 节点使用率着色：≥90% 红，≥70% 黄
+fn usage_color(percentage: Option<f64>) -> Color {
+    match percentage {
+        Some(p) if p >= 90.0 => Color::Red,
+        Some(p) if p >= 70.0 => Color::Yellow,
+        Some(_) => Color::Green,
+        None => Color::Gray,
+    }
+}
+
+fn render_node_metrics(f: &mut Frame, area: Rect, app: &AppState) {
+    let chinese = app.language_chinese;
+    let sort = match (app.node_metrics_sort, chinese) {
+        (NodeMetricsSort::Name, true) => "名称",
+        (NodeMetricsSort::Name, false) => "name",
+        (NodeMetricsSort::Cpu, _) => "CPU%",
+        (NodeMetricsSort::Memory, true) => "内存%",
+        (NodeMetricsSort::Memory, false) => "memory%",
+    };
+    let title = if chinese {
+        format!("节点资源使用 (排序: {}, s:切换排序, j/k:滚动)", sort)
+    } else {
+        format!("Node usage (sort: {}, s:cycle sort, j/k:scroll)", sort)
+    };
+
+    if app.node_metrics.is_empty() {
+        let text = match &app.node_metrics_error {
+            Some(error) => error.clone(),
+            None if chinese => {
+                "正在加载节点资源使用情况...\n\n注意: 需要安装metrics-server才能查看资源使用情况"
+                    .to_string()
+            }
+            None => "Loading node metrics...\n\nNote: requires metrics-server".to_string(),
+        };
+        let paragraph = ratatui::widgets::Paragraph::new(text)
+            .block(Block::default().borders(Borders::ALL).title(title))
+            .style(Style::default().fg(Color::Gray));
+        f.render_widget(paragraph, area);
+        return;
+    }
+
+    // 当前排序列加 ▼ 标记
+    let marker = |column: NodeMetricsSort, label: &str| {
+        if app.node_metrics_sort == column {
+            format!("{} ▼", label)
+        } else {
+            label.to_string()
+        }
+    };
+    let header = if chinese {
+        vec![
+            marker(NodeMetricsSort::Name, "节点"),
+            "CPU".to_string(),
+            marker(NodeMetricsSort::Cpu, "CPU%"),
+            "内存".to_string(),
+            marker(NodeMetricsSort::Memory, "内存%"),
+        ]
+    } else {
+        vec![
+            marker(NodeMetricsSort::Name, "Node"),
+            "CPU".to_string(),
+            marker(NodeMetricsSort::Cpu, "CPU%"),
+            "Memory".to_string(),
+            marker(NodeMetricsSort::Memory, "Memory%"),
+        ]
+    };
+
+    let rows: Vec<Row> = app
+        .node_metrics
+        .iter()
+        .map(|metrics| {
+            Row::new(vec![
+                Cell::from(metrics.name.clone()),
+                Cell::from(format_cpu(&metrics.cpu)),
+                Cell::from(format_percentage(metrics.cpu_percentage))
+                    .style(Style::default().fg(usage_color(metrics.cpu_percentage))),
+                Cell::from(format_memory(&metrics.memory)),
+                Cell::from(format_percentage(metrics.memory_percentage))
+                    .style(Style::default().fg(usage_color(metrics.memory_percentage))),
+            ])
+        })
+        .collect();
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Min(30),
+            Constraint::Length(10),
+            Constraint::Length(10),
+            Constraint::Length(10),
+            Constraint::Length(10),
+        ],
+    )
+    .header(
+        Row::new(header).style(
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        ),
+    )
+    .block(Block::default().borders(Borders::ALL).title(title))
+    .row_highlight_style(
+        Style::default()
+            .fg(Color::Black)
+            .bg(Color::Yellow)
+            .add_modifier(Modifier::BOLD),
+    );
+
+    let mut state = ratatui::widgets::TableState::default().with_selected(Some(app.metrics_scroll));
+    f.render_stateful_widget(table, area, &mut state);
 }
 
 fn render_pod_metrics_table(f: &mut Frame, area: Rect, app: &AppState) {
@@ -51,16 +242,32 @@ fn render_pod_metrics_table(f: &mut Frame, area: Rect, app: &AppState) {
             "CPU",
             Style::default().add_modifier(Modifier::BOLD),
         )),
+        Cell::from(Span::styled(
+            "CPU/请求",
+            Style::default().add_modifier(Modifier::BOLD),
+        )),
+        Cell::from(Span::styled(
+            "CPU/限制",
+            Style::default().add_modifier(Modifier::BOLD),
+        )),
         Cell::from(Span::styled(
             "内存",
             Style::default().add_modifier(Modifier::BOLD),
         )),
         Cell::from(Span::styled(
-            "CPU%",
+            "内存/请求",
+            Style::default().add_modifier(Modifier::BOLD),
+        )),
+        Cell::from(Span::styled(
+            "内存/限制",
+            Style::default().add_modifier(Modifier::BOLD),
+        )),
+        Cell::from(Span::styled(
+            "CPU 趋势",
             Style::default().add_modifier(Modifier::BOLD),
         )),
         Cell::from(Span::styled(
-            "内存%",
+            "内存 趋势",
             Style::default().add_modifier(Modifier::BOLD),
         )),
     ])
@@ -80,12 +287,29 @@ fn render_pod_metrics_table(f: &mut Frame, area: Rect, app: &AppState) {
                 Style::default().fg(Color::White)
             };
 
+            // 任一容器接近内存限制时标记
+            let near_limit = metrics.usage.near_memory_limit()
+                || metrics
+                    .containers
+                    .iter()
+                    .any(|c| c.usage.near_memory_limit());
+            let name = if near_limit {
+                format!("⚠ {}", metrics.name)
+            } else {
+                metrics.name.clone()
+            };
+
+            let history = app.metrics_history.pod(&metrics.namespace, &metrics.name);
             Row::new(vec![
-                Cell::from(metrics.name.clone()),
+                Cell::from(name),
                 Cell::from(format_cpu(&metrics.cpu)),
+                Cell::from(format_percentage(metrics.usage.cpu_of_request)),
+                limit_cell(metrics.usage.cpu_of_limit),
                 Cell::from(format_memory(&metrics.memory)),
-                Cell::from(format_percentage(metrics.cpu_percentage)),
-                Cell::from(format_percentage(metrics.memory_percentage)),
+                Cell::from(format_percentage(metrics.usage.memory_of_request)),
+                limit_cell(metrics.usage.memory_of_limit),
+                trend_cell(history, |s| &s.cpu),
+                trend_cell(history, |s| &s.memory),
             ])
             .style(style)
         })
@@ -94,11 +318,15 @@ fn render_pod_metrics_table(f: &mut Frame, area: Rect, app: &AppState) {
     let table = Table::new(
         rows,
         [
-            Constraint::Length(30), // Pod名称
-            Constraint::Length(10), // CPU
-            Constraint::Length(10), // 内存
-            Constraint::Length(8),  // CPU%
-            Constraint::Length(8),  // 内存%
+            Constraint::Length(30),                     // Pod名称
+            Constraint::Length(10),                     // CPU
+            Constraint::Length(10),                     // CPU/请求
+            Constraint::Length(10),                     // CPU/限制
+            Constraint::Length(10),                     // 内存
+            Constraint::Length(10),                     // 内存/请求
+            Constraint::Length(10),                     // 内存/限制
+            Constraint::Length(TREND_WIDTH as u16 + 1), // CPU 趋势
+            Constraint::Length(TREND_WIDTH as u16 + 1), // 内存 趋势
         ],
     )
     .header(header)
@@ -142,16 +370,40 @@ fn render_container_metrics_detail(f: &mut Frame, area: Rect, app: &AppState) {
                 "CPU",
                 Style::default().add_modifier(Modifier::BOLD),
             )),
+            Cell::from(Span::styled(
+                "CPU 请求/限制",
+                Style::default().add_modifier(Modifier::BOLD),
+            )),
+            Cell::from(Span::styled(
+                "CPU/请求",
+                Style::default().add_modifier(Modifier::BOLD),
+            )),
+            Cell::from(Span::styled(
+                "CPU/限制",
+                Style::default().add_modifier(Modifier::BOLD),
+            )),
             Cell::from(Span::styled(
                 "内存",
                 Style::default().add_modifier(Modifier::BOLD),
             )),
             Cell::from(Span::styled(
-                "CPU%",
+                "内存 请求/限制",
+                Style::default().add_modifier(Modifier::BOLD),
+            )),
+            Cell::from(Span::styled(
+                "内存/请求",
                 Style::default().add_modifier(Modifier::BOLD),
             )),
             Cell::from(Span::styled(
-                "内存%",
+                "内存/限制",
+                Style::default().add_modifier(Modifier::BOLD),
+            )),
+            Cell::from(Span::styled(
+                "CPU 趋势",
+                Style::default().add_modifier(Modifier::BOLD),
+            )),
+            Cell::from(Span::styled(
+                "内存 趋势",
                 Style::default().add_modifier(Modifier::BOLD),
             )),
         ])
@@ -162,12 +414,38 @@ fn render_container_metrics_detail(f: &mut Frame, area: Rect, app: &AppState) {
             .containers
             .iter()
             .map(|container| {
+                let bounds = &container.bounds;
+                let usage = &container.usage;
+                let name = if usage.near_memory_limit() {
+                    format!("⚠ {} (接近内存限制)", container.name)
+                } else {
+                    container.name.clone()
+                };
+                let history = app.metrics_history.container(
+                    &metrics.namespace,
+                    &metrics.name,
+                    &container.name,
+                );
                 Row::new(vec![
-                    Cell::from(container.name.clone()),
+                    Cell::from(name),
                     Cell::from(format_cpu(&container.cpu)),
+                    Cell::from(format_bounds(
+                        bounds.cpu_request,
+                        bounds.cpu_limit,
+                        quantity::format_cpu,
+                    )),
+                    Cell::from(format_percentage(usage.cpu_of_request)),
+                    limit_cell(usage.cpu_of_limit),
                     Cell::from(format_memory(&container.memory)),
-                    Cell::from(format_percentage(container.cpu_percentage)),
-                    Cell::from(format_percentage(container.memory_percentage)),
+                    Cell::from(format_bounds(
+                        bounds.memory_request,
+                        bounds.memory_limit,
+                        quantity::format_bytes,
+                    )),
+                    Cell::from(format_percentage(usage.memory_of_request)),
+                    limit_cell(usage.memory_of_limit),
+                    trend_cell(history, |s| &s.cpu),
+                    trend_cell(history, |s| &s.memory),
                 ])
                 .style(Style::default().fg(Color::White))
             })
@@ -176,11 +454,17 @@ fn render_container_metrics_detail(f: &mut Frame, area: Rect, app: &AppState) {
         let table = Table::new(
             rows,
             [
-                Constraint::Length(30), // 容器名称
-                Constraint::Length(10), // CPU
-                Constraint::Length(10), // 内存
-                Constraint::Length(8),  // CPU%
-                Constraint::Length(8),  // 内存%
+                Constraint::Length(30),                     // 容器名称
+                Constraint::Length(8),                      // CPU
+                Constraint::Length(14),                     // CPU 请求/限制
+                Constraint::Length(9),                      // CPU/请求
+                Constraint::Length(9),                      // CPU/限制
+                Constraint::Length(9),                      // 内存
+                Constraint::Length(16),                     // 内存 请求/限制
+                Constraint::Length(10),                     // 内存/请求
+                Constraint::Length(10),                     // 内存/限制
+                Constraint::Length(TREND_WIDTH as u16 + 1), // CPU 趋势
+                Constraint::Length(TREND_WIDTH as u16 + 1), // 内存 趋势
             ],
         )
         .header(header)
@@ -241,6 +525,27 @@ fn format_memory(memory_str: &str) -> String {
     memory_str.to_string()
 }
 
+/// 历史样本中的 CPU 以毫核记录
+fn format_millicores(millis: f64) -> String {
+    quantity::format_cpu(millis / 1000.0)
+}
+
+/// 请求/限制，未设置的显示为 -
+fn format_bounds(request: Option<f64>, limit: Option<f64>, format: fn(f64) -> String) -> String {
+    let show = |value: Option<f64>| value.map_or_else(|| "-".to_string(), format);
+    format!("{}/{}", show(request), show(limit))
+}
+
+/// 相对限制的使用率：内存接近限制会被 OOMKill，CPU 接近限制会被限流
+fn limit_cell(percentage: Option<f64>) -> Cell<'static> {
+    let color = match percentage {
+        Some(p) if p >= MEMORY_LIMIT_WARNING => Color::Red,
+        Some(p) if p >= 75.0 => Color::Yellow,
+        _ => Color::White,
+    };
+    Cell::from(format_percentage(percentage)).style(Style::default().fg(color))
+}
+
 fn format_percentage(percentage: Option<f64>) -> String {
     match percentage {
         Some(p) => format!("{:.1}%", p),
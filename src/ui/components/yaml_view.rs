@@ -9,6 +9,7 @@ use ratatui::{
     },
 };
 
+use super::search::{highlight_matches, split_content_search_input};
 use crate::app::state::{AppMode, AppState};
 
 pub fn render(f: &mut Frame, area: Rect, app: &AppState) {
@@ -132,6 +133,14 @@ pub fn render(f: &mut Frame, area: Rect, app: &AppState) {
         _ => "YAML配置".to_string(),
     };
 
+    let neat = match (app.yaml_neat, app.language_chinese) {
+        (false, _) => "",
+        (true, true) => " [精简]",
+        (true, false) => " [neat]",
+    };
+    let title = format!("{}{}{}", title, neat, app.content_search.status());
+    let area = split_content_search_input(f, area, app);
+
     if app.yaml_content.is_empty() {
         let no_content = Paragraph::new("正在加载YAML配置...")
             .block(Block::default().borders(Borders::ALL).title(title))
@@ -141,37 +150,82 @@ pub fn render(f: &mut Frame, area: Rect, app: &AppState) {
         return;
     }
 
-    // 将内容按行分割并应用语法高亮
-    let lines: Vec<&str> = app.yaml_content.lines().collect();
+    let lines = &app.yaml_lines_cache;
+    let search = &app.content_search;
+    let tree = &app.yaml_tree;
+    let rows = tree.rows();
     let visible_height = area.height.saturating_sub(2) as usize;
-    let total_lines = lines.len();
+    let total_lines = rows.len();
 
-    // 计算显示范围
-    let start_index = app.yaml_scroll;
+    // 光标保持在可视区域中间
+    let cursor = app.yaml_scroll.min(total_lines.saturating_sub(1));
+    let start_index = cursor
+        .saturating_sub(visible_height / 2)
+        .min(total_lines.saturating_sub(visible_height));
     let end_index = (start_index + visible_height).min(total_lines);
 
-    // 创建带语法高亮的可见内容项
-    let visible_lines: Vec<ListItem> = lines[start_index..end_index]
+    // 可折叠的行前显示折叠标记，折叠后显示隐藏的行数
+    let visible_lines: Vec<ListItem> = rows[start_index..end_index]
         .iter()
-        .map(|line| ListItem::new(highlight_yaml_line(line)))
+        .map(|&index| {
+            let mut line = match &search.pattern {
+                Some(pattern) if search.matches.binary_search(&index).is_ok() => {
+                    let current = search.current_line() == Some(index);
+                    highlight_matches(highlight_yaml_line(&lines[index]), pattern, current)
+                }
+                _ => highlight_yaml_line(&lines[index]),
+            };
+            let marker = match (tree.is_foldable(index), tree.is_folded(index)) {
+                (true, true) => "▸ ",
+                (true, false) => "▾ ",
+                (false, _) => "  ",
+            };
+            line.spans
+                .insert(0, Span::styled(marker, Style::default().fg(Color::Cyan)));
+            if tree.is_folded(index) {
+                let hidden = tree.lines[index].end - index - 1;
+                let text = if app.language_chinese {
+                    format!(" … {} 行", hidden)
+                } else {
+                    format!(" … {} lines", hidden)
+                };
+                line.spans
+                    .push(Span::styled(text, Style::default().fg(Color::DarkGray)));
+            }
+            ListItem::new(line)
+        })
         .collect();
 
-    let mut list_state = ListState::default();
+    let mut list_state = ListState::default().with_selected(Some(cursor - start_index));
 
     // 计算滚动条状态
     let scrollbar_state = if total_lines > visible_height {
         let state = ScrollbarState::default()
             .content_length(total_lines)
             .viewport_content_length(visible_height)
-            .position(start_index);
+            .position(cursor);
         Some(state)
     } else {
         None
     };
 
+    // 底部显示光标所在行的路径
+    let breadcrumb = app
+        .yaml_cursor_line()
+        .map(|line| tree.lines[line].path.as_str())
+        .filter(|path| !path.is_empty())
+        .map(|path| format!(" {} ", path))
+        .unwrap_or_default();
+
     let list = List::new(visible_lines)
-        .block(Block::default().borders(Borders::ALL).title(title))
-        .style(Style::default().fg(Color::White));
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(title)
+                .title_bottom(Line::styled(breadcrumb, Style::default().fg(Color::Cyan))),
+        )
+        .style(Style::default().fg(Color::White))
+        .highlight_style(Style::default().bg(Color::DarkGray));
 
     f.render_stateful_widget(list, area, &mut list_state);
 
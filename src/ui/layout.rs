@@ -1,6 +1,5 @@
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
 
-#[allow(dead_code)]
 pub fn main_layout(area: Rect) -> Vec<Rect> {
     Layout::default()
         .direction(Direction::Vertical)
@@ -8,6 +7,7 @@ pub fn main_layout(area: Rect) -> Vec<Rect> {
             Constraint::Length(3), // Header
             Constraint::Min(0),    // Main content
             Constraint::Length(3), // Footer
+            Constraint::Length(1), // Command line
         ])
         .split(area)
         .to_vec()
@@ -3,7 +3,7 @@ pub mod layout;
 
 use ratatui::{
     Frame,
-    layout::{Constraint, Direction, Layout, Rect},
+    layout::Rect,
     style::{Color, Modifier, Style},
     widgets::{Block, Borders, Paragraph, Tabs},
 };
@@ -11,15 +11,7 @@ use ratatui::{
 use crate::app::state::{AppMode, AppState};
 
 pub fn render_ui(f: &mut Frame, app: &AppState) {
-    let chunks = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints([
-            Constraint::Length(3), // Header
-            Constraint::Min(0),    // Main content
-            Constraint::Length(3), // Footer
-            Constraint::Length(1), // Command line
-        ])
-        .split(f.area());
+    let chunks = layout::main_layout(f.area());
 
     render_header(f, chunks[0], app);
     render_main_content(f, chunks[1], app);
@@ -27,6 +19,11 @@ pub fn render_ui(f: &mut Frame, app: &AppState) {
     render_command_line(f, chunks[3], app);
 }
 
+/// 终端窗口尺寸下伪终端可用的行列数
+pub fn terminal_pane_size(area: Rect) -> (u16, u16) {
+    components::terminal::pty_size(layout::main_layout(area)[1])
+}
+
 fn render_header(f: &mut Frame, area: Rect, app: &AppState) {
     let titles = vec![
         "Namespaces",
@@ -50,12 +47,12 @@ fn render_header(f: &mut Frame, area: Rect, app: &AppState) {
         AppMode::JobList => 4,
         AppMode::PVCList => 5,
         AppMode::PVList => 6,
-        AppMode::NodeList => 7,
-        AppMode::ConfigMapList => 8,
+        AppMode::NodeList | AppMode::NodeDrain | AppMode::NodeDetail => 7,
+        AppMode::ConfigMapList | AppMode::ConfigMapDetail => 8,
         AppMode::DaemonSetList => 9,
-        AppMode::SecretList => 10,
+        AppMode::SecretList | AppMode::SecretDetail => 10,
         AppMode::Help => 11,
-        AppMode::Logs | AppMode::Describe => {
+        AppMode::Logs | AppMode::Describe | AppMode::MultiLogs | AppMode::LogCaptures => {
             // 根据之前的模式显示正确的Tab高亮
             match app.previous_mode {
                 AppMode::PodList => 1,
@@ -98,6 +95,7 @@ fn render_header(f: &mut Frame, area: Rect, app: &AppState) {
             _ => 1,
         },
         AppMode::CommandHistory => 11,
+        AppMode::Terminal | AppMode::FileBrowser => 1,
     };
 
     let tabs = Tabs::new(titles)
@@ -134,6 +132,33 @@ fn render_main_content(f: &mut Frame, area: Rect, app: &AppState) {
         AppMode::YamlView => components::yaml_view::render(f, area, app),
         AppMode::TopView => components::top_view::render(f, area, app),
         AppMode::CommandHistory => {}
+        AppMode::Terminal => components::terminal::render(f, area, app),
+        AppMode::FileBrowser => components::file_browser::render(f, area, app),
+        AppMode::MultiLogs => components::multi_logs::render(f, area, app),
+        AppMode::LogCaptures => components::log_captures::render(f, area, app),
+        AppMode::NodeDrain => components::node_drain::render(f, area, app),
+        AppMode::NodeDetail => components::node_detail::render(f, area, app),
+        AppMode::SecretDetail => components::secret_detail::render(f, area, app),
+        AppMode::ConfigMapDetail => components::configmap_detail::render(f, area, app),
+    }
+
+    if app.exec_picker.is_some() {
+        components::exec_picker::render(f, area, app);
+    }
+
+    if app.log_options_dialog.is_some() {
+        components::log_options::render(f, area, app);
+    }
+
+    if app.multi_log_selector_input.is_some() {
+        components::multi_logs::render_selector_prompt(f, area, app);
+    }
+
+    if app.drain_dialog.is_some() {
+        components::node_drain::render_dialog(f, area, app);
+    }
+    if app.save_dialog.is_some() {
+        components::save_dialog::render(f, area, app);
     }
 }
 
@@ -158,7 +183,7 @@ fn render_footer(f: &mut Frame, area: Rect, app: &AppState) {
                         app.marked_items.len()
                     )
                 } else {
-                    "j/k 导航 • Space 详情 • Y YAML • T 监控 • L 日志 • D 删除 • E 进入 • v 批量 • / 搜索 • I 切换语言 • q 退出 • R 刷新".to_string()
+                    "j/k 导航 • Space 详情 • Y YAML • T 监控 • L 日志 • S 聚合日志 • C 捕获日志 • B 捕获列表 • D 删除 • E 进入 • F 文件 • v 批量 • / 搜索 • I 切换语言 • q 退出 • R 刷新".to_string()
                 }
             }
             AppMode::ServiceList => {
@@ -178,7 +203,7 @@ fn render_footer(f: &mut Frame, area: Rect, app: &AppState) {
                         app.marked_items.len()
                     )
                 } else {
-                    "j/k 导航 • Space 详情 • Y YAML • v 批量 • / 搜索 • I 切换语言 • q 退出 • R 刷新".to_string()
+                    "j/k 导航 • Space 详情 • Y YAML • Enter 容量 • T 资源使用 • C 禁止/恢复调度 • D 排空 • v 批量 • / 搜索 • I 切换语言 • q 退出 • R 刷新".to_string()
                 }
             }
             AppMode::DeploymentList => {
@@ -188,7 +213,7 @@ fn render_footer(f: &mut Frame, area: Rect, app: &AppState) {
                         app.marked_items.len()
                     )
                 } else {
-                    "j/k 导航 • Space 详情 • Y YAML • v 批量 • / 搜索 • I 切换语言 • q 退出 • R 刷新".to_string()
+                    "j/k 导航 • Space 详情 • Y YAML • L 聚合日志 • v 批量 • / 搜索 • I 切换语言 • q 退出 • R 刷新".to_string()
                 }
             }
             AppMode::JobList => {
@@ -198,7 +223,7 @@ fn render_footer(f: &mut Frame, area: Rect, app: &AppState) {
                         app.marked_items.len()
                     )
                 } else {
-                    "j/k 导航 • Space 详情 • Y YAML • v 批量 • / 搜索 • I 切换语言 • q 退出 • R 刷新".to_string()
+                    "j/k 导航 • Space 详情 • Y YAML • L 聚合日志 • v 批量 • / 搜索 • I 切换语言 • q 退出 • R 刷新".to_string()
                 }
             }
             AppMode::DaemonSetList => {
@@ -208,7 +233,7 @@ fn render_footer(f: &mut Frame, area: Rect, app: &AppState) {
                         app.marked_items.len()
                     )
                 } else {
-                    "j/k 导航 • Space 详情 • Y YAML • v 批量 • / 搜索 • I 切换语言 • q 退出 • R 刷新".to_string()
+                    "j/k 导航 • Space 详情 • Y YAML • L 聚合日志 • v 批量 • / 搜索 • I 切换语言 • q 退出 • R 刷新".to_string()
                 }
             }
             AppMode::PVCList => {
@@ -238,7 +263,7 @@ fn render_footer(f: &mut Frame, area: Rect, app: &AppState) {
                         app.marked_items.len()
                     )
                 } else {
-                    "j/k 导航 • Space 详情 • Y YAML • D 删除 • v 批量 • / 搜索 • I 切换语言 • q 退出 • R 刷新".to_string()
+                    "j/k 导航 • Enter 查看键 • Space 详情 • Y YAML • D 删除 • v 批量 • / 搜索 • I 切换语言 • q 退出 • R 刷新".to_string()
                 }
             }
             AppMode::SecretList => {
@@ -248,30 +273,33 @@ fn render_footer(f: &mut Frame, area: Rect, app: &AppState) {
                         app.marked_items.len()
                     )
                 } else {
-                    "j/k 导航 • Space 详情 • Y YAML • D 删除 • v 批量 • / 搜索 • I 切换语言 • q 退出 • R 刷新".to_string()
+                    "j/k 导航 • Enter 查看值 • Space 详情 • Y YAML • D 删除 • v 批量 • / 搜索 • I 切换语言 • q 退出 • R 刷新".to_string()
                 }
             }
             AppMode::Logs => {
                 if app.text_selection_mode {
-                    "j/k 滚动 • PgUp/PgDn 翻页 • V 分屏日志 • A 切换自动滚动 • R 切换自动刷新 • M 切换到滚轮模式 • 可选中复制文本 • I 切换语言 • Esc 返回 • q 退出".to_string()
+                    "j/k 滚动 • PgUp/PgDn 翻页 • V 分屏日志 • f 跟随/快照 • p 暂停 • o 日志选项 • / 搜索 • n/N 跳转 • g grep • s 结构化 • v 级别 • e 展开 • c 颜色 • w/W 保存/完整日志 • A 切换自动滚动 • R 切换自动刷新 • M 切换到滚轮模式 • 可选中复制文本 • I 切换语言 • Esc 返回 • q 退出".to_string()
                 } else {
-                    "j/k 滚动 • PgUp/PgDn 翻页 • V 分屏日志 • A 切换自动滚动 • R 切换自动刷新 • M 切换到选择模式 • 鼠标滚轮滚动 • I 切换语言 • Esc 返回 • q 退出".to_string()
+                    "j/k 滚动 • PgUp/PgDn 翻页 • V 分屏日志 • f 跟随/快照 • p 暂停 • o 日志选项 • / 搜索 • n/N 跳转 • g grep • s 结构化 • v 级别 • e 展开 • c 颜色 • w/W 保存/完整日志 • A 切换自动滚动 • R 切换自动刷新 • M 切换到选择模式 • 鼠标滚轮滚动 • I 切换语言 • Esc 返回 • q 退出".to_string()
                 }
             }
             AppMode::Describe => {
                 if app.text_selection_mode {
-                    "j/k 滚动 • R 切换自动刷新 • M 切换到滚轮模式 • 可选中复制文本 • I 切换语言 • Esc 返回 • q 退出".to_string()
+                    "j/k 滚动 • / 搜索 • n/N 下/上一个匹配 • z/Z 折叠段落/全部 • [/] 上/下一段 • s 段落列表 • w 保存 • R 切换自动刷新 • M 切换到滚轮模式 • 可选中复制文本 • I 切换语言 • Esc 返回 • q 退出".to_string()
                 } else {
-                    "j/k 滚动 • R 切换自动刷新 • M 切换到选择模式 • 鼠标滚轮滚动 • I 切换语言 • Esc 返回 • q 退出".to_string()
+                    "j/k 滚动 • / 搜索 • n/N 下/上一个匹配 • z/Z 折叠段落/全部 • [/] 上/下一段 • s 段落列表 • w 保存 • R 切换自动刷新 • M 切换到选择模式 • 鼠标滚轮滚动 • I 切换语言 • Esc 返回 • q 退出".to_string()
                 }
             }
             AppMode::YamlView => {
                 if app.text_selection_mode {
-                    "j/k 滚动 • R 切换自动刷新 • M 切换到滚轮模式 • 可选中复制文本 • I 切换语言 • Esc 返回 • q 退出".to_string()
+                    "j/k 移动光标 • / 搜索 • n/N 下/上一个匹配 • z/Z 折叠节点/全部 • S 只展开 spec • c 精简模式 • w 保存 • W 导出清单 • R 切换自动刷新 • M 切换到滚轮模式 • 可选中复制文本 • I 切换语言 • Esc 返回 • q 退出".to_string()
                 } else {
-                    "j/k 滚动 • R 切换自动刷新 • M 切换到选择模式 • 鼠标滚轮滚动 • I 切换语言 • Esc 返回 • q 退出".to_string()
+                    "j/k 移动光标 • / 搜索 • n/N 下/上一个匹配 • z/Z 折叠节点/全部 • S 只展开 spec • c 精简模式 • w 保存 • W 导出清单 • R 切换自动刷新 • M 切换到选择模式 • 鼠标滚轮滚动 • I 切换语言 • Esc 返回 • q 退出".to_string()
                 }
             }
+            AppMode::TopView if app.top_nodes => {
+                "j/k 滚动 • PgUp/PgDn 翻页 • s 切换排序 • I 切换语言 • Esc 返回 • q 退出".to_string()
+            }
             AppMode::TopView => {
                 "j/k 滚动 • PgUp/PgDn 翻页 • I 切换语言 • Esc 返回 • q 退出".to_string()
             }
@@ -281,6 +309,38 @@ fn render_footer(f: &mut Frame, area: Rect, app: &AppState) {
             AppMode::CommandHistory => {
                 "j/k 选择 • Enter 执行 • I 切换语言 • Esc 返回 • q 退出".to_string()
             }
+            AppMode::Terminal => {
+                if app.terminal_focus {
+                    "按键发送到 shell • Ctrl+] 交还焦点给 TUI".to_string()
+                } else {
+                    "Enter/i 聚焦 shell • Tab/[ ] 切换会话 • x 关闭会话 • Esc/` 返回 • q 退出"
+                        .to_string()
+                }
+            }
+            AppMode::FileBrowser => {
+                "j/k 导航 • Enter/l 展开 • h 折叠 • d 下载 • u 上传 • c 切换容器 • r 刷新 • Esc 返回"
+                    .to_string()
+            }
+            AppMode::MultiLogs => {
+                "j/k 滚动 • PgUp/PgDn 翻页 • g/G 顶部/底部 • A 切换自动滚动 • Esc 返回 • q 退出"
+                    .to_string()
+            }
+            AppMode::LogCaptures => {
+                "j/k 选择 • x 停止捕获 • X 全部停止 • Esc 返回（捕获继续） • q 退出".to_string()
+            }
+            AppMode::NodeDrain => {
+                "j/k 选择 • x 取消排空 • Esc 返回（排空继续） • q 退出".to_string()
+            }
+            AppMode::NodeDetail => {
+                "j/k 选择 Pod • Enter 跳转到 Pod 列表 • R 刷新 • Esc 返回 • q 退出".to_string()
+            }
+            AppMode::SecretDetail => {
+                "j/k 选择键 • r 显示/隐藏值 • c 复制值 • e 编辑 • R 刷新 • Esc 返回 • q 退出"
+                    .to_string()
+            }
+            AppMode::ConfigMapDetail => {
+                "j/k 选择键 • J/K 滚动 • e 编辑 • R 刷新 • Esc 返回 • q 退出".to_string()
+            }
         }
     } else {
         // English prompts
@@ -302,7 +362,7 @@ fn render_footer(f: &mut Frame, area: Rect, app: &AppState) {
                         app.marked_items.len()
                     )
                 } else {
-                    "j/k Navigate • Space Describe • Y YAML • T Top • L Logs • D Delete • E Exec • v Batch • / Search • I Language • q Quit • R Refresh".to_string()
+                    "j/k Navigate • Space Describe • Y YAML • T Top • L Logs • S Aggregated Logs • C Capture Logs • B Captures • D Delete • E Exec • F Files • v Batch • / Search • I Language • q Quit • R Refresh".to_string()
                 }
             }
             AppMode::ServiceList => {
@@ -322,7 +382,7 @@ fn render_footer(f: &mut Frame, area: Rect, app: &AppState) {
                         app.marked_items.len()
                     )
                 } else {
-                    "j/k Navigate • Space Describe • Y YAML • v Batch • / Search • I Language • q Quit • R Refresh".to_string()
+                    "j/k Navigate • Space Describe • Y YAML • Enter Capacity • T Usage • C Cordon/Uncordon • D Drain • v Batch • / Search • I Language • q Quit • R Refresh".to_string()
                 }
             }
             AppMode::DeploymentList => {
@@ -332,7 +392,7 @@ fn render_footer(f: &mut Frame, area: Rect, app: &AppState) {
                         app.marked_items.len()
                     )
                 } else {
-                    "j/k Navigate • Space Describe • Y YAML • v Batch • / Search • I Language • q Quit • R Refresh".to_string()
+                    "j/k Navigate • Space Describe • Y YAML • L Aggregated Logs • v Batch • / Search • I Language • q Quit • R Refresh".to_string()
                 }
             }
             AppMode::JobList => {
@@ -342,7 +402,7 @@ fn render_footer(f: &mut Frame, area: Rect, app: &AppState) {
                         app.marked_items.len()
                     )
                 } else {
-                    "j/k Navigate • Space Describe • Y YAML • v Batch • / Search • I Language • q Quit • R Refresh".to_string()
+                    "j/k Navigate • Space Describe • Y YAML • L Aggregated Logs • v Batch • / Search • I Language • q Quit • R Refresh".to_string()
                 }
             }
             AppMode::DaemonSetList => {
@@ -352,7 +412,7 @@ fn render_footer(f: &mut Frame, area: Rect, app: &AppState) {
                         app.marked_items.len()
                     )
                 } else {
-                    "j/k Navigate • Space Describe • Y YAML • v Batch • / Search • I Language • q Quit • R Refresh".to_string()
+                    "j/k Navigate • Space Describe • Y YAML • L Aggregated Logs • v Batch • / Search • I Language • q Quit • R Refresh".to_string()
                 }
             }
             AppMode::PVCList => {
@@ -382,7 +442,7 @@ fn render_footer(f: &mut Frame, area: Rect, app: &AppState) {
                         app.marked_items.len()
                     )
                 } else {
-                    "j/k Navigate • Space Describe • Y YAML • D Delete • v Batch • / Search • I Language • q Quit • R Refresh".to_string()
+                    "j/k Navigate • Enter View keys • Space Describe • Y YAML • D Delete • v Batch • / Search • I Language • q Quit • R Refresh".to_string()
                 }
             }
             AppMode::SecretList => {
@@ -392,30 +452,34 @@ fn render_footer(f: &mut Frame, area: Rect, app: &AppState) {
                         app.marked_items.len()
                     )
                 } else {
-                    "j/k Navigate • Space Describe • Y YAML • D Delete • v Batch • / Search • I Language • q Quit • R Refresh".to_string()
+                    "j/k Navigate • Enter Values • Space Describe • Y YAML • D Delete • v Batch • / Search • I Language • q Quit • R Refresh".to_string()
                 }
             }
             AppMode::Logs => {
                 if app.text_selection_mode {
-                    "j/k Scroll • PgUp/PgDn Page • V Split Logs • A Toggle Auto-scroll • R Toggle Auto-refresh • M Switch to scroll mode • Can select text • I Language • Esc Back • q Quit".to_string()
+                    "j/k Scroll • PgUp/PgDn Page • V Split Logs • f Follow/Snapshot • p Pause • o Log Options • / Search • n/N Jump • g Grep • s Structured • v Level • e Expand • c Colors • w/W Save/Full Logs • A Toggle Auto-scroll • R Toggle Auto-refresh • M Switch to scroll mode • Can select text • I Language • Esc Back • q Quit".to_string()
                 } else {
-                    "j/k Scroll • PgUp/PgDn Page • V Split Logs • A Toggle Auto-scroll • R Toggle Auto-refresh • M Switch to select mode • Mouse wheel scroll • I Language • Esc Back • q Quit".to_string()
+                    "j/k Scroll • PgUp/PgDn Page • V Split Logs • f Follow/Snapshot • p Pause • o Log Options • / Search • n/N Jump • g Grep • s Structured • v Level • e Expand • c Colors • w/W Save/Full Logs • A Toggle Auto-scroll • R Toggle Auto-refresh • M Switch to select mode • Mouse wheel scroll • I Language • Esc Back • q Quit".to_string()
                 }
             }
             AppMode::Describe => {
                 if app.text_selection_mode {
-                    "j/k Scroll • R Toggle Auto-refresh • M Switch to scroll mode • Can select text • I Language • Esc Back • q Quit".to_string()
+                    "j/k Scroll • / Search • n/N Next/prev match • z/Z Fold section/all • [/] Prev/next section • s Sections • w Save • R Toggle Auto-refresh • M Switch to scroll mode • Can select text • I Language • Esc Back • q Quit".to_string()
                 } else {
-                    "j/k Scroll • R Toggle Auto-refresh • M Switch to select mode • Mouse wheel scroll • I Language • Esc Back • q Quit".to_string()
+                    "j/k Scroll • / Search • n/N Next/prev match • z/Z Fold section/all • [/] Prev/next section • s Sections • w Save • R Toggle Auto-refresh • M Switch to select mode • Mouse wheel scroll • I Language • Esc Back • q Quit".to_string()
                 }
             }
             AppMode::YamlView => {
                 if app.text_selection_mode {
-                    "j/k Scroll • R Toggle Auto-refresh • M Switch to scroll mode • Can select text • I Language • Esc Back • q Quit".to_string()
+                    "j/k Move cursor • / Search • n/N Next/prev match • z/Z Fold node/all • S Only spec • c Neat mode • w Save • W Export manifest • R Toggle Auto-refresh • M Switch to scroll mode • Can select text • I Language • Esc Back • q Quit".to_string()
                 } else {
-                    "j/k Scroll • R Toggle Auto-refresh • M Switch to select mode • Mouse wheel scroll • I Language • Esc Back • q Quit".to_string()
+                    "j/k Move cursor • / Search • n/N Next/prev match • z/Z Fold node/all • S Only spec • c Neat mode • w Save • W Export manifest • R Toggle Auto-refresh • M Switch to select mode • Mouse wheel scroll • I Language • Esc Back • q Quit".to_string()
                 }
             }
+            AppMode::TopView if app.top_nodes => {
+                "j/k Scroll • PgUp/PgDn Page • s Cycle sort • I Language • Esc Back • q Quit"
+                    .to_string()
+            }
             AppMode::TopView => {
                 "j/k Scroll • PgUp/PgDn Page • I Language • Esc Back • q Quit".to_string()
             }
@@ -427,6 +491,38 @@ fn render_footer(f: &mut Frame, area: Rect, app: &AppState) {
             AppMode::CommandHistory => {
                 "j/k Select • Enter Execute • I Language • Esc Back • q Quit".to_string()
             }
+            AppMode::Terminal => {
+                if app.terminal_focus {
+                    "Keys go to the shell • Ctrl+] Return focus to TUI".to_string()
+                } else {
+                    "Enter/i Focus shell • Tab/[ ] Switch session • x Close session • Esc/` Back • q Quit".to_string()
+                }
+            }
+            AppMode::FileBrowser => {
+                "j/k Navigate • Enter/l Expand • h Collapse • d Download • u Upload • c Container • r Refresh • Esc Back"
+                    .to_string()
+            }
+            AppMode::MultiLogs => {
+                "j/k Scroll • PgUp/PgDn Page • g/G Top/Bottom • A Toggle Auto-scroll • Esc Back • q Quit"
+                    .to_string()
+            }
+            AppMode::LogCaptures => {
+                "j/k Select • x Stop capture • X Stop all • Esc Back (captures keep running) • q Quit"
+                    .to_string()
+            }
+            AppMode::NodeDrain => {
+                "j/k Select • x Cancel drain • Esc Back (drain keeps running) • q Quit".to_string()
+            }
+            AppMode::NodeDetail => {
+                "j/k Select pod • Enter Jump to pod list • R Refresh • Esc Back • q Quit".to_string()
+            }
+            AppMode::SecretDetail => {
+                "j/k Select key • r Reveal/hide • c Copy • e Edit • R Refresh • Esc Back • q Quit"
+                    .to_string()
+            }
+            AppMode::ConfigMapDetail => {
+                "j/k Select key • J/K Scroll • e Edit • R Refresh • Esc Back • q Quit".to_string()
+            }
         }
     };
 
@@ -590,6 +686,7 @@ fn render_command_line(f: &mut Frame, area: Rect, app: &AppState) {
                 }
                 _ => "YAML View Mode".to_string(),
             },
+            AppMode::TopView if app.top_nodes => "kubectl top nodes".to_string(),
             AppMode::TopView => {
                 format!("kubectl top pods -n {}", app.current_namespace)
             }
@@ -614,6 +711,62 @@ fn render_command_line(f: &mut Frame, area: Rect, app: &AppState) {
             AppMode::Confirm => "Confirmation Mode".to_string(),
             AppMode::Help => "Help Mode".to_string(),
             AppMode::CommandHistory => "Command History".to_string(),
+            AppMode::Terminal => app
+                .active_terminal_session()
+                .map(|s| format!("Terminal: {}", s.title))
+                .unwrap_or_else(|| "Terminal".to_string()),
+            AppMode::FileBrowser => match &app.file_browser {
+                Some(browser) => {
+                    let container_arg = browser
+                        .container
+                        .as_ref()
+                        .map(|c| format!(" -c {}", c))
+                        .unwrap_or_default();
+                    format!(
+                        "kubectl exec -n {} {}{} -- ls -la",
+                        app.current_namespace, browser.pod_name, container_arg
+                    )
+                }
+                None => "File Browser".to_string(),
+            },
+            AppMode::MultiLogs => match &app.multi_log {
+                Some(view) => format!(
+                    "kubectl logs -f -n {} -l {} --all-containers --prefix --timestamps",
+                    view.namespace, view.selector
+                ),
+                None => "Aggregated Logs".to_string(),
+            },
+            AppMode::LogCaptures => {
+                format!("Log captures → {}", app.log_captures.session_dir.display())
+            }
+            AppMode::NodeDrain => match &app.node_drain {
+                Some(drain) => format!(
+                    "kubectl {}",
+                    crate::kubectl::commands::drain_args(&drain.node, &drain.options).join(" ")
+                ),
+                None => "Drain".to_string(),
+            },
+            AppMode::NodeDetail => match &app.node_detail {
+                Some(view) => format!(
+                    "kubectl get node {0} && kubectl get pods -A --field-selector spec.nodeName={0}",
+                    view.node
+                ),
+                None => "Node".to_string(),
+            },
+            AppMode::SecretDetail => match &app.secret_view {
+                Some(view) => format!(
+                    "kubectl get secret {} -n {} -o json",
+                    view.name, view.namespace
+                ),
+                None => "Secret".to_string(),
+            },
+            AppMode::ConfigMapDetail => match &app.configmap_view {
+                Some(view) => format!(
+                    "kubectl get configmap {} -n {} -o json",
+                    view.name, view.namespace
+                ),
+                None => "ConfigMap".to_string(),
+            },
         }
     };
 